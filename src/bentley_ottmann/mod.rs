@@ -0,0 +1,191 @@
+use crate::expansion::{negate, product, sign, sum};
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet},
+};
+
+struct Event {
+    x: Vec<f64>,
+    y: Vec<f64>,
+    d: Vec<f64>,
+}
+
+struct Segment {
+    start: Event,
+    end: Event,
+    dx: Vec<f64>,
+    dy: Vec<f64>,
+    reversed: bool,
+}
+
+fn difference(a: &[f64], b: &[f64]) -> Vec<f64> {
+    sum(a, &negate(b))
+}
+
+fn compare(a: &[f64], a_d: &[f64], b: &[f64], b_d: &[f64]) -> Ordering {
+    sign(&difference(&product(a, b_d), &product(b, a_d)))
+}
+
+impl PartialEq for Event {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Event {}
+
+impl PartialOrd for Event {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Event {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare(&self.x, &self.d, &other.x, &other.d).then_with(|| compare(&self.y, &self.d, &other.y, &other.d))
+    }
+}
+
+impl Segment {
+    fn new([x1, y1, x2, y2]: [Vec<f64>; 4]) -> Self {
+        let a = Event { x: x1, y: y1, d: vec![1.0] };
+        let b = Event { x: x2, y: y2, d: vec![1.0] };
+        let reversed = a > b;
+        let (start, end) = if reversed { (b, a) } else { (a, b) };
+        let dx = difference(&end.x, &start.x);
+        let dy = difference(&end.y, &start.y);
+        Segment { start, end, dx, dy, reversed }
+    }
+
+    fn endpoints(&self) -> [&Event; 2] {
+        if self.reversed { [&self.end, &self.start] } else { [&self.start, &self.end] }
+    }
+}
+
+fn orient(s: &Segment, p: &Event) -> Ordering {
+    let px = difference(&p.x, &product(&s.start.x, &p.d));
+    let py = difference(&p.y, &product(&s.start.y, &p.d));
+    sign(&difference(&product(&s.dx, &py), &product(&s.dy, &px)))
+}
+
+fn directed(s: &Segment, p: &Event) -> Ordering {
+    if s.reversed { orient(s, p).reverse() } else { orient(s, p) }
+}
+
+fn orientations(a: &Segment, b: &Segment) -> [Ordering; 4] {
+    let ([a1, a2], [b1, b2]) = (a.endpoints(), b.endpoints());
+    [directed(a, b1), directed(a, b2), directed(b, a1), directed(b, a2)]
+}
+
+fn slope(a: &Segment, b: &Segment) -> Ordering {
+    sign(&difference(&product(&a.dy, &b.dx), &product(&b.dy, &a.dx)))
+}
+
+fn crossing(a: &Segment, b: &Segment) -> Option<Event> {
+    let (o1, o2) = (orient(a, &b.start), orient(a, &b.end));
+    let (o3, o4) = (orient(b, &a.start), orient(b, &a.end));
+    if [o1, o2, o3, o4].contains(&Ordering::Equal) || o1 == o2 || o3 == o4 {
+        return None;
+    }
+    let d = difference(&product(&a.dx, &b.dy), &product(&a.dy, &b.dx));
+    let num = difference(&product(&difference(&b.start.x, &a.start.x), &b.dy), &product(&difference(&b.start.y, &a.start.y), &b.dx));
+    let x = sum(&product(&a.start.x, &d), &product(&num, &a.dx));
+    let y = sum(&product(&a.start.y, &d), &product(&num, &a.dy));
+    if sign(&d) == Ordering::Less { Some(Event { x: negate(&x), y: negate(&y), d: negate(&d) }) } else { Some(Event { x, y, d }) }
+}
+
+fn schedule(segments: &[Segment], queue: &mut BTreeMap<Event, Vec<usize>>, p: &Event, a: usize, b: usize) {
+    if let Some(q) = crossing(&segments[a], &segments[b])
+        && q > *p
+    {
+        queue.entry(q).or_default();
+    }
+}
+
+pub fn intersecting_pairs(segments: Vec<[Vec<f64>; 4]>) -> Vec<(usize, usize, [Ordering; 4])> {
+    let segments: Vec<Segment> = segments.into_iter().map(Segment::new).collect();
+    let mut queue: BTreeMap<Event, Vec<usize>> = BTreeMap::new();
+    for (i, s) in segments.iter().enumerate() {
+        queue.entry(Event { x: s.start.x.clone(), y: s.start.y.clone(), d: vec![1.0] }).or_default().push(i);
+        queue.entry(Event { x: s.end.x.clone(), y: s.end.y.clone(), d: vec![1.0] }).or_default();
+    }
+    let mut status: Vec<usize> = Vec::new();
+    let mut pairs = BTreeSet::new();
+    while let Some((p, upper)) = queue.pop_first() {
+        let first = status.partition_point(|&s| orient(&segments[s], &p) == Ordering::Greater);
+        let last = first + status[first..].partition_point(|&s| orient(&segments[s], &p) == Ordering::Equal);
+        let through: Vec<usize> = status.drain(first..last).chain(upper).collect();
+        for (i, &a) in through.iter().enumerate() {
+            for &b in &through[i + 1..] {
+                pairs.insert((a.min(b), a.max(b)));
+            }
+        }
+        let mut inserted: Vec<usize> = through.into_iter().filter(|&s| segments[s].end != p).collect();
+        inserted.sort_by(|&a, &b| slope(&segments[a], &segments[b]).then(a.cmp(&b)));
+        let count = inserted.len();
+        status.splice(first..first, inserted);
+        if count == 0 {
+            if first > 0 && first < status.len() {
+                schedule(&segments, &mut queue, &p, status[first - 1], status[first]);
+            }
+        } else {
+            if first > 0 {
+                schedule(&segments, &mut queue, &p, status[first - 1], status[first]);
+            }
+            if first + count < status.len() {
+                schedule(&segments, &mut queue, &p, status[first + count - 1], status[first + count]);
+            }
+        }
+    }
+    pairs.into_iter().map(|(a, b)| (a, b, orientations(&segments[a], &segments[b]))).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::intersecting_pairs;
+    use std::cmp::Ordering;
+
+    fn segments(coordinates: &[[f64; 4]]) -> Vec<[Vec<f64>; 4]> {
+        coordinates.iter().map(|c| c.map(|v| vec![v])).collect()
+    }
+
+    fn pairs(coordinates: &[[f64; 4]]) -> Vec<(usize, usize)> {
+        intersecting_pairs(segments(coordinates)).into_iter().map(|(a, b, _)| (a, b)).collect()
+    }
+
+    #[test]
+    fn test_intersecting_pairs() {
+        assert_eq!(pairs(&[]), vec![]);
+        assert_eq!(pairs(&[[0.0, 0.0, 4.0, 4.0], [0.0, 4.0, 4.0, 0.0]]), vec![(0, 1)]);
+        assert_eq!(pairs(&[[0.0, 0.0, 4.0, 4.0], [0.0, 1.0, 4.0, 5.0]]), vec![]);
+        assert_eq!(pairs(&[[0.0, 0.0, 4.0, 0.0], [1.0, 1.0, 3.0, 1.0], [2.0, -1.0, 2.0, 2.0]]), vec![(0, 2), (1, 2)]);
+    }
+
+    #[test]
+    fn intersecting_pairs_order_change() {
+        let lines = [[0.0, 0.0, 10.0, 10.0], [0.0, 10.0, 10.0, 0.0], [0.0, 4.0, 10.0, 6.0], [0.0, 6.0, 10.0, 4.0], [20.0, 0.0, 21.0, 1.0]];
+        assert_eq!(pairs(&lines), vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+    }
+
+    #[test]
+    fn intersecting_pairs_degenerate() {
+        let shared = [[0.0, 0.0, 2.0, 2.0], [2.0, 2.0, 4.0, 0.0], [2.0, 2.0, 2.0, 5.0], [2.0, 2.0, 2.0, 2.0]];
+        assert_eq!(pairs(&shared), vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+        let collinear = [[0.0, 0.0, 4.0, 0.0], [2.0, 0.0, 6.0, 0.0], [5.0, 0.0, 8.0, 0.0], [9.0, 0.0, 10.0, 0.0]];
+        assert_eq!(pairs(&collinear), vec![(0, 1), (1, 2)]);
+        let vertical = [[0.0, 0.0, 0.0, 4.0], [0.0, 2.0, 0.0, 6.0], [-1.0, 3.0, 1.0, 3.0], [-1.0, 7.0, 1.0, 7.0]];
+        assert_eq!(pairs(&vertical), vec![(0, 1), (0, 2), (1, 2)]);
+        let touching = [[0.0, 0.0, 4.0, 0.0], [2.0, 0.0, 2.0, 3.0], [4.0, 0.0, 6.0, 1.0]];
+        assert_eq!(pairs(&touching), vec![(0, 1), (0, 2)]);
+    }
+
+    #[test]
+    fn intersecting_pairs_precision() {
+        let lines = [[0.0, 0.0, 3.0, 1.0], [1.0, 1.0 / 3.0, 2.0, 0.0], [0.1, 0.2, 0.3, 0.6], [0.1, 0.2, 0.30000000000000004, 0.6]];
+        assert_eq!(pairs(&lines), vec![(2, 3)]);
+        let (less, equal, greater) = (Ordering::Less, Ordering::Equal, Ordering::Greater);
+        assert_eq!(intersecting_pairs(segments(&lines[2..])), vec![(0, 1, [equal, less, equal, greater])]);
+        let reversed = [[0.3, 0.6, 0.1, 0.2], [0.30000000000000004, 0.6, 0.1, 0.2]];
+        assert_eq!(intersecting_pairs(segments(&reversed)), vec![(0, 1, [greater, equal, less, equal])]);
+    }
+}
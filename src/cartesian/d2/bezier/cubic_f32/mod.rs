@@ -0,0 +1,55 @@
+use crate::cartesian::d2::point::point_f32::Point;
+
+mod bounding_rect;
+mod derivative;
+mod eval;
+mod flatten;
+mod split;
+
+pub use self::bounding_rect::bounding_rect;
+pub use self::derivative::derivative;
+pub use self::eval::eval;
+pub use self::flatten::{flatten, try_flatten};
+pub use self::split::split;
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Cubic {
+    pub p0: Point,
+    pub p1: Point,
+    pub p2: Point,
+    pub p3: Point,
+}
+
+impl Cubic {
+    pub fn new(p0: (f32, f32), p1: (f32, f32), p2: (f32, f32), p3: (f32, f32)) -> Self {
+        Cubic { p0: Point::new(p0.0, p0.1), p1: Point::new(p1.0, p1.1), p2: Point::new(p2.0, p2.1), p3: Point::new(p3.0, p3.1) }
+    }
+}
+
+impl std::fmt::Display for Cubic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {}, {})", self.p0, self.p1, self.p2, self.p3)
+    }
+}
+
+fn lerp(a: &Point, b: &Point, t: f32) -> Point {
+    Point::new(t.mul_add(b.x - a.x, a.x), t.mul_add(b.y - a.y, a.y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cubic;
+    use crate::cartesian::d2::point::point_f32::Point;
+
+    #[test]
+    fn cubic() {
+        assert_eq!(Cubic::new((0.0, 0.0), (0.0, 4.0), (4.0, 4.0), (4.0, 0.0)), Cubic { p0: Point::new(0.0, 0.0), p1: Point::new(0.0, 4.0), p2: Point::new(4.0, 4.0), p3: Point::new(4.0, 0.0) });
+        assert_eq!(Cubic::new((-1.0, 1.0), (0.0, 0.0), (1.0, -1.0), (2.0, -2.0)), Cubic { p0: Point::new(-1.0, 1.0), p1: Point::zero(), p2: Point::new(1.0, -1.0), p3: Point::new(2.0, -2.0) });
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(Cubic::new((0.0, 0.0), (0.0, 4.0), (4.0, 4.0), (4.0, 0.0)).to_string(), "((0, 0), (0, 4), (4, 4), (4, 0))");
+        assert_eq!(Cubic::new((-1.5, 1.0), (0.0, 0.0), (1.0, -1.0), (2.0, -2.25)).to_string(), "((-1.5, 1), (0, 0), (1, -1), (2, -2.25))");
+    }
+}
@@ -0,0 +1,65 @@
+use super::{Cubic, eval};
+use crate::cartesian::d2::rect::rect_f64::Rect;
+
+fn extrema(p0: f64, p1: f64, p2: f64, p3: f64) -> [Option<f64>; 2] {
+    let a = 3.0 * (p1 - p2) + p3 - p0;
+    let b = 2.0 * (p0 - 2.0 * p1 + p2);
+    let c = p1 - p0;
+    let in_range = |t: f64| if t > 0.0 && t < 1.0 { Some(t) } else { None };
+    if a == 0.0 {
+        if b == 0.0 {
+            return [None, None];
+        }
+        return [in_range(-c / b), None];
+    }
+    let discriminant = b.mul_add(b, -4.0 * a * c);
+    if discriminant < 0.0 {
+        return [None, None];
+    }
+    let root = discriminant.sqrt();
+    [in_range((-b + root) / (2.0 * a)), in_range((-b - root) / (2.0 * a))]
+}
+
+pub fn bounding_rect(c: &Cubic) -> Rect {
+    let mut r = Rect { min: c.p0.clone(), max: c.p0.clone() };
+    let [x0, x1] = extrema(c.p0.x, c.p1.x, c.p2.x, c.p3.x);
+    let [y0, y1] = extrema(c.p0.y, c.p1.y, c.p2.y, c.p3.y);
+    for t in [Some(1.0), x0, x1, y0, y1].into_iter().flatten() {
+        let p = eval(c, t);
+        r.min.x = r.min.x.min(p.x);
+        r.min.y = r.min.y.min(p.y);
+        r.max.x = r.max.x.max(p.x);
+        r.max.y = r.max.y.max(p.y);
+    }
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::bounding_rect;
+    use crate::cartesian::d2::{bezier::cubic_f64::Cubic, rect::rect_f64::Rect};
+
+    #[test]
+    fn test_bounding_rect() {
+        assert_eq!(bounding_rect(&Cubic::new((0.0, 0.0), (0.0, 4.0), (4.0, 4.0), (4.0, 0.0))), Rect::new((0.0, 0.0), (4.0, 3.0)));
+        assert_eq!(bounding_rect(&Cubic::new((0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0))), Rect::new((0.0, 0.0), (3.0, 4.0)));
+        assert_eq!(bounding_rect(&Cubic::new((0.0, 0.0), (4.0, 4.0), (0.0, 4.0), (4.0, 0.0))), Rect::new((0.0, 0.0), (4.0, 3.0)));
+    }
+
+    #[test]
+    fn bounding_rect_two_extrema() {
+        assert_eq!(bounding_rect(&Cubic::new((0.0, 0.0), (1.0, -9.0), (3.0, 12.0), (4.0, -1.0))), Rect::new((0.0, -2.3203125), (4.0, 3.375)));
+        assert_eq!(bounding_rect(&Cubic::new((0.0, 0.0), (-9.0, 1.0), (12.0, 3.0), (-1.0, 4.0))), Rect::new((-2.3203125, 0.0), (3.375, 4.0)));
+    }
+
+    #[test]
+    fn bounding_rect_monotonic() {
+        assert_eq!(bounding_rect(&Cubic::new((0.0, 0.0), (1.0, 1.0), (3.0, 3.0), (4.0, 4.0))), Rect::new((0.0, 0.0), (4.0, 4.0)));
+        assert_eq!(bounding_rect(&Cubic::new((4.0, 4.0), (3.0, 3.0), (1.0, 1.0), (0.0, 0.0))), Rect::new((0.0, 0.0), (4.0, 4.0)));
+    }
+
+    #[test]
+    fn bounding_rect_point() {
+        assert_eq!(bounding_rect(&Cubic::new((1.0, 1.0), (1.0, 1.0), (1.0, 1.0), (1.0, 1.0))), Rect::new((1.0, 1.0), (1.0, 1.0)));
+    }
+}
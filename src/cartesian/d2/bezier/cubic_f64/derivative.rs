@@ -0,0 +1,31 @@
+use super::{Cubic, lerp};
+use crate::cartesian::d2::point::point_f64::Point;
+
+pub fn derivative(c: &Cubic, t: f64) -> Point {
+    let d0 = Point::new(3.0 * (c.p1.x - c.p0.x), 3.0 * (c.p1.y - c.p0.y));
+    let d1 = Point::new(3.0 * (c.p2.x - c.p1.x), 3.0 * (c.p2.y - c.p1.y));
+    let d2 = Point::new(3.0 * (c.p3.x - c.p2.x), 3.0 * (c.p3.y - c.p2.y));
+    lerp(&lerp(&d0, &d1, t), &lerp(&d1, &d2, t), t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::derivative;
+    use crate::cartesian::d2::{bezier::cubic_f64::Cubic, point::point_f64::Point};
+
+    #[test]
+    fn test_derivative() {
+        let c = Cubic::new((0.0, 0.0), (0.0, 4.0), (4.0, 4.0), (4.0, 0.0));
+        assert_eq!(derivative(&c, 0.0), Point::new(0.0, 12.0));
+        assert_eq!(derivative(&c, 0.25), Point::new(4.5, 6.0));
+        assert_eq!(derivative(&c, 0.5), Point::new(6.0, 0.0));
+        assert_eq!(derivative(&c, 1.0), Point::new(0.0, -12.0));
+    }
+
+    #[test]
+    fn derivative_degenerate() {
+        let c = Cubic::new((1.0, 1.0), (1.0, 1.0), (1.0, 1.0), (1.0, 1.0));
+        assert_eq!(derivative(&c, 0.0), Point::zero());
+        assert_eq!(derivative(&c, 0.5), Point::zero());
+    }
+}
@@ -0,0 +1,32 @@
+use super::{Cubic, lerp};
+use crate::cartesian::d2::point::point_f64::Point;
+
+pub fn eval(c: &Cubic, t: f64) -> Point {
+    let q0 = lerp(&c.p0, &c.p1, t);
+    let q1 = lerp(&c.p1, &c.p2, t);
+    let q2 = lerp(&c.p2, &c.p3, t);
+    lerp(&lerp(&q0, &q1, t), &lerp(&q1, &q2, t), t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::eval;
+    use crate::cartesian::d2::{bezier::cubic_f64::Cubic, point::point_f64::Point};
+
+    #[test]
+    fn test_eval() {
+        let c = Cubic::new((0.0, 0.0), (0.0, 4.0), (4.0, 4.0), (4.0, 0.0));
+        assert_eq!(eval(&c, 0.0), Point::new(0.0, 0.0));
+        assert_eq!(eval(&c, 0.25), Point::new(0.625, 2.25));
+        assert_eq!(eval(&c, 0.5), Point::new(2.0, 3.0));
+        assert_eq!(eval(&c, 0.75), Point::new(3.375, 2.25));
+        assert_eq!(eval(&c, 1.0), Point::new(4.0, 0.0));
+    }
+
+    #[test]
+    fn eval_straight() {
+        let c = Cubic::new((-3.0, -3.0), (-1.0, -1.0), (1.0, 1.0), (3.0, 3.0));
+        assert_eq!(eval(&c, 0.25), Point::new(-1.5, -1.5));
+        assert_eq!(eval(&c, 0.5), Point::new(0.0, 0.0));
+    }
+}
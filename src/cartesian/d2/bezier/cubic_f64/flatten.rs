@@ -0,0 +1,82 @@
+use super::{Cubic, split};
+use crate::cartesian::d2::{
+    point::point_f64::{Point, distance},
+    polyline::polyline_f64::Polyline,
+};
+
+const MAX_DEPTH: u32 = 32;
+
+fn segment_distance(p: &Point, a: &Point, b: &Point) -> f64 {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let len_squared = dx.mul_add(dx, dy * dy);
+    if len_squared == 0.0 {
+        return distance(p, a);
+    }
+    let t = ((p.x - a.x).mul_add(dx, (p.y - a.y) * dy) / len_squared).clamp(0.0, 1.0);
+    distance(p, &Point::new(t.mul_add(dx, a.x), t.mul_add(dy, a.y)))
+}
+
+pub fn try_flatten(c: &Cubic, tolerance: f64) -> Option<Polyline> {
+    if tolerance <= 0.0 || !tolerance.is_finite() {
+        return None;
+    }
+    let mut points = vec![c.p0.clone()];
+    let mut stack = vec![(c.clone(), 0)];
+    while let Some((curve, depth)) = stack.pop() {
+        let flatness = segment_distance(&curve.p1, &curve.p0, &curve.p3).max(segment_distance(&curve.p2, &curve.p0, &curve.p3));
+        if depth >= MAX_DEPTH || flatness <= tolerance {
+            points.push(curve.p3);
+        } else {
+            let (left, right) = split(&curve, 0.5);
+            stack.push((right, depth + 1));
+            stack.push((left, depth + 1));
+        }
+    }
+    Some(Polyline { points })
+}
+
+pub fn flatten(c: &Cubic, tolerance: f64) -> Polyline {
+    try_flatten(c, tolerance).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{flatten, try_flatten};
+    use crate::cartesian::d2::{bezier::cubic_f64::Cubic, polyline::polyline_f64::Polyline};
+
+    #[test]
+    fn test_try_flatten() {
+        let c = Cubic::new((0.0, 0.0), (0.0, 4.0), (4.0, 4.0), (4.0, 0.0));
+        assert_eq!(try_flatten(&c, 4.0), Some(Polyline::new(&[(0.0, 0.0), (4.0, 0.0)])));
+        assert_eq!(try_flatten(&c, 3.0), Some(Polyline::new(&[(0.0, 0.0), (2.0, 3.0), (4.0, 0.0)])));
+        assert_eq!(try_flatten(&c, 1.0), Some(Polyline::new(&[(0.0, 0.0), (0.625, 2.25), (2.0, 3.0), (3.375, 2.25), (4.0, 0.0)])));
+    }
+
+    #[test]
+    fn try_flatten_straight() {
+        assert_eq!(try_flatten(&Cubic::new((0.0, 0.0), (1.0, 1.0), (3.0, 3.0), (4.0, 4.0)), 0.001), Some(Polyline::new(&[(0.0, 0.0), (4.0, 4.0)])));
+        assert_eq!(try_flatten(&Cubic::new((1.0, 1.0), (1.0, 1.0), (1.0, 1.0), (1.0, 1.0)), 0.001), Some(Polyline::new(&[(1.0, 1.0), (1.0, 1.0)])));
+    }
+
+    #[test]
+    fn try_flatten_invalid_tolerance() {
+        let c = Cubic::new((0.0, 0.0), (0.0, 4.0), (4.0, 4.0), (4.0, 0.0));
+        assert_eq!(try_flatten(&c, 0.0), None);
+        assert_eq!(try_flatten(&c, -1.0), None);
+        assert_eq!(try_flatten(&c, f64::NAN), None);
+        assert_eq!(try_flatten(&c, f64::INFINITY), None);
+    }
+
+    #[test]
+    fn test_flatten() {
+        assert_eq!(flatten(&Cubic::new((0.0, 0.0), (0.0, 4.0), (4.0, 4.0), (4.0, 0.0)), 3.0), Polyline::new(&[(0.0, 0.0), (2.0, 3.0), (4.0, 0.0)]));
+    }
+
+    #[test]
+    fn flatten_loop_back() {
+        let points = flatten(&Cubic::new((0.0, 0.0), (10.0, 0.0), (-9.0, 0.0), (1.0, 0.0)), 0.001).points;
+        assert!(points.iter().all(|p| p.y == 0.0));
+        assert!(points.iter().any(|p| p.x >= 3.005));
+        assert!(points.iter().any(|p| p.x <= -2.005));
+    }
+}
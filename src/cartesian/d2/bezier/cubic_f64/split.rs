@@ -0,0 +1,31 @@
+use super::{Cubic, lerp};
+
+pub fn split(c: &Cubic, t: f64) -> (Cubic, Cubic) {
+    let q0 = lerp(&c.p0, &c.p1, t);
+    let q1 = lerp(&c.p1, &c.p2, t);
+    let q2 = lerp(&c.p2, &c.p3, t);
+    let r0 = lerp(&q0, &q1, t);
+    let r1 = lerp(&q1, &q2, t);
+    let s = lerp(&r0, &r1, t);
+    (Cubic { p0: c.p0.clone(), p1: q0, p2: r0, p3: s.clone() }, Cubic { p0: s, p1: r1, p2: q2, p3: c.p3.clone() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split;
+    use crate::cartesian::d2::bezier::cubic_f64::Cubic;
+
+    #[test]
+    fn test_split() {
+        let c = Cubic::new((0.0, 0.0), (0.0, 4.0), (4.0, 4.0), (4.0, 0.0));
+        assert_eq!(split(&c, 0.5), (Cubic::new((0.0, 0.0), (0.0, 2.0), (1.0, 3.0), (2.0, 3.0)), Cubic::new((2.0, 3.0), (3.0, 3.0), (4.0, 2.0), (4.0, 0.0))));
+        assert_eq!(split(&c, 0.25), (Cubic::new((0.0, 0.0), (0.0, 1.0), (0.25, 1.75), (0.625, 2.25)), Cubic::new((0.625, 2.25), (1.75, 3.75), (4.0, 3.0), (4.0, 0.0))));
+    }
+
+    #[test]
+    fn split_ends() {
+        let c = Cubic::new((0.0, 0.0), (0.0, 4.0), (4.0, 4.0), (4.0, 0.0));
+        assert_eq!(split(&c, 0.0), (Cubic::new((0.0, 0.0), (0.0, 0.0), (0.0, 0.0), (0.0, 0.0)), c.clone()));
+        assert_eq!(split(&c, 1.0), (c.clone(), Cubic::new((4.0, 0.0), (4.0, 0.0), (4.0, 0.0), (4.0, 0.0))));
+    }
+}
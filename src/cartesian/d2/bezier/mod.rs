@@ -0,0 +1,5 @@
+pub mod quadratic_f32;
+pub mod quadratic_f64;
+
+pub mod cubic_f32;
+pub mod cubic_f64;
@@ -0,0 +1,30 @@
+use super::{Quadratic, lerp};
+use crate::cartesian::d2::point::point_f32::Point;
+
+pub fn derivative(q: &Quadratic, t: f32) -> Point {
+    let d0 = Point::new(2.0 * (q.p1.x - q.p0.x), 2.0 * (q.p1.y - q.p0.y));
+    let d1 = Point::new(2.0 * (q.p2.x - q.p1.x), 2.0 * (q.p2.y - q.p1.y));
+    lerp(&d0, &d1, t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::derivative;
+    use crate::cartesian::d2::{bezier::quadratic_f32::Quadratic, point::point_f32::Point};
+
+    #[test]
+    fn test_derivative() {
+        let q = Quadratic::new((0.0, 0.0), (2.0, 4.0), (4.0, 0.0));
+        assert_eq!(derivative(&q, 0.0), Point::new(4.0, 8.0));
+        assert_eq!(derivative(&q, 0.25), Point::new(4.0, 4.0));
+        assert_eq!(derivative(&q, 0.5), Point::new(4.0, 0.0));
+        assert_eq!(derivative(&q, 1.0), Point::new(4.0, -8.0));
+    }
+
+    #[test]
+    fn derivative_degenerate() {
+        let q = Quadratic::new((1.0, 1.0), (1.0, 1.0), (1.0, 1.0));
+        assert_eq!(derivative(&q, 0.0), Point::zero());
+        assert_eq!(derivative(&q, 0.5), Point::zero());
+    }
+}
@@ -0,0 +1,86 @@
+use super::{Quadratic, split};
+use crate::cartesian::d2::{
+    point::point_f32::{Point, distance},
+    polyline::polyline_f32::Polyline,
+};
+
+const MAX_DEPTH: u32 = 32;
+
+fn segment_distance(p: &Point, a: &Point, b: &Point) -> f32 {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let len_squared = dx.mul_add(dx, dy * dy);
+    if len_squared == 0.0 {
+        return distance(p, a);
+    }
+    let t = ((p.x - a.x).mul_add(dx, (p.y - a.y) * dy) / len_squared).clamp(0.0, 1.0);
+    distance(p, &Point::new(t.mul_add(dx, a.x), t.mul_add(dy, a.y)))
+}
+
+pub fn try_flatten(q: &Quadratic, tolerance: f32) -> Option<Polyline> {
+    if tolerance <= 0.0 || !tolerance.is_finite() {
+        return None;
+    }
+    let mut points = vec![q.p0.clone()];
+    let mut stack = vec![(q.clone(), 0)];
+    while let Some((curve, depth)) = stack.pop() {
+        if depth >= MAX_DEPTH || segment_distance(&curve.p1, &curve.p0, &curve.p2) <= tolerance {
+            points.push(curve.p2);
+        } else {
+            let (left, right) = split(&curve, 0.5);
+            stack.push((right, depth + 1));
+            stack.push((left, depth + 1));
+        }
+    }
+    Some(Polyline { points })
+}
+
+pub fn flatten(q: &Quadratic, tolerance: f32) -> Polyline {
+    try_flatten(q, tolerance).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{flatten, try_flatten};
+    use crate::cartesian::d2::{bezier::quadratic_f32::Quadratic, polyline::polyline_f32::Polyline};
+
+    #[test]
+    fn test_try_flatten() {
+        let q = Quadratic::new((0.0, 0.0), (2.0, 4.0), (4.0, 0.0));
+        assert_eq!(try_flatten(&q, 4.0), Some(Polyline::new(&[(0.0, 0.0), (4.0, 0.0)])));
+        assert_eq!(try_flatten(&q, 1.0), Some(Polyline::new(&[(0.0, 0.0), (2.0, 2.0), (4.0, 0.0)])));
+        assert_eq!(try_flatten(&q, 0.5), Some(Polyline::new(&[(0.0, 0.0), (1.0, 1.5), (2.0, 2.0), (3.0, 1.5), (4.0, 0.0)])));
+    }
+
+    #[test]
+    fn try_flatten_straight() {
+        assert_eq!(try_flatten(&Quadratic::new((0.0, 0.0), (1.0, 1.0), (4.0, 4.0)), 0.001), Some(Polyline::new(&[(0.0, 0.0), (4.0, 4.0)])));
+        assert_eq!(try_flatten(&Quadratic::new((1.0, 1.0), (1.0, 1.0), (1.0, 1.0)), 0.001), Some(Polyline::new(&[(1.0, 1.0), (1.0, 1.0)])));
+    }
+
+    #[test]
+    fn try_flatten_closed() {
+        assert_eq!(try_flatten(&Quadratic::new((0.0, 0.0), (4.0, 0.0), (0.0, 0.0)), 4.0), Some(Polyline::new(&[(0.0, 0.0), (0.0, 0.0)])));
+        assert_eq!(try_flatten(&Quadratic::new((0.0, 0.0), (4.0, 0.0), (0.0, 0.0)), 2.0), Some(Polyline::new(&[(0.0, 0.0), (2.0, 0.0), (0.0, 0.0)])));
+    }
+
+    #[test]
+    fn try_flatten_invalid_tolerance() {
+        let q = Quadratic::new((0.0, 0.0), (2.0, 4.0), (4.0, 0.0));
+        assert_eq!(try_flatten(&q, 0.0), None);
+        assert_eq!(try_flatten(&q, -1.0), None);
+        assert_eq!(try_flatten(&q, f32::NAN), None);
+        assert_eq!(try_flatten(&q, f32::INFINITY), None);
+    }
+
+    #[test]
+    fn test_flatten() {
+        assert_eq!(flatten(&Quadratic::new((0.0, 0.0), (2.0, 4.0), (4.0, 0.0)), 1.0), Polyline::new(&[(0.0, 0.0), (2.0, 2.0), (4.0, 0.0)]));
+    }
+
+    #[test]
+    fn flatten_loop_back() {
+        let points = flatten(&Quadratic::new((0.0, 0.0), (10.0, 0.0), (1.0, 0.0)), 0.001).points;
+        assert!(points.iter().all(|p| p.y == 0.0));
+        assert!(points.iter().any(|p| p.x >= 5.262));
+    }
+}
@@ -0,0 +1,55 @@
+use super::{Quadratic, eval};
+use crate::cartesian::d2::rect::rect_f64::Rect;
+
+fn extremum(a: f64, b: f64, c: f64) -> Option<f64> {
+    let denominator = a - 2.0 * b + c;
+    if denominator == 0.0 {
+        return None;
+    }
+    let t = (a - b) / denominator;
+    if t > 0.0 && t < 1.0 { Some(t) } else { None }
+}
+
+pub fn bounding_rect(q: &Quadratic) -> Rect {
+    let mut r = Rect { min: q.p0.clone(), max: q.p0.clone() };
+    let candidates = [Some(1.0), extremum(q.p0.x, q.p1.x, q.p2.x), extremum(q.p0.y, q.p1.y, q.p2.y)];
+    for t in candidates.into_iter().flatten() {
+        let p = eval(q, t);
+        r.min.x = r.min.x.min(p.x);
+        r.min.y = r.min.y.min(p.y);
+        r.max.x = r.max.x.max(p.x);
+        r.max.y = r.max.y.max(p.y);
+    }
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::bounding_rect;
+    use crate::cartesian::d2::{bezier::quadratic_f64::Quadratic, rect::rect_f64::Rect};
+
+    #[test]
+    fn test_bounding_rect() {
+        assert_eq!(bounding_rect(&Quadratic::new((0.0, 0.0), (2.0, 4.0), (4.0, 0.0))), Rect::new((0.0, 0.0), (4.0, 2.0)));
+        assert_eq!(bounding_rect(&Quadratic::new((0.0, 0.0), (4.0, 2.0), (0.0, 4.0))), Rect::new((0.0, 0.0), (2.0, 4.0)));
+        assert_eq!(bounding_rect(&Quadratic::new((0.0, 0.0), (4.0, -4.0), (0.0, 0.0))), Rect::new((0.0, -2.0), (2.0, 0.0)));
+    }
+
+    #[test]
+    fn bounding_rect_monotonic() {
+        assert_eq!(bounding_rect(&Quadratic::new((0.0, 0.0), (1.0, 3.0), (4.0, 4.0))), Rect::new((0.0, 0.0), (4.0, 4.0)));
+        assert_eq!(bounding_rect(&Quadratic::new((4.0, 4.0), (1.0, 3.0), (0.0, 0.0))), Rect::new((0.0, 0.0), (4.0, 4.0)));
+        assert_eq!(bounding_rect(&Quadratic::new((-2.0, -2.0), (0.0, 0.0), (2.0, 2.0))), Rect::new((-2.0, -2.0), (2.0, 2.0)));
+    }
+
+    #[test]
+    fn bounding_rect_control_outside() {
+        let r = bounding_rect(&Quadratic::new((0.0, 0.0), (2.0, 100.0), (4.0, 0.0)));
+        assert_eq!(r, Rect::new((0.0, 0.0), (4.0, 50.0)));
+    }
+
+    #[test]
+    fn bounding_rect_point() {
+        assert_eq!(bounding_rect(&Quadratic::new((1.0, 1.0), (1.0, 1.0), (1.0, 1.0))), Rect::new((1.0, 1.0), (1.0, 1.0)));
+    }
+}
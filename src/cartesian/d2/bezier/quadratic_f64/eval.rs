@@ -0,0 +1,29 @@
+use super::{Quadratic, lerp};
+use crate::cartesian::d2::point::point_f64::Point;
+
+pub fn eval(q: &Quadratic, t: f64) -> Point {
+    lerp(&lerp(&q.p0, &q.p1, t), &lerp(&q.p1, &q.p2, t), t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::eval;
+    use crate::cartesian::d2::{bezier::quadratic_f64::Quadratic, point::point_f64::Point};
+
+    #[test]
+    fn test_eval() {
+        let q = Quadratic::new((0.0, 0.0), (2.0, 4.0), (4.0, 0.0));
+        assert_eq!(eval(&q, 0.0), Point::new(0.0, 0.0));
+        assert_eq!(eval(&q, 0.25), Point::new(1.0, 1.5));
+        assert_eq!(eval(&q, 0.5), Point::new(2.0, 2.0));
+        assert_eq!(eval(&q, 0.75), Point::new(3.0, 1.5));
+        assert_eq!(eval(&q, 1.0), Point::new(4.0, 0.0));
+    }
+
+    #[test]
+    fn eval_straight() {
+        let q = Quadratic::new((-2.0, -2.0), (0.0, 0.0), (2.0, 2.0));
+        assert_eq!(eval(&q, 0.25), Point::new(-1.0, -1.0));
+        assert_eq!(eval(&q, 0.5), Point::new(0.0, 0.0));
+    }
+}
@@ -0,0 +1,66 @@
+use crate::cartesian::d2::{bezier::quadratic_f32, point::point_f64::Point};
+
+mod bounding_rect;
+mod derivative;
+mod eval;
+mod flatten;
+mod split;
+
+pub use self::bounding_rect::bounding_rect;
+pub use self::derivative::derivative;
+pub use self::eval::eval;
+pub use self::flatten::{flatten, try_flatten};
+pub use self::split::split;
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Quadratic {
+    pub p0: Point,
+    pub p1: Point,
+    pub p2: Point,
+}
+
+impl Quadratic {
+    pub fn new(p0: (f64, f64), p1: (f64, f64), p2: (f64, f64)) -> Self {
+        Quadratic { p0: Point::new(p0.0, p0.1), p1: Point::new(p1.0, p1.1), p2: Point::new(p2.0, p2.1) }
+    }
+}
+
+impl std::fmt::Display for Quadratic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.p0, self.p1, self.p2)
+    }
+}
+
+impl From<quadratic_f32::Quadratic> for Quadratic {
+    fn from(q: quadratic_f32::Quadratic) -> Self {
+        Quadratic { p0: Point::from(q.p0), p1: Point::from(q.p1), p2: Point::from(q.p2) }
+    }
+}
+
+fn lerp(a: &Point, b: &Point, t: f64) -> Point {
+    Point::new(t.mul_add(b.x - a.x, a.x), t.mul_add(b.y - a.y, a.y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Quadratic;
+    use crate::cartesian::d2::{bezier::quadratic_f32, point::point_f64::Point};
+
+    #[test]
+    fn quadratic() {
+        assert_eq!(Quadratic::new((0.0, 0.0), (2.0, 4.0), (4.0, 0.0)), Quadratic { p0: Point::new(0.0, 0.0), p1: Point::new(2.0, 4.0), p2: Point::new(4.0, 0.0) });
+        assert_eq!(Quadratic::new((-1.0, 1.0), (0.0, 0.0), (1.0, -1.0)), Quadratic { p0: Point::new(-1.0, 1.0), p1: Point::zero(), p2: Point::new(1.0, -1.0) });
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(Quadratic::new((0.0, 0.0), (2.0, 4.0), (4.0, 0.0)).to_string(), "((0, 0), (2, 4), (4, 0))");
+        assert_eq!(Quadratic::new((-1.5, 1.0), (0.0, 0.0), (1.0, -1.0)).to_string(), "((-1.5, 1), (0, 0), (1, -1))");
+    }
+
+    #[test]
+    fn from() {
+        assert_eq!(Quadratic::from(quadratic_f32::Quadratic::new((0.0, 0.0), (2.0, 4.0), (4.0, 0.0))), Quadratic::new((0.0, 0.0), (2.0, 4.0), (4.0, 0.0)));
+        assert_eq!(Quadratic::from(quadratic_f32::Quadratic::new((-1.5, 1.0), (0.0, 0.0), (1.0, -1.0))), Quadratic::new((-1.5, 1.0), (0.0, 0.0), (1.0, -1.0)));
+    }
+}
@@ -0,0 +1,28 @@
+use super::{Quadratic, lerp};
+
+pub fn split(q: &Quadratic, t: f64) -> (Quadratic, Quadratic) {
+    let q0 = lerp(&q.p0, &q.p1, t);
+    let q1 = lerp(&q.p1, &q.p2, t);
+    let r = lerp(&q0, &q1, t);
+    (Quadratic { p0: q.p0.clone(), p1: q0, p2: r.clone() }, Quadratic { p0: r, p1: q1, p2: q.p2.clone() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split;
+    use crate::cartesian::d2::bezier::quadratic_f64::Quadratic;
+
+    #[test]
+    fn test_split() {
+        let q = Quadratic::new((0.0, 0.0), (2.0, 4.0), (4.0, 0.0));
+        assert_eq!(split(&q, 0.5), (Quadratic::new((0.0, 0.0), (1.0, 2.0), (2.0, 2.0)), Quadratic::new((2.0, 2.0), (3.0, 2.0), (4.0, 0.0))));
+        assert_eq!(split(&q, 0.25), (Quadratic::new((0.0, 0.0), (0.5, 1.0), (1.0, 1.5)), Quadratic::new((1.0, 1.5), (2.5, 3.0), (4.0, 0.0))));
+    }
+
+    #[test]
+    fn split_ends() {
+        let q = Quadratic::new((0.0, 0.0), (2.0, 4.0), (4.0, 0.0));
+        assert_eq!(split(&q, 0.0), (Quadratic::new((0.0, 0.0), (0.0, 0.0), (0.0, 0.0)), q.clone()));
+        assert_eq!(split(&q, 1.0), (q.clone(), Quadratic::new((4.0, 0.0), (4.0, 0.0), (4.0, 0.0))));
+    }
+}
@@ -0,0 +1,71 @@
+use super::{Bvh, Data, Node, allocate, fatten, insert_leaf};
+use crate::cartesian::d2::rect::rect_f32::Rect;
+
+pub fn insert<P>(t: &mut Bvh<P>, r: Rect, payload: P) -> usize {
+    let bounds = fatten(&r, t.margin);
+    let leaf = allocate(t, Node { bounds, parent: None, height: 0, data: Data::Leaf(r, payload) });
+    insert_leaf(t, leaf);
+    leaf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::insert;
+    use crate::cartesian::d2::{
+        bvh::bvh_f32::{Bvh, Data, Node},
+        rect::rect_f32::Rect,
+    };
+
+    #[test]
+    fn test_insert() {
+        let mut t = Bvh::new(1.0);
+        assert_eq!(insert(&mut t, Rect::new((0.0, 0.0), (2.0, 2.0)), 'a'), 0);
+        assert_eq!(t.root, Some(0));
+        assert_eq!(t.nodes, vec![Node { bounds: Rect::new((-1.0, -1.0), (3.0, 3.0)), parent: None, height: 0, data: Data::Leaf(Rect::new((0.0, 0.0), (2.0, 2.0)), 'a') }]);
+        assert_eq!(insert(&mut t, Rect::new((10.0, 0.0), (12.0, 2.0)), 'b'), 1);
+        assert_eq!(t.root, Some(2));
+        assert_eq!(t.nodes[2], Node { bounds: Rect::new((-1.0, -1.0), (13.0, 3.0)), parent: None, height: 1, data: Data::Branch(0, 1) });
+        assert_eq!(t.nodes[0].parent, Some(2));
+        assert_eq!(t.nodes[1].parent, Some(2));
+    }
+
+    #[test]
+    fn insert_sibling() {
+        let mut t = Bvh::new(0.0);
+        insert(&mut t, Rect::new((0.0, 0.0), (1.0, 1.0)), 'a');
+        insert(&mut t, Rect::new((100.0, 0.0), (101.0, 1.0)), 'b');
+        insert(&mut t, Rect::new((2.0, 0.0), (3.0, 1.0)), 'c');
+        assert_eq!(t.root, Some(2));
+        assert_eq!(t.nodes[2].data, Data::Branch(4, 1));
+        assert_eq!(t.nodes[4], Node { bounds: Rect::new((0.0, 0.0), (3.0, 1.0)), parent: Some(2), height: 1, data: Data::Branch(0, 3) });
+        assert_eq!(t.nodes[2].height, 2);
+    }
+
+    #[test]
+    fn insert_balance() {
+        let mut t = Bvh::new(0.0);
+        for i in 0..64u8 {
+            let x = f32::from(i) * 2.0;
+            insert(&mut t, Rect::new((x, 0.0), (x + 1.0, 1.0)), i);
+        }
+        let root = t.root.unwrap();
+        assert_eq!(t.nodes[root].bounds, Rect::new((0.0, 0.0), (127.0, 1.0)));
+        assert!(t.nodes[root].height <= 8);
+        assert_eq!(t.nodes.len(), 127);
+        for (index, node) in t.nodes.iter().enumerate() {
+            if let Data::Branch(a, b) = node.data {
+                assert_eq!(t.nodes[a].parent, Some(index));
+                assert_eq!(t.nodes[b].parent, Some(index));
+            }
+        }
+    }
+
+    #[test]
+    fn insert_bounds() {
+        let mut t = Bvh::new(0.0);
+        insert(&mut t, Rect::min(), 'a');
+        insert(&mut t, Rect::max(), 'b');
+        insert(&mut t, Rect::largest(), 'c');
+        assert_eq!(t.nodes[t.root.unwrap()].bounds, Rect::largest());
+    }
+}
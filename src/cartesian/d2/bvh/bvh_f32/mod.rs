@@ -0,0 +1,206 @@
+use crate::cartesian::d2::rect::rect_f32::{Rect, union_bounds};
+
+mod insert;
+mod query;
+mod remove;
+mod update;
+
+pub use self::insert::insert;
+pub use self::query::{query_pairs, query_ray, query_rect};
+pub use self::remove::remove;
+pub use self::update::update;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum Data<P> {
+    Leaf(Rect, P),
+    Branch(usize, usize),
+    Free,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Node<P> {
+    pub bounds: Rect,
+    pub parent: Option<usize>,
+    pub height: u32,
+    pub data: Data<P>,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Bvh<P> {
+    pub nodes: Vec<Node<P>>,
+    pub root: Option<usize>,
+    pub free: Vec<usize>,
+    pub margin: f32,
+}
+
+impl<P> Bvh<P> {
+    pub fn new(margin: f32) -> Self {
+        Bvh { nodes: Vec::new(), root: None, free: Vec::new(), margin }
+    }
+}
+
+pub fn get<P>(t: &Bvh<P>, handle: usize) -> Option<(&Rect, &P)> {
+    match &t.nodes.get(handle)?.data {
+        Data::Leaf(r, payload) => Some((r, payload)),
+        _ => None,
+    }
+}
+
+fn fatten(r: &Rect, margin: f32) -> Rect {
+    Rect::new((r.min.x - margin, r.min.y - margin), (r.max.x + margin, r.max.y + margin))
+}
+
+fn perimeter(r: &Rect) -> f32 {
+    2.0 * ((r.max.x - r.min.x) + (r.max.y - r.min.y))
+}
+
+fn children<P>(t: &Bvh<P>, index: usize) -> Option<(usize, usize)> {
+    match t.nodes[index].data {
+        Data::Branch(a, b) => Some((a, b)),
+        _ => None,
+    }
+}
+
+fn allocate<P>(t: &mut Bvh<P>, node: Node<P>) -> usize {
+    match t.free.pop() {
+        Some(index) => {
+            t.nodes[index] = node;
+            index
+        }
+        None => {
+            t.nodes.push(node);
+            t.nodes.len() - 1
+        }
+    }
+}
+
+fn release<P>(t: &mut Bvh<P>, index: usize) -> Data<P> {
+    t.free.push(index);
+    t.nodes[index].parent = None;
+    t.nodes[index].height = 0;
+    std::mem::replace(&mut t.nodes[index].data, Data::Free)
+}
+
+fn replace_child<P>(t: &mut Bvh<P>, parent: Option<usize>, old: usize, new: usize) {
+    t.nodes[new].parent = parent;
+    match parent {
+        Some(index) => {
+            if let Data::Branch(a, b) = &mut t.nodes[index].data {
+                if *a == old {
+                    *a = new;
+                } else {
+                    *b = new;
+                }
+            }
+        }
+        None => t.root = Some(new),
+    }
+}
+
+fn recompute<P>(t: &mut Bvh<P>, index: usize) {
+    if let Some((a, b)) = children(t, index) {
+        t.nodes[index].bounds = union_bounds(&t.nodes[a].bounds, &t.nodes[b].bounds);
+        t.nodes[index].height = 1 + t.nodes[a].height.max(t.nodes[b].height);
+    }
+}
+
+fn rotate<P>(t: &mut Bvh<P>, index: usize, up: usize) -> usize {
+    let Some((f, g)) = children(t, up) else { return index };
+    let (kept, moved) = if t.nodes[f].height > t.nodes[g].height { (f, g) } else { (g, f) };
+    replace_child(t, t.nodes[index].parent, index, up);
+    replace_child(t, Some(index), up, moved);
+    t.nodes[up].data = Data::Branch(index, kept);
+    t.nodes[index].parent = Some(up);
+    recompute(t, index);
+    recompute(t, up);
+    up
+}
+
+fn balance<P>(t: &mut Bvh<P>, index: usize) -> usize {
+    let Some((a, b)) = children(t, index) else { return index };
+    let (height_a, height_b) = (t.nodes[a].height, t.nodes[b].height);
+    if height_b > height_a + 1 {
+        rotate(t, index, b)
+    } else if height_a > height_b + 1 {
+        rotate(t, index, a)
+    } else {
+        index
+    }
+}
+
+fn refit<P>(t: &mut Bvh<P>, start: Option<usize>) {
+    let mut current = start;
+    while let Some(index) = current {
+        let index = balance(t, index);
+        recompute(t, index);
+        current = t.nodes[index].parent;
+    }
+}
+
+fn insert_leaf<P>(t: &mut Bvh<P>, leaf: usize) {
+    let Some(root) = t.root else {
+        t.root = Some(leaf);
+        return;
+    };
+    let bounds = t.nodes[leaf].bounds.clone();
+    let mut sibling = root;
+    while let Some((a, b)) = children(t, sibling) {
+        let combined = perimeter(&union_bounds(&t.nodes[sibling].bounds, &bounds));
+        let cost = 2.0 * combined;
+        let inheritance = 2.0 * (combined - perimeter(&t.nodes[sibling].bounds));
+        let descend_cost = |child: usize| {
+            let merged = perimeter(&union_bounds(&t.nodes[child].bounds, &bounds));
+            match t.nodes[child].data {
+                Data::Branch(_, _) => merged - perimeter(&t.nodes[child].bounds) + inheritance,
+                _ => merged + inheritance,
+            }
+        };
+        let (cost_a, cost_b) = (descend_cost(a), descend_cost(b));
+        if cost < cost_a && cost < cost_b {
+            break;
+        }
+        sibling = if cost_a < cost_b { a } else { b };
+    }
+    let parent = t.nodes[sibling].parent;
+    let branch = allocate(t, Node { bounds: union_bounds(&t.nodes[sibling].bounds, &bounds), parent: None, height: t.nodes[sibling].height + 1, data: Data::Branch(sibling, leaf) });
+    replace_child(t, parent, sibling, branch);
+    t.nodes[sibling].parent = Some(branch);
+    t.nodes[leaf].parent = Some(branch);
+    refit(t, parent);
+}
+
+fn remove_leaf<P>(t: &mut Bvh<P>, leaf: usize) {
+    let Some(parent) = t.nodes[leaf].parent else {
+        t.root = None;
+        return;
+    };
+    let Some((a, b)) = children(t, parent) else { return };
+    let sibling = if a == leaf { b } else { a };
+    let grandparent = t.nodes[parent].parent;
+    replace_child(t, grandparent, parent, sibling);
+    release(t, parent);
+    t.nodes[leaf].parent = None;
+    refit(t, grandparent);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bvh, get, insert};
+    use crate::cartesian::d2::rect::rect_f32::Rect;
+
+    #[test]
+    fn bvh() {
+        assert_eq!(Bvh::<u8>::new(0.5), Bvh { nodes: Vec::new(), root: None, free: Vec::new(), margin: 0.5 });
+    }
+
+    #[test]
+    fn test_get() {
+        let mut t = Bvh::new(0.5);
+        let a = insert(&mut t, Rect::new((0.0, 0.0), (1.0, 1.0)), 'a');
+        let b = insert(&mut t, Rect::new((4.0, 4.0), (5.0, 5.0)), 'b');
+        assert_eq!(get(&t, a), Some((&Rect::new((0.0, 0.0), (1.0, 1.0)), &'a')));
+        assert_eq!(get(&t, b), Some((&Rect::new((4.0, 4.0), (5.0, 5.0)), &'b')));
+        assert_eq!(get(&t, 2), None);
+        assert_eq!(get(&t, 3), None);
+    }
+}
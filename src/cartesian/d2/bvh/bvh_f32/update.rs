@@ -0,0 +1,62 @@
+use super::{Bvh, Data, fatten, insert_leaf, remove_leaf};
+use crate::cartesian::d2::rect::rect_f32::{Rect, contains_rect};
+
+pub fn update<P>(t: &mut Bvh<P>, handle: usize, r: Rect) -> bool {
+    let Some(node) = t.nodes.get_mut(handle) else { return false };
+    let Data::Leaf(tight, _) = &mut node.data else { return false };
+    let moved = !contains_rect(&node.bounds, &r);
+    if moved {
+        node.bounds = fatten(&r, t.margin);
+    }
+    *tight = r;
+    if moved {
+        remove_leaf(t, handle);
+        insert_leaf(t, handle);
+    }
+    moved
+}
+
+#[cfg(test)]
+mod tests {
+    use super::update;
+    use crate::cartesian::d2::{
+        bvh::bvh_f32::{Bvh, get, insert},
+        rect::rect_f32::Rect,
+    };
+
+    #[test]
+    fn test_update() {
+        let mut t = Bvh::new(1.0);
+        let a = insert(&mut t, Rect::new((0.0, 0.0), (2.0, 2.0)), 'a');
+        let b = insert(&mut t, Rect::new((10.0, 0.0), (12.0, 2.0)), 'b');
+        assert!(!update(&mut t, a, Rect::new((0.5, -0.5), (2.5, 1.5))));
+        assert_eq!(get(&t, a), Some((&Rect::new((0.5, -0.5), (2.5, 1.5)), &'a')));
+        assert_eq!(t.nodes[a].bounds, Rect::new((-1.0, -1.0), (3.0, 3.0)));
+        assert!(update(&mut t, a, Rect::new((20.0, 0.0), (22.0, 2.0))));
+        assert_eq!(get(&t, a), Some((&Rect::new((20.0, 0.0), (22.0, 2.0)), &'a')));
+        assert_eq!(t.nodes[a].bounds, Rect::new((19.0, -1.0), (23.0, 3.0)));
+        let root = t.root.unwrap();
+        assert_eq!(t.nodes[root].bounds, Rect::new((9.0, -1.0), (23.0, 3.0)));
+        assert_eq!(t.nodes[a].parent, Some(root));
+        assert_eq!(t.nodes[b].parent, Some(root));
+    }
+
+    #[test]
+    fn update_single() {
+        let mut t = Bvh::new(1.0);
+        let a = insert(&mut t, Rect::new((0.0, 0.0), (2.0, 2.0)), 'a');
+        assert!(update(&mut t, a, Rect::new((10.0, 0.0), (12.0, 2.0))));
+        assert_eq!(t.root, Some(a));
+        assert_eq!(t.nodes[a].bounds, Rect::new((9.0, -1.0), (13.0, 3.0)));
+        assert_eq!(t.nodes.len(), 1);
+    }
+
+    #[test]
+    fn update_invalid() {
+        let mut t = Bvh::new(1.0);
+        insert(&mut t, Rect::new((0.0, 0.0), (2.0, 2.0)), 'a');
+        insert(&mut t, Rect::new((10.0, 0.0), (12.0, 2.0)), 'b');
+        assert!(!update(&mut t, 2, Rect::new((10.0, 0.0), (12.0, 2.0))));
+        assert!(!update(&mut t, 3, Rect::new((10.0, 0.0), (12.0, 2.0))));
+    }
+}
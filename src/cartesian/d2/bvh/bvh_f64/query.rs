@@ -0,0 +1,130 @@
+use super::{Bvh, Data};
+use crate::cartesian::d2::{
+    ray::ray_f64::{Hit, Ray, cast_rect},
+    rect::rect_f64::{Rect, intersects},
+};
+
+fn query<P>(t: &Bvh<P>, skip: usize, r: &Rect) -> Vec<usize> {
+    let mut result = Vec::new();
+    let mut stack: Vec<usize> = t.root.into_iter().collect();
+    while let Some(index) = stack.pop() {
+        let node = &t.nodes[index];
+        if !intersects(&node.bounds, r) {
+            continue;
+        }
+        match &node.data {
+            Data::Leaf(tight, _) => {
+                if index != skip && intersects(tight, r) {
+                    result.push(index);
+                }
+            }
+            Data::Branch(a, b) => stack.extend([*b, *a]),
+            Data::Free => {}
+        }
+    }
+    result
+}
+
+pub fn query_rect<P>(t: &Bvh<P>, r: &Rect) -> Vec<usize> {
+    query(t, usize::MAX, r)
+}
+
+pub fn query_pairs<P>(t: &Bvh<P>) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    for (index, node) in t.nodes.iter().enumerate() {
+        if let Data::Leaf(tight, _) = &node.data {
+            pairs.extend(query(t, index, tight).into_iter().filter(|other| *other > index).map(|other| (index, other)));
+        }
+    }
+    pairs.sort();
+    pairs
+}
+
+pub fn query_ray<P>(t: &Bvh<P>, ray: &Ray) -> Vec<(usize, Hit)> {
+    let mut result = Vec::new();
+    let mut stack: Vec<usize> = t.root.into_iter().collect();
+    while let Some(index) = stack.pop() {
+        let node = &t.nodes[index];
+        if cast_rect(ray, &node.bounds).is_none() {
+            continue;
+        }
+        match &node.data {
+            Data::Leaf(tight, _) => result.extend(cast_rect(ray, tight).map(|hit| (index, hit))),
+            Data::Branch(a, b) => stack.extend([*b, *a]),
+            Data::Free => {}
+        }
+    }
+    result.sort_by(|a, b| a.1.enter.total_cmp(&b.1.enter));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{query_pairs, query_ray, query_rect};
+    use crate::cartesian::d2::{
+        bvh::bvh_f64::{Bvh, get, insert, remove, update},
+        ray::ray_f64::{Hit, Ray},
+        rect::rect_f64::Rect,
+    };
+
+    fn row() -> (Bvh<u8>, Vec<usize>) {
+        let mut t = Bvh::new(0.5);
+        let mut handles = Vec::new();
+        for i in 0..8u8 {
+            let x = f64::from(i) * 3.0;
+            handles.push(insert(&mut t, Rect::new((x, 0.0), (x + 2.0, 2.0)), i));
+        }
+        (t, handles)
+    }
+
+    fn payloads(t: &Bvh<u8>, handles: Vec<usize>) -> Vec<u8> {
+        let mut payloads: Vec<u8> = handles.into_iter().map(|handle| *get(t, handle).unwrap().1).collect();
+        payloads.sort();
+        payloads
+    }
+
+    #[test]
+    fn test_query_rect() {
+        let (t, _) = row();
+        assert_eq!(payloads(&t, query_rect(&t, &Rect::new((0.0, 0.0), (24.0, 2.0)))), vec![0, 1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(payloads(&t, query_rect(&t, &Rect::new((1.0, 1.0), (4.0, 1.0)))), vec![0, 1]);
+        assert_eq!(payloads(&t, query_rect(&t, &Rect::new((8.0, 2.0), (9.0, 3.0)))), vec![2, 3]);
+        assert_eq!(payloads(&t, query_rect(&t, &Rect::new((2.2, 0.0), (2.8, 2.0)))), vec![]);
+        assert_eq!(payloads(&t, query_rect(&t, &Rect::new((0.0, 2.2), (24.0, 3.0)))), vec![]);
+        assert_eq!(query_rect(&Bvh::<u8>::new(0.5), &Rect::largest()), vec![]);
+    }
+
+    #[test]
+    fn test_query_pairs() {
+        let (mut t, handles) = row();
+        assert_eq!(query_pairs(&t), vec![]);
+        update(&mut t, handles[1], Rect::new((1.0, 1.0), (6.0, 3.0)));
+        update(&mut t, handles[6], Rect::new((15.0, 0.0), (21.0, 1.0)));
+        let mut expected = vec![(handles[0], handles[1]), (handles[1], handles[2]), (handles[5], handles[6]), (handles[6], handles[7])];
+        assert_eq!(query_pairs(&t), expected);
+        remove(&mut t, handles[6]);
+        expected.truncate(2);
+        assert_eq!(query_pairs(&t), expected);
+        assert_eq!(query_pairs(&Bvh::<u8>::new(0.5)), vec![]);
+    }
+
+    #[test]
+    fn test_query_ray() {
+        let (t, handles) = row();
+        assert_eq!(
+            query_ray(&t, &Ray::new((5.5, 1.0), (1.0, 0.0))),
+            vec![
+                (handles[2], Hit::new(0.5, 2.5, (-1.0, 0.0))),
+                (handles[3], Hit::new(3.5, 5.5, (-1.0, 0.0))),
+                (handles[4], Hit::new(6.5, 8.5, (-1.0, 0.0))),
+                (handles[5], Hit::new(9.5, 11.5, (-1.0, 0.0))),
+                (handles[6], Hit::new(12.5, 14.5, (-1.0, 0.0))),
+                (handles[7], Hit::new(15.5, 17.5, (-1.0, 0.0))),
+            ]
+        );
+        assert_eq!(query_ray(&t, &Ray::new((4.0, 5.0), (0.0, -1.0))), vec![(handles[1], Hit::new(3.0, 5.0, (0.0, 1.0)))]);
+        assert_eq!(query_ray(&t, &Ray::new((1.0, 1.0), (-1.0, 0.0))), vec![(handles[0], Hit::new(-1.0, 1.0, (1.0, 0.0)))]);
+        assert_eq!(query_ray(&t, &Ray::new((2.5, 5.0), (0.0, -1.0))), vec![]);
+        assert_eq!(query_ray(&t, &Ray::new((0.0, 3.0), (1.0, 0.0))), vec![]);
+    }
+}
@@ -0,0 +1,66 @@
+use super::{Bvh, Data, release, remove_leaf};
+
+pub fn remove<P>(t: &mut Bvh<P>, handle: usize) -> Option<P> {
+    if !matches!(t.nodes.get(handle)?.data, Data::Leaf(_, _)) {
+        return None;
+    }
+    remove_leaf(t, handle);
+    match release(t, handle) {
+        Data::Leaf(_, payload) => Some(payload),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::remove;
+    use crate::cartesian::d2::{
+        bvh::bvh_f64::{Bvh, Data, get, insert},
+        rect::rect_f64::Rect,
+    };
+
+    #[test]
+    fn test_remove() {
+        let mut t = Bvh::new(0.0);
+        let a = insert(&mut t, Rect::new((0.0, 0.0), (1.0, 1.0)), 'a');
+        let b = insert(&mut t, Rect::new((4.0, 0.0), (5.0, 1.0)), 'b');
+        let c = insert(&mut t, Rect::new((8.0, 0.0), (9.0, 1.0)), 'c');
+        assert_eq!(remove(&mut t, b), Some('b'));
+        assert_eq!(remove(&mut t, b), None);
+        assert_eq!(get(&t, b), None);
+        let root = t.root.unwrap();
+        assert_eq!(t.nodes[root].bounds, Rect::new((0.0, 0.0), (9.0, 1.0)));
+        assert_eq!(t.nodes[root].height, 1);
+        assert_eq!(t.nodes[a].parent, Some(root));
+        assert_eq!(t.nodes[c].parent, Some(root));
+        assert_eq!(remove(&mut t, a), Some('a'));
+        assert_eq!(t.root, Some(c));
+        assert_eq!(t.nodes[c].parent, None);
+        assert_eq!(remove(&mut t, c), Some('c'));
+        assert_eq!(t.root, None);
+        assert!(t.nodes.iter().all(|node| node.data == Data::Free));
+    }
+
+    #[test]
+    fn remove_reuse() {
+        let mut t = Bvh::new(0.0);
+        let a = insert(&mut t, Rect::new((0.0, 0.0), (1.0, 1.0)), 'a');
+        let b = insert(&mut t, Rect::new((4.0, 0.0), (5.0, 1.0)), 'b');
+        assert_eq!(remove(&mut t, a), Some('a'));
+        assert_eq!(t.free, vec![2, 0]);
+        assert_eq!(insert(&mut t, Rect::new((2.0, 0.0), (3.0, 1.0)), 'c'), 0);
+        assert_eq!(t.root, Some(2));
+        assert_eq!(t.nodes[2].data, Data::Branch(b, 0));
+        assert_eq!(t.nodes.len(), 3);
+    }
+
+    #[test]
+    fn remove_invalid() {
+        let mut t = Bvh::new(0.0);
+        insert(&mut t, Rect::new((0.0, 0.0), (1.0, 1.0)), 'a');
+        insert(&mut t, Rect::new((4.0, 0.0), (5.0, 1.0)), 'b');
+        assert_eq!(remove(&mut t, 2), None);
+        assert_eq!(remove(&mut t, 3), None);
+        assert_eq!(t.nodes.len(), 3);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod bvh_f32;
+pub mod bvh_f64;
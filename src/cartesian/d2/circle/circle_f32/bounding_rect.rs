@@ -0,0 +1,76 @@
+use super::Circle;
+use crate::cartesian::{
+    d1::point::point_f32::{MAX, MIN},
+    d2::rect::rect_f32::Rect,
+};
+
+pub fn try_checked_bounding_rect(c: &Circle) -> Option<Rect> {
+    let min_x = c.p.x - c.r;
+    let min_y = c.p.y - c.r;
+    let max_x = c.p.x + c.r;
+    let max_y = c.p.y + c.r;
+    if min_x < MIN || min_y < MIN || max_x > MAX || max_y > MAX {
+        return None;
+    }
+    Some(Rect::new((min_x, min_y), (max_x, max_y)))
+}
+
+pub fn checked_bounding_rect(c: &Circle) -> Rect {
+    try_checked_bounding_rect(c).unwrap()
+}
+
+pub fn saturating_bounding_rect(c: &Circle) -> Rect {
+    let min_x = (c.p.x - c.r).clamp(MIN, MAX);
+    let min_y = (c.p.y - c.r).clamp(MIN, MAX);
+    let max_x = (c.p.x + c.r).clamp(MIN, MAX);
+    let max_y = (c.p.y + c.r).clamp(MIN, MAX);
+    Rect::new((min_x, min_y), (max_x, max_y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{checked_bounding_rect, saturating_bounding_rect, try_checked_bounding_rect};
+    use crate::cartesian::{
+        d1::point::point_f32::{MAX, MIN},
+        d2::{circle::circle_f32::Circle, point::point_f32::Point, rect::rect_f32::Rect},
+    };
+
+    #[test]
+    fn test_try_checked_bounding_rect() {
+        assert_eq!(try_checked_bounding_rect(&Circle::new(Point::new(10.0, 10.0), 5.0)), Some(Rect::new((5.0, 5.0), (15.0, 15.0))));
+        assert_eq!(try_checked_bounding_rect(&Circle::new(Point::new(10.0, 20.0), 0.0)), Some(Rect::new((10.0, 20.0), (10.0, 20.0))));
+        assert_eq!(try_checked_bounding_rect(&Circle::new(Point::new(7.0, 8.0), 7.0)), Some(Rect::new((0.0, 1.0), (14.0, 15.0))));
+    }
+
+    #[test]
+    fn try_checked_bounding_rect_bounds() {
+        assert_eq!(try_checked_bounding_rect(&Circle::new(Point::new(MIN + 5.0, MAX - 5.0), 5.0)), Some(Rect::new((MIN, MAX - 10.0), (MIN + 10.0, MAX))));
+        assert_eq!(try_checked_bounding_rect(&Circle::new(Point::zero(), MAX)), Some(Rect::new((MIN + 1.0, MIN + 1.0), (MAX, MAX))));
+        assert_eq!(try_checked_bounding_rect(&Circle::new(Point::new(MIN + 5.0, 0.0), 7.0)), None);
+        assert_eq!(try_checked_bounding_rect(&Circle::new(Point::new(0.0, MIN + 5.0), 7.0)), None);
+        assert_eq!(try_checked_bounding_rect(&Circle::new(Point::new(MAX - 5.0, 0.0), 7.0)), None);
+        assert_eq!(try_checked_bounding_rect(&Circle::new(Point::new(0.0, MAX - 5.0), 7.0)), None);
+    }
+
+    #[test]
+    fn test_checked_bounding_rect() {
+        assert_eq!(checked_bounding_rect(&Circle::new(Point::new(10.0, 10.0), 5.0)), Rect::new((5.0, 5.0), (15.0, 15.0)));
+        assert_eq!(checked_bounding_rect(&Circle::new(Point::new(10.0, 20.0), 0.0)), Rect::new((10.0, 20.0), (10.0, 20.0)));
+        assert_eq!(checked_bounding_rect(&Circle::new(Point::new(7.0, 8.0), 7.0)), Rect::new((0.0, 1.0), (14.0, 15.0)));
+    }
+
+    #[test]
+    fn test_saturating_bounding_rect() {
+        assert_eq!(saturating_bounding_rect(&Circle::new(Point::new(10.0, 10.0), 5.0)), Rect::new((5.0, 5.0), (15.0, 15.0)));
+        assert_eq!(saturating_bounding_rect(&Circle::new(Point::new(10.0, 20.0), 0.0)), Rect::new((10.0, 20.0), (10.0, 20.0)));
+        assert_eq!(saturating_bounding_rect(&Circle::new(Point::new(7.0, 8.0), 7.0)), Rect::new((0.0, 1.0), (14.0, 15.0)));
+    }
+
+    #[test]
+    fn saturating_bounding_rect_bounds() {
+        assert_eq!(saturating_bounding_rect(&Circle::new(Point::new(MIN + 5.0, MAX - 5.0), 10.0)), Rect::new((MIN, MAX - 15.0), (MIN + 15.0, MAX)));
+        assert_eq!(saturating_bounding_rect(&Circle::new(Point::zero(), MAX)), Rect::new((MIN + 1.0, MIN + 1.0), (MAX, MAX)));
+        assert_eq!(saturating_bounding_rect(&Circle::new(Point::min(), MAX)), Rect::largest_min());
+        assert_eq!(saturating_bounding_rect(&Circle::new(Point::max(), MAX)), Rect::new((0.0, 0.0), (MAX, MAX)));
+    }
+}
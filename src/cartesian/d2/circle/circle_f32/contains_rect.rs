@@ -0,0 +1,51 @@
+use super::{Circle, contains_point};
+use crate::cartesian::d2::{point::point_f32::Point, rect::rect_f32::Rect};
+
+pub fn contains_rect(c: &Circle, r: &Rect) -> bool {
+    contains_point(c, &r.min) && contains_point(c, &r.max) && contains_point(c, &Point::new(r.min.x, r.max.y)) && contains_point(c, &Point::new(r.max.x, r.min.y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::contains_rect;
+    use crate::cartesian::{
+        d1::point::point_f32::{MAX, MIN},
+        d2::{circle::circle_f32::Circle, point::point_f32::Point, rect::rect_f32::Rect},
+    };
+
+    #[test]
+    fn inside() {
+        let c = Circle::new(Point::new(10.0, 10.0), 5.0);
+        assert!(contains_rect(&c, &Rect::new((10.0, 10.0), (10.0, 10.0))));
+        assert!(contains_rect(&c, &Rect::new((8.0, 8.0), (12.0, 12.0))));
+        assert!(contains_rect(&c, &Rect::new((7.0, 7.0), (13.0, 13.0))));
+    }
+
+    #[test]
+    fn borders() {
+        let c = Circle::new(Point::new(10.0, 10.0), 5.0);
+        assert!(contains_rect(&c, &Rect::new((6.0, 7.0), (14.0, 13.0))));
+        assert!(contains_rect(&c, &Rect::new((7.0, 6.0), (13.0, 14.0))));
+        assert!(contains_rect(&c, &Rect::new((5.0, 10.0), (15.0, 10.0))));
+        assert!(contains_rect(&c, &Rect::new((10.0, 5.0), (10.0, 15.0))));
+    }
+
+    #[test]
+    fn outside() {
+        let c = Circle::new(Point::new(10.0, 10.0), 5.0);
+        assert!(!contains_rect(&c, &Rect::new((6.0, 6.0), (14.0, 14.0))));
+        assert!(!contains_rect(&c, &Rect::new((5.0, 9.0), (15.0, 10.0))));
+        assert!(!contains_rect(&c, &Rect::new((9.0, 5.0), (10.0, 15.0))));
+        assert!(!contains_rect(&c, &Rect::new((0.0, 0.0), (30.0, 30.0))));
+        assert!(!contains_rect(&c, &Rect::new((20.0, 20.0), (30.0, 30.0))));
+    }
+
+    #[test]
+    fn bounds() {
+        let c = Circle::new(Point::zero(), MAX);
+        assert!(contains_rect(&c, &Rect::new((MIN + 1.0, 0.0), (MAX, 0.0))));
+        assert!(contains_rect(&c, &Rect::new((0.0, MIN + 1.0), (0.0, MAX))));
+        assert!(!contains_rect(&c, &Rect::new((MIN, 0.0), (MAX, 0.0))));
+        assert!(!contains_rect(&c, &Rect::largest()));
+    }
+}
@@ -0,0 +1,97 @@
+use super::{Circle, contains_point};
+use crate::cartesian::d2::{point::point_f32::Point, rect::rect_f32::Rect};
+
+pub fn intersects_circle(a: &Circle, b: &Circle) -> bool {
+    let dx = b.p.x - a.p.x;
+    let dy = b.p.y - a.p.y;
+    let r = a.r + b.r;
+    dx.mul_add(dx, dy * dy) <= r * r
+}
+
+pub fn intersects_rect(c: &Circle, r: &Rect) -> bool {
+    let closest = Point::new(c.p.x.max(r.min.x).min(r.max.x), c.p.y.max(r.min.y).min(r.max.y));
+    contains_point(c, &closest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{intersects_circle, intersects_rect};
+    use crate::cartesian::{
+        d1::point::point_f32::{MAX, MIN},
+        d2::{circle::circle_f32::Circle, point::point_f32::Point, rect::rect_f32::Rect},
+    };
+
+    #[test]
+    fn test_intersects_circle() {
+        let c = Circle::new(Point::new(10.0, 10.0), 5.0);
+        assert!(intersects_circle(&c, &c));
+        assert!(intersects_circle(&c, &Circle::new(Point::new(10.0, 10.0), 1.0)));
+        assert!(intersects_circle(&c, &Circle::new(Point::new(12.0, 12.0), 1.0)));
+        assert!(intersects_circle(&c, &Circle::new(Point::new(15.0, 15.0), 5.0)));
+        assert!(intersects_circle(&c, &Circle::new(Point::new(20.0, 20.0), 20.0)));
+    }
+
+    #[test]
+    fn intersects_circle_borders() {
+        let c = Circle::new(Point::new(10.0, 10.0), 5.0);
+        assert!(intersects_circle(&c, &Circle::new(Point::new(20.0, 10.0), 5.0)));
+        assert!(intersects_circle(&c, &Circle::new(Point::new(10.0, 20.0), 5.0)));
+        assert!(intersects_circle(&c, &Circle::new(Point::new(16.0, 18.0), 5.0)));
+        assert!(intersects_circle(&c, &Circle::new(Point::new(13.0, 14.0), 0.0)));
+    }
+
+    #[test]
+    fn intersects_circle_outside() {
+        let c = Circle::new(Point::new(10.0, 10.0), 5.0);
+        assert!(!intersects_circle(&c, &Circle::new(Point::new(21.0, 10.0), 5.0)));
+        assert!(!intersects_circle(&c, &Circle::new(Point::new(10.0, 21.0), 5.0)));
+        assert!(!intersects_circle(&c, &Circle::new(Point::new(16.0, 19.0), 5.0)));
+        assert!(!intersects_circle(&c, &Circle::new(Point::new(14.0, 14.0), 0.0)));
+    }
+
+    #[test]
+    fn intersects_circle_bounds() {
+        assert!(intersects_circle(&Circle::new(Point::zero(), MAX), &Circle::new(Point::max(), MAX)));
+        assert!(!intersects_circle(&Circle::new(Point::min(), MAX), &Circle::new(Point::max(), MAX)));
+        assert!(intersects_circle(&Circle::new(Point::zero(), MAX), &Circle::new(Point::new(MAX, 0.0), 0.0)));
+        assert!(!intersects_circle(&Circle::new(Point::zero(), MAX), &Circle::new(Point::new(MIN, 0.0), 0.0)));
+        assert!(!intersects_circle(&Circle::new(Point::min(), 0.0), &Circle::new(Point::max(), 0.0)));
+    }
+
+    #[test]
+    fn test_intersects_rect() {
+        let c = Circle::new(Point::new(10.0, 10.0), 5.0);
+        assert!(intersects_rect(&c, &Rect::new((9.0, 9.0), (11.0, 11.0))));
+        assert!(intersects_rect(&c, &Rect::new((0.0, 0.0), (30.0, 30.0))));
+        assert!(intersects_rect(&c, &Rect::new((12.0, 0.0), (20.0, 30.0))));
+        assert!(intersects_rect(&c, &Rect::new((12.0, 12.0), (20.0, 20.0))));
+    }
+
+    #[test]
+    fn intersects_rect_borders() {
+        let c = Circle::new(Point::new(10.0, 10.0), 5.0);
+        assert!(intersects_rect(&c, &Rect::new((15.0, 0.0), (20.0, 20.0))));
+        assert!(intersects_rect(&c, &Rect::new((0.0, 0.0), (5.0, 20.0))));
+        assert!(intersects_rect(&c, &Rect::new((0.0, 15.0), (20.0, 20.0))));
+        assert!(intersects_rect(&c, &Rect::new((13.0, 14.0), (20.0, 20.0))));
+        assert!(intersects_rect(&c, &Rect::new((0.0, 0.0), (6.0, 7.0))));
+    }
+
+    #[test]
+    fn intersects_rect_outside() {
+        let c = Circle::new(Point::new(10.0, 10.0), 5.0);
+        assert!(!intersects_rect(&c, &Rect::new((16.0, 0.0), (20.0, 20.0))));
+        assert!(!intersects_rect(&c, &Rect::new((0.0, 0.0), (4.0, 20.0))));
+        assert!(!intersects_rect(&c, &Rect::new((0.0, 16.0), (20.0, 20.0))));
+        assert!(!intersects_rect(&c, &Rect::new((14.0, 14.0), (20.0, 20.0))));
+        assert!(!intersects_rect(&c, &Rect::new((0.0, 0.0), (6.0, 6.0))));
+    }
+
+    #[test]
+    fn intersects_rect_bounds() {
+        assert!(intersects_rect(&Circle::new(Point::min(), MAX), &Rect::largest()));
+        assert!(intersects_rect(&Circle::new(Point::zero(), MAX), &Rect::new((MAX, MIN), (MAX, MAX))));
+        assert!(!intersects_rect(&Circle::new(Point::zero(), MAX), &Rect::new((MIN, MIN), (MIN, MAX))));
+        assert!(!intersects_rect(&Circle::new(Point::min(), 1.0), &Rect::new((MIN + 1.0, MIN + 1.0), (MAX, MAX))));
+    }
+}
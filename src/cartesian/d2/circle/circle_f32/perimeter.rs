@@ -0,0 +1,30 @@
+use super::Circle;
+use std::f32;
+
+pub fn perimeter(c: &Circle) -> f32 {
+    f32::consts::TAU * c.r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::perimeter;
+    use crate::cartesian::{
+        d1::point::point_f32::MAX,
+        d2::{circle::circle_f32::Circle, point::point_f32::Point},
+    };
+    use std::f32::consts::TAU;
+
+    #[test]
+    fn test_perimeter() {
+        assert_eq!(perimeter(&Circle::new(Point::zero(), 1.0)), TAU);
+        assert_eq!(perimeter(&Circle::new(Point::zero(), 2.0)), 2.0 * TAU);
+        assert_eq!(perimeter(&Circle::new(Point::zero(), 4.0)), 4.0 * TAU);
+        assert_eq!(perimeter(&Circle::new(Point::zero(), 0.5)), 0.5 * TAU);
+    }
+
+    #[test]
+    fn perimeter_bounds() {
+        assert_eq!(perimeter(&Circle::new(Point::zero(), 0.0)), 0.0);
+        assert_eq!(perimeter(&Circle::new(Point::zero(), MAX)), TAU * MAX);
+    }
+}
@@ -0,0 +1,116 @@
+use super::Circle;
+use crate::cartesian::d1::point::point_f32::MAX;
+
+pub fn try_checked_scale_assign(c: &mut Circle, factor: f32) -> Option<()> {
+    let r = c.r * factor;
+    if !(0.0..=MAX).contains(&r) {
+        return None;
+    }
+    c.r = r;
+    Some(())
+}
+
+pub fn try_checked_scale(c: &Circle, factor: f32) -> Option<Circle> {
+    let r = c.r * factor;
+    if !(0.0..=MAX).contains(&r) {
+        return None;
+    }
+    Some(Circle { p: c.p.clone(), r })
+}
+
+pub fn checked_scale_assign(c: &mut Circle, factor: f32) {
+    try_checked_scale_assign(c, factor).unwrap()
+}
+
+pub fn checked_scale(c: &Circle, factor: f32) -> Circle {
+    try_checked_scale(c, factor).unwrap()
+}
+
+pub fn saturating_scale_assign(c: &mut Circle, factor: f32) {
+    c.r = (c.r * factor).clamp(0.0, MAX);
+}
+
+pub fn saturating_scale(c: &Circle, factor: f32) -> Circle {
+    Circle { p: c.p.clone(), r: (c.r * factor).clamp(0.0, MAX) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{checked_scale, checked_scale_assign, saturating_scale, saturating_scale_assign, try_checked_scale, try_checked_scale_assign};
+    use crate::cartesian::{
+        d1::point::point_f32::MAX,
+        d2::{circle::circle_f32::Circle, point::point_f32::Point},
+    };
+
+    #[test]
+    fn test_try_checked_scale_assign() {
+        let mut c = Circle::new(Point::new(10.0, 10.0), 5.0);
+        assert_eq!(try_checked_scale_assign(&mut c, 3.0), Some(()));
+        assert_eq!(c, Circle::new(Point::new(10.0, 10.0), 15.0));
+        assert_eq!(try_checked_scale_assign(&mut c, 1.0), Some(()));
+        assert_eq!(c, Circle::new(Point::new(10.0, 10.0), 15.0));
+        assert_eq!(try_checked_scale_assign(&mut c, 0.0), Some(()));
+        assert_eq!(c, Circle::new(Point::new(10.0, 10.0), 0.0));
+    }
+
+    #[test]
+    fn try_checked_scale_assign_bounds() {
+        let mut c = Circle::new(Point::min(), MAX);
+        assert_eq!(try_checked_scale_assign(&mut c, 1.0), Some(()));
+        assert_eq!(c, Circle::new(Point::min(), MAX));
+        assert_eq!(try_checked_scale_assign(&mut c, 2.0), None);
+        assert_eq!(c, Circle::new(Point::min(), MAX));
+    }
+
+    #[test]
+    fn test_try_checked_scale() {
+        assert_eq!(try_checked_scale(&Circle::new(Point::new(10.0, 10.0), 5.0), 3.0), Some(Circle::new(Point::new(10.0, 10.0), 15.0)));
+        assert_eq!(try_checked_scale(&Circle::new(Point::new(10.0, 10.0), 5.0), 1.0), Some(Circle::new(Point::new(10.0, 10.0), 5.0)));
+        assert_eq!(try_checked_scale(&Circle::new(Point::new(10.0, 10.0), 5.0), 0.0), Some(Circle::new(Point::new(10.0, 10.0), 0.0)));
+        assert_eq!(try_checked_scale(&Circle::new(Point::zero(), 4.0), 0.5), Some(Circle::new(Point::zero(), 2.0)));
+        assert_eq!(try_checked_scale(&Circle::new(Point::zero(), 4.0), -1.0), None);
+    }
+
+    #[test]
+    fn try_checked_scale_bounds() {
+        assert_eq!(try_checked_scale(&Circle::new(Point::max(), MAX), 1.0), Some(Circle::new(Point::max(), MAX)));
+        assert_eq!(try_checked_scale(&Circle::new(Point::max(), MAX), 2.0), None);
+        assert_eq!(try_checked_scale(&Circle::new(Point::max(), MAX / 2.0 + 1.0), 2.0), None);
+    }
+
+    #[test]
+    fn test_checked_scale_assign() {
+        let mut c = Circle::new(Point::new(10.0, 10.0), 5.0);
+        checked_scale_assign(&mut c, 3.0);
+        assert_eq!(c, Circle::new(Point::new(10.0, 10.0), 15.0));
+        checked_scale_assign(&mut c, 2.0);
+        assert_eq!(c, Circle::new(Point::new(10.0, 10.0), 30.0));
+    }
+
+    #[test]
+    fn test_checked_scale() {
+        assert_eq!(checked_scale(&Circle::new(Point::new(10.0, 10.0), 5.0), 3.0), Circle::new(Point::new(10.0, 10.0), 15.0));
+        assert_eq!(checked_scale(&Circle::new(Point::new(10.0, 10.0), 5.0), 6.0), Circle::new(Point::new(10.0, 10.0), 30.0));
+    }
+
+    #[test]
+    fn test_saturating_scale_assign() {
+        let mut c = Circle::new(Point::new(10.0, 10.0), 5.0);
+        saturating_scale_assign(&mut c, 3.0);
+        assert_eq!(c, Circle::new(Point::new(10.0, 10.0), 15.0));
+        saturating_scale_assign(&mut c, MAX);
+        assert_eq!(c, Circle::new(Point::new(10.0, 10.0), MAX));
+        saturating_scale_assign(&mut c, 0.0);
+        assert_eq!(c, Circle::new(Point::new(10.0, 10.0), 0.0));
+    }
+
+    #[test]
+    fn test_saturating_scale() {
+        assert_eq!(saturating_scale(&Circle::new(Point::new(10.0, 10.0), 5.0), 3.0), Circle::new(Point::new(10.0, 10.0), 15.0));
+        assert_eq!(saturating_scale(&Circle::new(Point::new(10.0, 10.0), 5.0), 0.0), Circle::new(Point::new(10.0, 10.0), 0.0));
+        assert_eq!(saturating_scale(&Circle::new(Point::min(), MAX), 2.0), Circle::new(Point::min(), MAX));
+        assert_eq!(saturating_scale(&Circle::new(Point::max(), MAX / 2.0 + 1.0), 2.0), Circle::new(Point::max(), MAX));
+        assert_eq!(saturating_scale(&Circle::new(Point::zero(), 4.0), 0.5), Circle::new(Point::zero(), 2.0));
+        assert_eq!(saturating_scale(&Circle::new(Point::zero(), 4.0), -1.0), Circle::new(Point::zero(), 0.0));
+    }
+}
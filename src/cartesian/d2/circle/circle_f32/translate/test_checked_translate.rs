@@ -0,0 +1,8 @@
+use super::checked_translate;
+use crate::cartesian::d2::{circle::circle_f32::Circle, point::point_f32::Point};
+
+#[test]
+fn test() {
+    assert_eq!(checked_translate(&Circle::new(Point::new(10.0, 10.0), 5.0), &Point::new(5.0, -3.0)), Circle::new(Point::new(15.0, 7.0), 5.0));
+    assert_eq!(checked_translate(&Circle::new(Point::new(15.0, 7.0), 5.0), &Point::new(-10.0, 3.0)), Circle::new(Point::new(5.0, 10.0), 5.0));
+}
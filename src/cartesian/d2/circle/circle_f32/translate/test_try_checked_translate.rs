@@ -0,0 +1,19 @@
+use super::try_checked_translate;
+use crate::cartesian::{
+    d1::point::point_f32::{MAX, MIN},
+    d2::{circle::circle_f32::Circle, point::point_f32::Point},
+};
+
+#[test]
+fn test() {
+    assert_eq!(try_checked_translate(&Circle::new(Point::new(10.0, 10.0), 5.0), &Point::new(5.0, -3.0)), Some(Circle::new(Point::new(15.0, 7.0), 5.0)));
+    assert_eq!(try_checked_translate(&Circle::new(Point::new(15.0, 7.0), 5.0), &Point::new(-10.0, 3.0)), Some(Circle::new(Point::new(5.0, 10.0), 5.0)));
+}
+
+#[test]
+fn out_of_bounds() {
+    let c = Circle::new(Point::new(MAX - 2.0, MIN + 5.0), 5.0);
+    assert_eq!(try_checked_translate(&c, &Point::new(2.0, -5.0)), Some(Circle::new(Point::new(MAX, MIN), 5.0)));
+    assert_eq!(try_checked_translate(&c, &Point::new(10.0, 0.0)), None);
+    assert_eq!(try_checked_translate(&c, &Point::new(0.0, -10.0)), None);
+}
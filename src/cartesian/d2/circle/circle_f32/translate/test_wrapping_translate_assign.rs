@@ -0,0 +1,21 @@
+use super::wrapping_translate_assign;
+use crate::cartesian::{
+    d1::point::point_f32::{MAX, MIN},
+    d2::{circle::circle_f32::Circle, point::point_f32::Point},
+};
+
+#[test]
+fn test() {
+    let mut c = Circle::new(Point::new(10.0, 10.0), 5.0);
+    wrapping_translate_assign(&mut c, &Point::new(5.0, -3.0));
+    assert_eq!(c, Circle::new(Point::new(15.0, 7.0), 5.0));
+    wrapping_translate_assign(&mut c, &Point::new(-10.0, 3.0));
+    assert_eq!(c, Circle::new(Point::new(5.0, 10.0), 5.0));
+}
+
+#[test]
+fn out_of_bounds() {
+    let mut c = Circle::new(Point::new(MAX - 2.0, MIN + 5.0), 5.0);
+    wrapping_translate_assign(&mut c, &Point::new(10.0, -10.0));
+    assert_eq!(c, Circle::new(Point::new(MIN + 7.0, MAX - 4.0), 5.0));
+}
@@ -0,0 +1,59 @@
+use super::Circle;
+use crate::cartesian::d2::point::point_f64::Point;
+
+pub fn contains_point(c: &Circle, p: &Point) -> bool {
+    let dx = p.x - c.p.x;
+    let dy = p.y - c.p.y;
+    dx.mul_add(dx, dy * dy) <= c.r * c.r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::contains_point;
+    use crate::cartesian::{
+        d1::point::point_f64::{MAX, MIN},
+        d2::{circle::circle_f64::Circle, point::point_f64::Point},
+    };
+
+    #[test]
+    fn inside() {
+        let c = Circle::new(Point::new(10.0, 10.0), 5.0);
+        assert!(contains_point(&c, &Point::new(10.0, 10.0)));
+        assert!(contains_point(&c, &Point::new(12.0, 12.0)));
+        assert!(contains_point(&c, &Point::new(7.0, 13.0)));
+        assert!(contains_point(&c, &Point::new(14.0, 8.0)));
+    }
+
+    #[test]
+    fn borders() {
+        let c = Circle::new(Point::new(10.0, 10.0), 5.0);
+        assert!(contains_point(&c, &Point::new(15.0, 10.0)));
+        assert!(contains_point(&c, &Point::new(5.0, 10.0)));
+        assert!(contains_point(&c, &Point::new(10.0, 15.0)));
+        assert!(contains_point(&c, &Point::new(10.0, 5.0)));
+        assert!(contains_point(&c, &Point::new(13.0, 14.0)));
+        assert!(contains_point(&c, &Point::new(6.0, 7.0)));
+    }
+
+    #[test]
+    fn outside() {
+        let c = Circle::new(Point::new(10.0, 10.0), 5.0);
+        assert!(!contains_point(&c, &Point::new(16.0, 10.0)));
+        assert!(!contains_point(&c, &Point::new(4.0, 10.0)));
+        assert!(!contains_point(&c, &Point::new(10.0, 16.0)));
+        assert!(!contains_point(&c, &Point::new(10.0, 4.0)));
+        assert!(!contains_point(&c, &Point::new(14.0, 14.0)));
+        assert!(!contains_point(&c, &Point::new(6.0, 6.0)));
+    }
+
+    #[test]
+    fn bounds() {
+        let c = Circle::new(Point::zero(), MAX);
+        assert!(contains_point(&c, &Point::new(MAX, 0.0)));
+        assert!(contains_point(&c, &Point::new(0.0, MIN + 1.0)));
+        assert!(!contains_point(&c, &Point::new(MIN, 0.0)));
+        assert!(!contains_point(&c, &Point::new(0.0, MIN)));
+        assert!(!contains_point(&c, &Point::max()));
+        assert!(!contains_point(&c, &Point::min()));
+    }
+}
@@ -1,8 +1,24 @@
 use crate::cartesian::d2::{circle::circle_f32, point::point_f64::Point};
 
 mod area;
+mod bounding_rect;
+mod contains_point;
+mod contains_rect;
+mod intersects;
+mod perimeter;
+mod scale;
+mod translate;
 
 pub use self::area::area;
+pub use self::bounding_rect::{checked_bounding_rect, saturating_bounding_rect, try_checked_bounding_rect};
+pub use self::contains_point::contains_point;
+pub use self::contains_rect::contains_rect;
+pub use self::intersects::{intersects_circle, intersects_rect};
+pub use self::perimeter::perimeter;
+pub use self::scale::{checked_scale, checked_scale_assign, saturating_scale, saturating_scale_assign, try_checked_scale, try_checked_scale_assign};
+pub use self::translate::{
+    checked_translate, checked_translate_assign, saturating_translate, saturating_translate_assign, try_checked_translate, try_checked_translate_assign, wrapping_translate, wrapping_translate_assign,
+};
 
 #[derive(PartialEq, Debug, Clone)]
 pub struct Circle {
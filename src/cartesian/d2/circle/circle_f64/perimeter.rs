@@ -0,0 +1,30 @@
+use super::Circle;
+use std::f64;
+
+pub fn perimeter(c: &Circle) -> f64 {
+    f64::consts::TAU * c.r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::perimeter;
+    use crate::cartesian::{
+        d1::point::point_f64::MAX,
+        d2::{circle::circle_f64::Circle, point::point_f64::Point},
+    };
+    use std::f64::consts::TAU;
+
+    #[test]
+    fn test_perimeter() {
+        assert_eq!(perimeter(&Circle::new(Point::zero(), 1.0)), TAU);
+        assert_eq!(perimeter(&Circle::new(Point::zero(), 2.0)), 2.0 * TAU);
+        assert_eq!(perimeter(&Circle::new(Point::zero(), 4.0)), 4.0 * TAU);
+        assert_eq!(perimeter(&Circle::new(Point::zero(), 0.5)), 0.5 * TAU);
+    }
+
+    #[test]
+    fn perimeter_bounds() {
+        assert_eq!(perimeter(&Circle::new(Point::zero(), 0.0)), 0.0);
+        assert_eq!(perimeter(&Circle::new(Point::zero(), MAX)), TAU * MAX);
+    }
+}
@@ -0,0 +1,11 @@
+use super::checked_translate_assign;
+use crate::cartesian::d2::{circle::circle_f64::Circle, point::point_f64::Point};
+
+#[test]
+fn test() {
+    let mut c = Circle::new(Point::new(10.0, 10.0), 5.0);
+    checked_translate_assign(&mut c, &Point::new(5.0, -3.0));
+    assert_eq!(c, Circle::new(Point::new(15.0, 7.0), 5.0));
+    checked_translate_assign(&mut c, &Point::new(-10.0, 3.0));
+    assert_eq!(c, Circle::new(Point::new(5.0, 10.0), 5.0));
+}
@@ -0,0 +1,17 @@
+use super::saturating_translate;
+use crate::cartesian::{
+    d1::point::point_i16::{MAX, MIN},
+    d2::{circle::circle_i16::Circle, point::point_i16::Point},
+};
+
+#[test]
+fn test() {
+    assert_eq!(saturating_translate(&Circle::new(Point::new(10, 10), 5), &Point::new(5, -3)), Circle::new(Point::new(15, 7), 5));
+    assert_eq!(saturating_translate(&Circle::new(Point::new(15, 7), 5), &Point::new(-10, 3)), Circle::new(Point::new(5, 10), 5));
+}
+
+#[test]
+fn out_of_bounds() {
+    let c = Circle::new(Point::new(MAX - 1, MIN), 5);
+    assert_eq!(saturating_translate(&c, &Point::new(2, -1)), Circle::new(Point::new(MAX, MIN), 5));
+}
@@ -0,0 +1,19 @@
+use super::try_checked_translate;
+use crate::cartesian::{
+    d1::point::point_i16::{MAX, MIN},
+    d2::{circle::circle_i16::Circle, point::point_i16::Point},
+};
+
+#[test]
+fn test() {
+    assert_eq!(try_checked_translate(&Circle::new(Point::new(10, 10), 5), &Point::new(5, -3)), Some(Circle::new(Point::new(15, 7), 5)));
+    assert_eq!(try_checked_translate(&Circle::new(Point::new(15, 7), 5), &Point::new(-10, 3)), Some(Circle::new(Point::new(5, 10), 5)));
+}
+
+#[test]
+fn out_of_bounds() {
+    let c = Circle::new(Point::new(MAX - 1, MIN), 5);
+    assert_eq!(try_checked_translate(&c, &Point::new(1, 0)), Some(Circle::new(Point::new(MAX, MIN), 5)));
+    assert_eq!(try_checked_translate(&c, &Point::new(2, 0)), None);
+    assert_eq!(try_checked_translate(&c, &Point::new(0, -1)), None);
+}
@@ -0,0 +1,58 @@
+use super::Circle;
+use crate::cartesian::d2::point::point_i32::{self, Point};
+
+pub fn try_checked_translate_assign(c: &mut Circle, delta: &Point) -> Option<()> {
+    point_i32::try_checked_add_assign(&mut c.p, delta)
+}
+
+pub fn try_checked_translate(c: &Circle, delta: &Point) -> Option<Circle> {
+    Some(Circle { p: point_i32::try_checked_add(&c.p, delta)?, r: c.r })
+}
+
+pub fn checked_translate_assign(c: &mut Circle, delta: &Point) {
+    try_checked_translate_assign(c, delta).unwrap()
+}
+
+pub fn checked_translate(c: &Circle, delta: &Point) -> Circle {
+    try_checked_translate(c, delta).unwrap()
+}
+
+pub fn saturating_translate_assign(c: &mut Circle, delta: &Point) {
+    point_i32::saturating_add_assign(&mut c.p, delta);
+}
+
+pub fn saturating_translate(c: &Circle, delta: &Point) -> Circle {
+    Circle { p: point_i32::saturating_add(&c.p, delta), r: c.r }
+}
+
+pub fn wrapping_translate_assign(c: &mut Circle, delta: &Point) {
+    point_i32::wrapping_add_assign(&mut c.p, delta);
+}
+
+pub fn wrapping_translate(c: &Circle, delta: &Point) -> Circle {
+    Circle { p: point_i32::wrapping_add(&c.p, delta), r: c.r }
+}
+
+#[cfg(test)]
+mod test_try_checked_translate_assign;
+
+#[cfg(test)]
+mod test_try_checked_translate;
+
+#[cfg(test)]
+mod test_checked_translate_assign;
+
+#[cfg(test)]
+mod test_checked_translate;
+
+#[cfg(test)]
+mod test_saturating_translate_assign;
+
+#[cfg(test)]
+mod test_saturating_translate;
+
+#[cfg(test)]
+mod test_wrapping_translate_assign;
+
+#[cfg(test)]
+mod test_wrapping_translate;
@@ -0,0 +1,16 @@
+use super::wrapping_translate;
+use crate::cartesian::{
+    d1::point::point_i32::{MAX, MIN},
+    d2::{circle::circle_i32::Circle, point::point_i32::Point},
+};
+
+#[test]
+fn test() {
+    assert_eq!(wrapping_translate(&Circle::new(Point::new(10, 10), 5), &Point::new(5, -3)), Circle::new(Point::new(15, 7), 5));
+    assert_eq!(wrapping_translate(&Circle::new(Point::new(15, 7), 5), &Point::new(-10, 3)), Circle::new(Point::new(5, 10), 5));
+}
+
+#[test]
+fn out_of_bounds() {
+    assert_eq!(wrapping_translate(&Circle::new(Point::new(MAX, MIN), 5), &Point::new(1, -1)), Circle::new(Point::new(MIN, MAX), 5));
+}
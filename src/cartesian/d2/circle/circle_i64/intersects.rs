@@ -0,0 +1,107 @@
+use super::{Circle, contains_point};
+use crate::cartesian::d2::{point::point_i64::Point, rect::rect_i64::Rect};
+
+pub fn intersects_circle(a: &Circle, b: &Circle) -> bool {
+    let dx = u128::from(a.p.x.abs_diff(b.p.x));
+    let dy = u128::from(a.p.y.abs_diff(b.p.y));
+    let r = u128::from(a.r) + u128::from(b.r);
+    match ((dx * dx).checked_add(dy * dy), r.checked_mul(r)) {
+        (Some(d), Some(r_squared)) => d <= r_squared,
+        (None, Some(_)) => false,
+        (Some(_), None) => true,
+        (None, None) => {
+            let d_excess = (dx * dx).wrapping_add(dy * dy);
+            let r_excess = r - (1 << 64);
+            r_excess.checked_mul(1 << 65).and_then(|v| v.checked_add(r_excess * r_excess)).is_none_or(|r_squared_excess| d_excess <= r_squared_excess)
+        }
+    }
+}
+
+pub fn intersects_rect(c: &Circle, r: &Rect) -> bool {
+    let closest = Point::new(c.p.x.max(r.min.x).min(r.max.x), c.p.y.max(r.min.y).min(r.max.y));
+    contains_point(c, &closest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{intersects_circle, intersects_rect};
+    use crate::cartesian::{
+        d1::point::point_i64::{MAX, MIN},
+        d2::{circle::circle_i64::Circle, point::point_i64::Point, rect::rect_i64::Rect},
+    };
+
+    #[test]
+    fn test_intersects_circle() {
+        let c = Circle::new(Point::new(10, 10), 5);
+        assert!(intersects_circle(&c, &c));
+        assert!(intersects_circle(&c, &Circle::new(Point::new(10, 10), 1)));
+        assert!(intersects_circle(&c, &Circle::new(Point::new(12, 12), 1)));
+        assert!(intersects_circle(&c, &Circle::new(Point::new(15, 15), 5)));
+        assert!(intersects_circle(&c, &Circle::new(Point::new(20, 20), 20)));
+    }
+
+    #[test]
+    fn intersects_circle_borders() {
+        let c = Circle::new(Point::new(10, 10), 5);
+        assert!(intersects_circle(&c, &Circle::new(Point::new(20, 10), 5)));
+        assert!(intersects_circle(&c, &Circle::new(Point::new(10, 20), 5)));
+        assert!(intersects_circle(&c, &Circle::new(Point::new(16, 18), 5)));
+        assert!(intersects_circle(&c, &Circle::new(Point::new(13, 14), 0)));
+    }
+
+    #[test]
+    fn intersects_circle_outside() {
+        let c = Circle::new(Point::new(10, 10), 5);
+        assert!(!intersects_circle(&c, &Circle::new(Point::new(21, 10), 5)));
+        assert!(!intersects_circle(&c, &Circle::new(Point::new(10, 21), 5)));
+        assert!(!intersects_circle(&c, &Circle::new(Point::new(16, 19), 5)));
+        assert!(!intersects_circle(&c, &Circle::new(Point::new(14, 14), 0)));
+    }
+
+    #[test]
+    fn intersects_circle_bounds() {
+        assert!(intersects_circle(&Circle::new(Point::min(), u64::MAX), &Circle::new(Point::max(), u64::MAX)));
+        assert!(intersects_circle(&Circle::new(Point::min(), u64::MAX), &Circle::new(Point::new(MAX, MIN), 0)));
+        assert!(intersects_circle(&Circle::new(Point::min(), u64::MAX), &Circle::new(Point::new(MIN + 1, MIN), 1)));
+        assert!(!intersects_circle(&Circle::new(Point::min(), u64::MAX), &Circle::new(Point::new(MAX, MIN + 1), 0)));
+        assert!(!intersects_circle(&Circle::new(Point::min(), 0), &Circle::new(Point::max(), 0)));
+        assert!(!intersects_circle(&Circle::new(Point::min(), u64::MAX / 2 + 1), &Circle::new(Point::max(), u64::MAX / 2 + 1)));
+    }
+
+    #[test]
+    fn test_intersects_rect() {
+        let c = Circle::new(Point::new(10, 10), 5);
+        assert!(intersects_rect(&c, &Rect::new((9, 9), (11, 11))));
+        assert!(intersects_rect(&c, &Rect::new((0, 0), (30, 30))));
+        assert!(intersects_rect(&c, &Rect::new((12, 0), (20, 30))));
+        assert!(intersects_rect(&c, &Rect::new((12, 12), (20, 20))));
+    }
+
+    #[test]
+    fn intersects_rect_borders() {
+        let c = Circle::new(Point::new(10, 10), 5);
+        assert!(intersects_rect(&c, &Rect::new((15, 0), (20, 20))));
+        assert!(intersects_rect(&c, &Rect::new((0, 0), (5, 20))));
+        assert!(intersects_rect(&c, &Rect::new((0, 15), (20, 20))));
+        assert!(intersects_rect(&c, &Rect::new((13, 14), (20, 20))));
+        assert!(intersects_rect(&c, &Rect::new((0, 0), (6, 7))));
+    }
+
+    #[test]
+    fn intersects_rect_outside() {
+        let c = Circle::new(Point::new(10, 10), 5);
+        assert!(!intersects_rect(&c, &Rect::new((16, 0), (20, 20))));
+        assert!(!intersects_rect(&c, &Rect::new((0, 0), (4, 20))));
+        assert!(!intersects_rect(&c, &Rect::new((0, 16), (20, 20))));
+        assert!(!intersects_rect(&c, &Rect::new((14, 14), (20, 20))));
+        assert!(!intersects_rect(&c, &Rect::new((0, 0), (6, 6))));
+    }
+
+    #[test]
+    fn intersects_rect_bounds() {
+        assert!(intersects_rect(&Circle::new(Point::min(), u64::MAX), &Rect::largest()));
+        assert!(intersects_rect(&Circle::new(Point::min(), u64::MAX), &Rect::new((MAX, MIN), (MAX, MAX))));
+        assert!(!intersects_rect(&Circle::new(Point::min(), u64::MAX), &Rect::new((MAX, MIN + 1), (MAX, MAX))));
+        assert!(!intersects_rect(&Circle::new(Point::min(), 1), &Rect::new((MIN + 1, MIN + 1), (MAX, MAX))));
+    }
+}
@@ -0,0 +1,100 @@
+use super::Circle;
+
+pub fn try_checked_scale_assign(c: &mut Circle, factor: u64) -> Option<()> {
+    c.r = c.r.checked_mul(factor)?;
+    Some(())
+}
+
+pub fn try_checked_scale(c: &Circle, factor: u64) -> Option<Circle> {
+    Some(Circle { p: c.p.clone(), r: c.r.checked_mul(factor)? })
+}
+
+pub fn checked_scale_assign(c: &mut Circle, factor: u64) {
+    try_checked_scale_assign(c, factor).unwrap()
+}
+
+pub fn checked_scale(c: &Circle, factor: u64) -> Circle {
+    try_checked_scale(c, factor).unwrap()
+}
+
+pub fn saturating_scale_assign(c: &mut Circle, factor: u64) {
+    c.r = c.r.saturating_mul(factor);
+}
+
+pub fn saturating_scale(c: &Circle, factor: u64) -> Circle {
+    Circle { p: c.p.clone(), r: c.r.saturating_mul(factor) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{checked_scale, checked_scale_assign, saturating_scale, saturating_scale_assign, try_checked_scale, try_checked_scale_assign};
+    use crate::cartesian::d2::{circle::circle_i64::Circle, point::point_i64::Point};
+
+    #[test]
+    fn test_try_checked_scale_assign() {
+        let mut c = Circle::new(Point::new(10, 10), 5);
+        assert_eq!(try_checked_scale_assign(&mut c, 3), Some(()));
+        assert_eq!(c, Circle::new(Point::new(10, 10), 15));
+        assert_eq!(try_checked_scale_assign(&mut c, 1), Some(()));
+        assert_eq!(c, Circle::new(Point::new(10, 10), 15));
+        assert_eq!(try_checked_scale_assign(&mut c, 0), Some(()));
+        assert_eq!(c, Circle::new(Point::new(10, 10), 0));
+    }
+
+    #[test]
+    fn try_checked_scale_assign_bounds() {
+        let mut c = Circle::new(Point::min(), u64::MAX);
+        assert_eq!(try_checked_scale_assign(&mut c, 1), Some(()));
+        assert_eq!(c, Circle::new(Point::min(), u64::MAX));
+        assert_eq!(try_checked_scale_assign(&mut c, 2), None);
+        assert_eq!(c, Circle::new(Point::min(), u64::MAX));
+    }
+
+    #[test]
+    fn test_try_checked_scale() {
+        assert_eq!(try_checked_scale(&Circle::new(Point::new(10, 10), 5), 3), Some(Circle::new(Point::new(10, 10), 15)));
+        assert_eq!(try_checked_scale(&Circle::new(Point::new(10, 10), 5), 1), Some(Circle::new(Point::new(10, 10), 5)));
+        assert_eq!(try_checked_scale(&Circle::new(Point::new(10, 10), 5), 0), Some(Circle::new(Point::new(10, 10), 0)));
+    }
+
+    #[test]
+    fn try_checked_scale_bounds() {
+        assert_eq!(try_checked_scale(&Circle::new(Point::max(), u64::MAX), 1), Some(Circle::new(Point::max(), u64::MAX)));
+        assert_eq!(try_checked_scale(&Circle::new(Point::max(), u64::MAX), 2), None);
+        assert_eq!(try_checked_scale(&Circle::new(Point::max(), u64::MAX / 2 + 1), 2), None);
+    }
+
+    #[test]
+    fn test_checked_scale_assign() {
+        let mut c = Circle::new(Point::new(10, 10), 5);
+        checked_scale_assign(&mut c, 3);
+        assert_eq!(c, Circle::new(Point::new(10, 10), 15));
+        checked_scale_assign(&mut c, 2);
+        assert_eq!(c, Circle::new(Point::new(10, 10), 30));
+    }
+
+    #[test]
+    fn test_checked_scale() {
+        assert_eq!(checked_scale(&Circle::new(Point::new(10, 10), 5), 3), Circle::new(Point::new(10, 10), 15));
+        assert_eq!(checked_scale(&Circle::new(Point::new(10, 10), 5), 6), Circle::new(Point::new(10, 10), 30));
+    }
+
+    #[test]
+    fn test_saturating_scale_assign() {
+        let mut c = Circle::new(Point::new(10, 10), 5);
+        saturating_scale_assign(&mut c, 3);
+        assert_eq!(c, Circle::new(Point::new(10, 10), 15));
+        saturating_scale_assign(&mut c, u64::MAX);
+        assert_eq!(c, Circle::new(Point::new(10, 10), u64::MAX));
+        saturating_scale_assign(&mut c, 0);
+        assert_eq!(c, Circle::new(Point::new(10, 10), 0));
+    }
+
+    #[test]
+    fn test_saturating_scale() {
+        assert_eq!(saturating_scale(&Circle::new(Point::new(10, 10), 5), 3), Circle::new(Point::new(10, 10), 15));
+        assert_eq!(saturating_scale(&Circle::new(Point::new(10, 10), 5), 0), Circle::new(Point::new(10, 10), 0));
+        assert_eq!(saturating_scale(&Circle::new(Point::min(), u64::MAX), 2), Circle::new(Point::min(), u64::MAX));
+        assert_eq!(saturating_scale(&Circle::new(Point::max(), u64::MAX / 2 + 1), 2), Circle::new(Point::max(), u64::MAX));
+    }
+}
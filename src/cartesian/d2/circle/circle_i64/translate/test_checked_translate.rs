@@ -0,0 +1,8 @@
+use super::checked_translate;
+use crate::cartesian::d2::{circle::circle_i64::Circle, point::point_i64::Point};
+
+#[test]
+fn test() {
+    assert_eq!(checked_translate(&Circle::new(Point::new(10, 10), 5), &Point::new(5, -3)), Circle::new(Point::new(15, 7), 5));
+    assert_eq!(checked_translate(&Circle::new(Point::new(15, 7), 5), &Point::new(-10, 3)), Circle::new(Point::new(5, 10), 5));
+}
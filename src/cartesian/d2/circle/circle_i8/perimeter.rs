@@ -0,0 +1,27 @@
+use super::Circle;
+use std::f64;
+
+pub fn perimeter(c: &Circle) -> f64 {
+    f64::consts::TAU * c.r as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::perimeter;
+    use crate::cartesian::d2::{circle::circle_i8::Circle, point::point_i8::Point};
+    use std::f64::consts::TAU;
+
+    #[test]
+    fn test_perimeter() {
+        assert_eq!(perimeter(&Circle::new(Point::new(1, 1), 1)), TAU);
+        assert_eq!(perimeter(&Circle::new(Point::new(1, 1), 2)), 2.0 * TAU);
+        assert_eq!(perimeter(&Circle::new(Point::new(1, 1), 4)), 4.0 * TAU);
+        assert_eq!(perimeter(&Circle::new(Point::new(1, 1), 8)), 8.0 * TAU);
+    }
+
+    #[test]
+    fn perimeter_bounds() {
+        assert_eq!(perimeter(&Circle::new(Point::min(), 0)), 0.0);
+        assert_eq!(perimeter(&Circle::new(Point::max(), u8::MAX)), TAU * u8::MAX as f64);
+    }
+}
@@ -0,0 +1,70 @@
+use super::Circle;
+use crate::cartesian::d2::rect::rect_u16::Rect;
+
+pub fn try_checked_bounding_rect(c: &Circle) -> Option<Rect> {
+    let min_x = c.p.x.checked_sub(c.r)?;
+    let min_y = c.p.y.checked_sub(c.r)?;
+    let max_x = c.p.x.checked_add(c.r)?;
+    let max_y = c.p.y.checked_add(c.r)?;
+    Some(Rect::new((min_x, min_y), (max_x, max_y)))
+}
+
+pub fn checked_bounding_rect(c: &Circle) -> Rect {
+    try_checked_bounding_rect(c).unwrap()
+}
+
+pub fn saturating_bounding_rect(c: &Circle) -> Rect {
+    let min_x = c.p.x.saturating_sub(c.r);
+    let min_y = c.p.y.saturating_sub(c.r);
+    let max_x = c.p.x.saturating_add(c.r);
+    let max_y = c.p.y.saturating_add(c.r);
+    Rect::new((min_x, min_y), (max_x, max_y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{checked_bounding_rect, saturating_bounding_rect, try_checked_bounding_rect};
+    use crate::cartesian::{
+        d1::point::point_u16::MAX,
+        d2::{circle::circle_u16::Circle, point::point_u16::Point, rect::rect_u16::Rect},
+    };
+
+    #[test]
+    fn test_try_checked_bounding_rect() {
+        assert_eq!(try_checked_bounding_rect(&Circle::new(Point::new(10, 10), 5)), Some(Rect::new((5, 5), (15, 15))));
+        assert_eq!(try_checked_bounding_rect(&Circle::new(Point::new(10, 20), 0)), Some(Rect::new((10, 20), (10, 20))));
+        assert_eq!(try_checked_bounding_rect(&Circle::new(Point::new(7, 8), 7)), Some(Rect::new((0, 1), (14, 15))));
+    }
+
+    #[test]
+    fn try_checked_bounding_rect_bounds() {
+        assert_eq!(try_checked_bounding_rect(&Circle::new(Point::new(5, MAX - 5), 5)), Some(Rect::new((0, MAX - 10), (10, MAX))));
+        assert_eq!(try_checked_bounding_rect(&Circle::new(Point::min(), MAX)), None);
+        assert_eq!(try_checked_bounding_rect(&Circle::new(Point::new(5, 10), 6)), None);
+        assert_eq!(try_checked_bounding_rect(&Circle::new(Point::new(10, 5), 6)), None);
+        assert_eq!(try_checked_bounding_rect(&Circle::new(Point::new(MAX - 5, 10), 6)), None);
+        assert_eq!(try_checked_bounding_rect(&Circle::new(Point::new(10, MAX - 5), 6)), None);
+    }
+
+    #[test]
+    fn test_checked_bounding_rect() {
+        assert_eq!(checked_bounding_rect(&Circle::new(Point::new(10, 10), 5)), Rect::new((5, 5), (15, 15)));
+        assert_eq!(checked_bounding_rect(&Circle::new(Point::new(10, 20), 0)), Rect::new((10, 20), (10, 20)));
+        assert_eq!(checked_bounding_rect(&Circle::new(Point::new(7, 8), 7)), Rect::new((0, 1), (14, 15)));
+    }
+
+    #[test]
+    fn test_saturating_bounding_rect() {
+        assert_eq!(saturating_bounding_rect(&Circle::new(Point::new(10, 10), 5)), Rect::new((5, 5), (15, 15)));
+        assert_eq!(saturating_bounding_rect(&Circle::new(Point::new(10, 20), 0)), Rect::new((10, 20), (10, 20)));
+        assert_eq!(saturating_bounding_rect(&Circle::new(Point::new(7, 8), 7)), Rect::new((0, 1), (14, 15)));
+    }
+
+    #[test]
+    fn saturating_bounding_rect_bounds() {
+        assert_eq!(saturating_bounding_rect(&Circle::new(Point::new(5, MAX - 5), 10)), Rect::new((0, MAX - 15), (15, MAX)));
+        assert_eq!(saturating_bounding_rect(&Circle::new(Point::min(), MAX)), Rect::largest());
+        assert_eq!(saturating_bounding_rect(&Circle::new(Point::max(), MAX)), Rect::largest());
+        assert_eq!(saturating_bounding_rect(&Circle::new(Point::new(MAX / 2, MAX / 2), MAX)), Rect::largest());
+    }
+}
@@ -0,0 +1,52 @@
+use super::{Circle, contains_point};
+use crate::cartesian::d2::{point::point_u16::Point, rect::rect_u16::Rect};
+
+pub fn contains_rect(c: &Circle, r: &Rect) -> bool {
+    contains_point(c, &r.min) && contains_point(c, &r.max) && contains_point(c, &Point::new(r.min.x, r.max.y)) && contains_point(c, &Point::new(r.max.x, r.min.y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::contains_rect;
+    use crate::cartesian::{
+        d1::point::point_u16::MAX,
+        d2::{circle::circle_u16::Circle, point::point_u16::Point, rect::rect_u16::Rect},
+    };
+
+    #[test]
+    fn inside() {
+        let c = Circle::new(Point::new(10, 10), 5);
+        assert!(contains_rect(&c, &Rect::new((10, 10), (10, 10))));
+        assert!(contains_rect(&c, &Rect::new((8, 8), (12, 12))));
+        assert!(contains_rect(&c, &Rect::new((7, 7), (13, 13))));
+    }
+
+    #[test]
+    fn borders() {
+        let c = Circle::new(Point::new(10, 10), 5);
+        assert!(contains_rect(&c, &Rect::new((6, 7), (14, 13))));
+        assert!(contains_rect(&c, &Rect::new((7, 6), (13, 14))));
+        assert!(contains_rect(&c, &Rect::new((5, 10), (15, 10))));
+        assert!(contains_rect(&c, &Rect::new((10, 5), (10, 15))));
+    }
+
+    #[test]
+    fn outside() {
+        let c = Circle::new(Point::new(10, 10), 5);
+        assert!(!contains_rect(&c, &Rect::new((6, 6), (14, 14))));
+        assert!(!contains_rect(&c, &Rect::new((5, 9), (15, 10))));
+        assert!(!contains_rect(&c, &Rect::new((9, 5), (10, 15))));
+        assert!(!contains_rect(&c, &Rect::new((0, 0), (30, 30))));
+        assert!(!contains_rect(&c, &Rect::new((20, 20), (30, 30))));
+    }
+
+    #[test]
+    fn bounds() {
+        let c = Circle::new(Point::min(), MAX);
+        assert!(contains_rect(&c, &Rect::new((0, 0), (MAX, 0))));
+        assert!(contains_rect(&c, &Rect::new((0, 0), (0, MAX))));
+        assert!(contains_rect(&c, &Rect::new((0, 0), (MAX / 2, MAX / 2))));
+        assert!(!contains_rect(&c, &Rect::new((0, 0), (MAX, 1))));
+        assert!(!contains_rect(&c, &Rect::largest()));
+    }
+}
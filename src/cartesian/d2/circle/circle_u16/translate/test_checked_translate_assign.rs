@@ -0,0 +1,14 @@
+use super::checked_translate_assign;
+use crate::cartesian::d2::{
+    circle::circle_u16::Circle,
+    point::{point_i16, point_u16::Point},
+};
+
+#[test]
+fn test() {
+    let mut c = Circle::new(Point::new(10, 10), 5);
+    checked_translate_assign(&mut c, &point_i16::Point::new(5, -3));
+    assert_eq!(c, Circle::new(Point::new(15, 7), 5));
+    checked_translate_assign(&mut c, &point_i16::Point::new(-10, 3));
+    assert_eq!(c, Circle::new(Point::new(5, 10), 5));
+}
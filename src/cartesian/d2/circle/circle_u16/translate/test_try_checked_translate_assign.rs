@@ -0,0 +1,27 @@
+use super::try_checked_translate_assign;
+use crate::cartesian::{
+    d1::point::point_u16::MAX,
+    d2::{
+        circle::circle_u16::Circle,
+        point::{point_i16, point_u16::Point},
+    },
+};
+
+#[test]
+fn test() {
+    let mut c = Circle::new(Point::new(10, 10), 5);
+    assert_eq!(try_checked_translate_assign(&mut c, &point_i16::Point::new(5, -3)), Some(()));
+    assert_eq!(c, Circle::new(Point::new(15, 7), 5));
+    assert_eq!(try_checked_translate_assign(&mut c, &point_i16::Point::new(-10, 3)), Some(()));
+    assert_eq!(c, Circle::new(Point::new(5, 10), 5));
+}
+
+#[test]
+fn out_of_bounds() {
+    let mut c = Circle::new(Point::new(MAX - 1, 0), 5);
+    assert_eq!(try_checked_translate_assign(&mut c, &point_i16::Point::new(2, 0)), None);
+    assert_eq!(try_checked_translate_assign(&mut c, &point_i16::Point::new(0, -1)), None);
+    assert_eq!(c, Circle::new(Point::new(MAX - 1, 0), 5));
+    assert_eq!(try_checked_translate_assign(&mut c, &point_i16::Point::new(1, 0)), Some(()));
+    assert_eq!(c, Circle::new(Point::new(MAX, 0), 5));
+}
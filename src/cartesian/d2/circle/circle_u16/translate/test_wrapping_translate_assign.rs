@@ -0,0 +1,24 @@
+use super::wrapping_translate_assign;
+use crate::cartesian::{
+    d1::point::point_u16::MAX,
+    d2::{
+        circle::circle_u16::Circle,
+        point::{point_i16, point_u16::Point},
+    },
+};
+
+#[test]
+fn test() {
+    let mut c = Circle::new(Point::new(10, 10), 5);
+    wrapping_translate_assign(&mut c, &point_i16::Point::new(5, -3));
+    assert_eq!(c, Circle::new(Point::new(15, 7), 5));
+    wrapping_translate_assign(&mut c, &point_i16::Point::new(-10, 3));
+    assert_eq!(c, Circle::new(Point::new(5, 10), 5));
+}
+
+#[test]
+fn out_of_bounds() {
+    let mut c = Circle::new(Point::new(MAX, 0), 5);
+    wrapping_translate_assign(&mut c, &point_i16::Point::new(1, -1));
+    assert_eq!(c, Circle::new(Point::new(0, MAX), 5));
+}
@@ -0,0 +1,60 @@
+use super::Circle;
+use crate::cartesian::d2::point::point_u32::Point;
+
+pub fn contains_point(c: &Circle, p: &Point) -> bool {
+    let dx = u128::from(c.p.x.abs_diff(p.x));
+    let dy = u128::from(c.p.y.abs_diff(p.y));
+    let r = u128::from(c.r);
+    dx * dx + dy * dy <= r * r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::contains_point;
+    use crate::cartesian::{
+        d1::point::point_u32::MAX,
+        d2::{circle::circle_u32::Circle, point::point_u32::Point},
+    };
+
+    #[test]
+    fn inside() {
+        let c = Circle::new(Point::new(10, 10), 5);
+        assert!(contains_point(&c, &Point::new(10, 10)));
+        assert!(contains_point(&c, &Point::new(12, 12)));
+        assert!(contains_point(&c, &Point::new(7, 13)));
+        assert!(contains_point(&c, &Point::new(14, 8)));
+    }
+
+    #[test]
+    fn borders() {
+        let c = Circle::new(Point::new(10, 10), 5);
+        assert!(contains_point(&c, &Point::new(15, 10)));
+        assert!(contains_point(&c, &Point::new(5, 10)));
+        assert!(contains_point(&c, &Point::new(10, 15)));
+        assert!(contains_point(&c, &Point::new(10, 5)));
+        assert!(contains_point(&c, &Point::new(13, 14)));
+        assert!(contains_point(&c, &Point::new(6, 7)));
+    }
+
+    #[test]
+    fn outside() {
+        let c = Circle::new(Point::new(10, 10), 5);
+        assert!(!contains_point(&c, &Point::new(16, 10)));
+        assert!(!contains_point(&c, &Point::new(4, 10)));
+        assert!(!contains_point(&c, &Point::new(10, 16)));
+        assert!(!contains_point(&c, &Point::new(10, 4)));
+        assert!(!contains_point(&c, &Point::new(14, 14)));
+        assert!(!contains_point(&c, &Point::new(6, 6)));
+    }
+
+    #[test]
+    fn bounds() {
+        let c = Circle::new(Point::min(), MAX);
+        assert!(contains_point(&c, &Point::new(MAX, 0)));
+        assert!(contains_point(&c, &Point::new(0, MAX)));
+        assert!(contains_point(&c, &Point::new(MAX / 2, MAX / 2)));
+        assert!(!contains_point(&c, &Point::new(MAX, 1)));
+        assert!(!contains_point(&c, &Point::new(1, MAX)));
+        assert!(!contains_point(&c, &Point::max()));
+    }
+}
@@ -0,0 +1,22 @@
+use super::try_checked_translate;
+use crate::cartesian::{
+    d1::point::point_u32::MAX,
+    d2::{
+        circle::circle_u32::Circle,
+        point::{point_i32, point_u32::Point},
+    },
+};
+
+#[test]
+fn test() {
+    assert_eq!(try_checked_translate(&Circle::new(Point::new(10, 10), 5), &point_i32::Point::new(5, -3)), Some(Circle::new(Point::new(15, 7), 5)));
+    assert_eq!(try_checked_translate(&Circle::new(Point::new(15, 7), 5), &point_i32::Point::new(-10, 3)), Some(Circle::new(Point::new(5, 10), 5)));
+}
+
+#[test]
+fn out_of_bounds() {
+    let c = Circle::new(Point::new(MAX - 1, 0), 5);
+    assert_eq!(try_checked_translate(&c, &point_i32::Point::new(1, 0)), Some(Circle::new(Point::new(MAX, 0), 5)));
+    assert_eq!(try_checked_translate(&c, &point_i32::Point::new(2, 0)), None);
+    assert_eq!(try_checked_translate(&c, &point_i32::Point::new(0, -1)), None);
+}
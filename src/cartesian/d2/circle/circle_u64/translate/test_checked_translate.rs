@@ -0,0 +1,11 @@
+use super::checked_translate;
+use crate::cartesian::d2::{
+    circle::circle_u64::Circle,
+    point::{point_i64, point_u64::Point},
+};
+
+#[test]
+fn test() {
+    assert_eq!(checked_translate(&Circle::new(Point::new(10, 10), 5), &point_i64::Point::new(5, -3)), Circle::new(Point::new(15, 7), 5));
+    assert_eq!(checked_translate(&Circle::new(Point::new(15, 7), 5), &point_i64::Point::new(-10, 3)), Circle::new(Point::new(5, 10), 5));
+}
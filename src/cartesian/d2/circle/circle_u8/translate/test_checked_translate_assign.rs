@@ -0,0 +1,14 @@
+use super::checked_translate_assign;
+use crate::cartesian::d2::{
+    circle::circle_u8::Circle,
+    point::{point_i8, point_u8::Point},
+};
+
+#[test]
+fn test() {
+    let mut c = Circle::new(Point::new(10, 10), 5);
+    checked_translate_assign(&mut c, &point_i8::Point::new(5, -3));
+    assert_eq!(c, Circle::new(Point::new(15, 7), 5));
+    checked_translate_assign(&mut c, &point_i8::Point::new(-10, 3));
+    assert_eq!(c, Circle::new(Point::new(5, 10), 5));
+}
@@ -0,0 +1,24 @@
+use super::saturating_translate_assign;
+use crate::cartesian::{
+    d1::point::point_u8::MAX,
+    d2::{
+        circle::circle_u8::Circle,
+        point::{point_i8, point_u8::Point},
+    },
+};
+
+#[test]
+fn test() {
+    let mut c = Circle::new(Point::new(10, 10), 5);
+    saturating_translate_assign(&mut c, &point_i8::Point::new(5, -3));
+    assert_eq!(c, Circle::new(Point::new(15, 7), 5));
+    saturating_translate_assign(&mut c, &point_i8::Point::new(-10, 3));
+    assert_eq!(c, Circle::new(Point::new(5, 10), 5));
+}
+
+#[test]
+fn out_of_bounds() {
+    let mut c = Circle::new(Point::new(MAX - 1, 0), 5);
+    saturating_translate_assign(&mut c, &point_i8::Point::new(2, -1));
+    assert_eq!(c, Circle::new(Point::new(MAX, 0), 5));
+}
@@ -0,0 +1,19 @@
+use super::wrapping_translate;
+use crate::cartesian::{
+    d1::point::point_u8::MAX,
+    d2::{
+        circle::circle_u8::Circle,
+        point::{point_i8, point_u8::Point},
+    },
+};
+
+#[test]
+fn test() {
+    assert_eq!(wrapping_translate(&Circle::new(Point::new(10, 10), 5), &point_i8::Point::new(5, -3)), Circle::new(Point::new(15, 7), 5));
+    assert_eq!(wrapping_translate(&Circle::new(Point::new(15, 7), 5), &point_i8::Point::new(-10, 3)), Circle::new(Point::new(5, 10), 5));
+}
+
+#[test]
+fn out_of_bounds() {
+    assert_eq!(wrapping_translate(&Circle::new(Point::new(MAX, 0), 5), &point_i8::Point::new(1, -1)), Circle::new(Point::new(0, MAX), 5));
+}
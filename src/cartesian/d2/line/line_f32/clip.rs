@@ -0,0 +1,100 @@
+use super::Line;
+use crate::cartesian::d2::{point::point_f32::Point, rect::rect_f32::Rect};
+
+pub fn clip(l: &Line, r: &Rect) -> Option<Line> {
+    let dx = l.max.x - l.min.x;
+    let dy = l.max.y - l.min.y;
+    let edges = [(-dx, l.min.x - r.min.x), (dx, r.max.x - l.min.x), (-dy, l.min.y - r.min.y), (dy, r.max.y - l.min.y)];
+    let mut enter = 0.0;
+    let mut exit = 1.0;
+    for (p, q) in edges {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else if p < 0.0 {
+            enter = f32::max(enter, q / p);
+        } else {
+            exit = f32::min(exit, q / p);
+        }
+    }
+    if enter > exit {
+        return None;
+    }
+    let at = |t: f32| {
+        if t == 0.0 {
+            return l.min.clone();
+        }
+        if t == 1.0 {
+            return l.max.clone();
+        }
+        Point::new(t.mul_add(dx, l.min.x).clamp(r.min.x, r.max.x), t.mul_add(dy, l.min.y).clamp(r.min.y, r.max.y))
+    };
+    Some(Line { min: at(enter), max: at(exit) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::clip;
+    use crate::cartesian::{
+        d1::point::point_f32::{MAX, MIN},
+        d2::{line::line_f32::Line, rect::rect_f32::Rect},
+    };
+
+    #[test]
+    fn clip_inside() {
+        let r = Rect::new((2.0, 2.0), (6.0, 6.0));
+        assert_eq!(clip(&Line::new((3.0, 3.0), (5.0, 5.0)), &r), Some(Line::new((3.0, 3.0), (5.0, 5.0))));
+        assert_eq!(clip(&Line::new((2.0, 2.0), (6.0, 6.0)), &r), Some(Line::new((2.0, 2.0), (6.0, 6.0))));
+        assert_eq!(clip(&Line::new((6.0, 2.0), (2.0, 6.0)), &r), Some(Line::new((6.0, 2.0), (2.0, 6.0))));
+        assert_eq!(clip(&Line::new((4.0, 4.0), (4.0, 4.0)), &r), Some(Line::new((4.0, 4.0), (4.0, 4.0))));
+    }
+
+    #[test]
+    fn clip_outside() {
+        let r = Rect::new((2.0, 2.0), (6.0, 6.0));
+        assert_eq!(clip(&Line::new((7.0, 7.0), (9.0, 9.0)), &r), None);
+        assert_eq!(clip(&Line::new((0.0, 7.0), (8.0, 7.0)), &r), None);
+        assert_eq!(clip(&Line::new((1.0, 0.0), (1.0, 8.0)), &r), None);
+        assert_eq!(clip(&Line::new((0.0, 5.0), (3.0, 8.0)), &r), None);
+        assert_eq!(clip(&Line::new((1.0, 1.0), (1.0, 1.0)), &r), None);
+    }
+
+    #[test]
+    fn clip_crossing() {
+        let r = Rect::new((2.0, 2.0), (6.0, 6.0));
+        assert_eq!(clip(&Line::new((0.0, 4.0), (8.0, 4.0)), &r), Some(Line::new((2.0, 4.0), (6.0, 4.0))));
+        assert_eq!(clip(&Line::new((8.0, 4.0), (0.0, 4.0)), &r), Some(Line::new((6.0, 4.0), (2.0, 4.0))));
+        assert_eq!(clip(&Line::new((4.0, 0.0), (4.0, 8.0)), &r), Some(Line::new((4.0, 2.0), (4.0, 6.0))));
+        assert_eq!(clip(&Line::new((0.0, 0.0), (8.0, 8.0)), &r), Some(Line::new((2.0, 2.0), (6.0, 6.0))));
+        assert_eq!(clip(&Line::new((0.0, 2.0), (8.0, 10.0)), &r), Some(Line::new((2.0, 4.0), (4.0, 6.0))));
+        assert_eq!(clip(&Line::new((4.0, 4.0), (9.0, 4.0)), &r), Some(Line::new((4.0, 4.0), (6.0, 4.0))));
+        assert_eq!(clip(&Line::new((0.0, 6.0), (8.0, 6.0)), &r), Some(Line::new((2.0, 6.0), (6.0, 6.0))));
+    }
+
+    #[test]
+    fn clip_corner() {
+        let r = Rect::new((2.0, 2.0), (6.0, 6.0));
+        assert_eq!(clip(&Line::new((0.0, 4.0), (4.0, 0.0)), &r), Some(Line::new((2.0, 2.0), (2.0, 2.0))));
+        assert_eq!(clip(&Line::new((4.0, 8.0), (8.0, 4.0)), &r), Some(Line::new((6.0, 6.0), (6.0, 6.0))));
+        assert_eq!(clip(&Line::new((0.0, 5.0), (5.0, 0.0)), &r), Some(Line::new((2.0, 3.0), (3.0, 2.0))));
+    }
+
+    #[test]
+    fn clip_fractional() {
+        let r = Rect::new((2.0, 0.0), (6.0, 6.0));
+        assert_eq!(clip(&Line::new((0.0, 0.0), (8.0, 3.0)), &r), Some(Line::new((2.0, 0.75), (6.0, 2.25))));
+        assert_eq!(clip(&Line::new((8.0, 3.0), (0.0, 0.0)), &r), Some(Line::new((6.0, 2.25), (2.0, 0.75))));
+        assert_eq!(clip(&Line::new((0.0, 0.0), (4.0, 1.0)), &r), Some(Line::new((2.0, 0.5), (4.0, 1.0))));
+    }
+
+    #[test]
+    fn clip_bounds() {
+        assert_eq!(clip(&Line::largest(), &Rect::largest()), Some(Line::largest()));
+        assert_eq!(clip(&Line::new((MIN, MAX), (MAX, MIN)), &Rect::largest()), Some(Line::new((MIN, MAX), (MAX, MIN))));
+        assert_eq!(clip(&Line::largest(), &Rect::new((MIN + 1.0, MIN + 1.0), (MAX - 1.0, MAX - 1.0))), Some(Line::new((MIN + 1.0, MIN + 1.0), (MAX - 1.0, MAX - 1.0))));
+        assert_eq!(clip(&Line::new((MAX, MAX), (MIN, MIN)), &Rect::new((MIN + 1.0, MIN + 1.0), (MAX - 1.0, MAX - 1.0))), Some(Line::new((MAX - 1.0, MAX - 1.0), (MIN + 1.0, MIN + 1.0))));
+        assert_eq!(clip(&Line::new((MIN, MAX), (MAX, MAX)), &Rect::new((MIN, MIN), (MAX, MAX - 1.0))), None);
+        assert_eq!(clip(&Line::max(), &Rect::largest()), Some(Line::max()));
+    }
+}
@@ -0,0 +1,210 @@
+use super::Line;
+use crate::cartesian::d2::point::point_f32::{Point, orient2d};
+use std::cmp::Ordering;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum Intersection {
+    Point(Point),
+    Line(Line),
+}
+
+fn orientation(p: &Point, q: &Point, r: &Point) -> Ordering {
+    let left = (q.x - p.x) * (r.y - p.y);
+    let right = (q.y - p.y) * (r.x - p.x);
+    let cross = left - right;
+    let err_bound = (3.0 + 16.0 * f32::EPSILON) * f32::EPSILON * (left.abs() + right.abs());
+    if cross > err_bound {
+        Ordering::Greater
+    } else if cross < -err_bound {
+        Ordering::Less
+    } else {
+        Ordering::Equal
+    }
+}
+
+fn crossing(a: &Point, b: &Point, c: &Point, d: &Point) -> Point {
+    let (d1_x, d1_y) = (b.x - a.x, b.y - a.y);
+    let (d2_x, d2_y) = (d.x - c.x, d.y - c.y);
+    let t = ((c.x - a.x) * d2_y - (c.y - a.y) * d2_x) / (d1_x * d2_y - d1_y * d2_x);
+    Point::new(t.mul_add(d1_x, a.x), t.mul_add(d1_y, a.y))
+}
+
+fn sorted<'a>(p1: &'a Point, p2: &'a Point) -> (&'a Point, &'a Point) {
+    if (p1.x, p1.y) <= (p2.x, p2.y) { (p1, p2) } else { (p2, p1) }
+}
+
+fn overlap(l1: &Line, l2: &Line) -> Option<Intersection> {
+    let (start1, end1) = sorted(&l1.min, &l1.max);
+    let (start2, end2) = sorted(&l2.min, &l2.max);
+    let start = if (start1.x, start1.y) >= (start2.x, start2.y) { start1 } else { start2 };
+    let end = if (end1.x, end1.y) <= (end2.x, end2.y) { end1 } else { end2 };
+    if (start.x, start.y) > (end.x, end.y) {
+        None
+    } else if start == end {
+        Some(Intersection::Point(start.clone()))
+    } else {
+        Some(Intersection::Line(Line { min: start.clone(), max: end.clone() }))
+    }
+}
+
+fn orientations(l1: &Line, l2: &Line, orient: impl Fn(&Point, &Point, &Point) -> Ordering) -> [Ordering; 4] {
+    [orient(&l1.min, &l1.max, &l2.min), orient(&l1.min, &l1.max, &l2.max), orient(&l2.min, &l2.max, &l1.min), orient(&l2.min, &l2.max, &l1.max)]
+}
+
+fn touches(l1: &Line, l2: &Line, [o1, o2, o3, o4]: [Ordering; 4]) -> bool {
+    if o1 == Ordering::Equal && o2 == Ordering::Equal && o3 == Ordering::Equal && o4 == Ordering::Equal {
+        return overlap(l1, l2).is_some();
+    }
+    o1 != o2 && o3 != o4
+}
+
+pub fn intersects(l1: &Line, l2: &Line) -> bool {
+    touches(l1, l2, orientations(l1, l2, orientation))
+}
+
+pub fn exact_intersects(l1: &Line, l2: &Line) -> bool {
+    touches(l1, l2, orientations(l1, l2, orient2d))
+}
+
+pub fn intersection(l1: &Line, l2: &Line) -> Option<Intersection> {
+    classify(l1, l2, orientations(l1, l2, orientation))
+}
+
+pub fn exact_intersection(l1: &Line, l2: &Line) -> Option<Intersection> {
+    classify(l1, l2, orientations(l1, l2, orient2d))
+}
+
+pub fn classify(l1: &Line, l2: &Line, [o1, o2, o3, o4]: [Ordering; 4]) -> Option<Intersection> {
+    if o1 == Ordering::Equal && o2 == Ordering::Equal && o3 == Ordering::Equal && o4 == Ordering::Equal {
+        return overlap(l1, l2);
+    }
+    if o1 == o2 || o3 == o4 {
+        return None;
+    }
+    if o1 == Ordering::Equal {
+        return Some(Intersection::Point(l2.min.clone()));
+    }
+    if o2 == Ordering::Equal {
+        return Some(Intersection::Point(l2.max.clone()));
+    }
+    if o3 == Ordering::Equal {
+        return Some(Intersection::Point(l1.min.clone()));
+    }
+    if o4 == Ordering::Equal {
+        return Some(Intersection::Point(l1.max.clone()));
+    }
+    Some(Intersection::Point(crossing(&l1.min, &l1.max, &l2.min, &l2.max)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Intersection, exact_intersection, exact_intersects, intersection, intersects};
+    use crate::cartesian::{
+        d1::point::point_f32::{MAX, MIN},
+        d2::{line::line_f32::Line, point::point_f32::Point},
+    };
+
+    #[test]
+    fn crossing() {
+        assert_eq!(intersection(&Line::new((0.0, 0.0), (10.0, 10.0)), &Line::new((0.0, 10.0), (10.0, 0.0))), Some(Intersection::Point(Point::new(5.0, 5.0))));
+        assert_eq!(intersection(&Line::new((0.0, 5.0), (10.0, 5.0)), &Line::new((5.0, 0.0), (5.0, 10.0))), Some(Intersection::Point(Point::new(5.0, 5.0))));
+        assert_eq!(intersection(&Line::new((0.0, 0.0), (3.0, 1.0)), &Line::new((0.0, 1.0), (3.0, 0.0))), Some(Intersection::Point(Point::new(1.5, 0.5))));
+        assert_eq!(intersection(&Line::new((10.0, 10.0), (0.0, 0.0)), &Line::new((10.0, 0.0), (0.0, 10.0))), Some(Intersection::Point(Point::new(5.0, 5.0))));
+        assert!(intersects(&Line::new((0.0, 0.0), (10.0, 10.0)), &Line::new((0.0, 10.0), (10.0, 0.0))));
+        assert!(intersects(&Line::new((0.0, 5.0), (10.0, 5.0)), &Line::new((5.0, 0.0), (5.0, 10.0))));
+        assert!(intersects(&Line::new((0.0, 0.0), (3.0, 1.0)), &Line::new((0.0, 1.0), (3.0, 0.0))));
+        assert!(intersects(&Line::new((10.0, 10.0), (0.0, 0.0)), &Line::new((10.0, 0.0), (0.0, 10.0))));
+    }
+
+    #[test]
+    fn touching() {
+        assert_eq!(intersection(&Line::new((0.0, 0.0), (10.0, 10.0)), &Line::new((10.0, 10.0), (20.0, 0.0))), Some(Intersection::Point(Point::new(10.0, 10.0))));
+        assert_eq!(intersection(&Line::new((0.0, 0.0), (10.0, 10.0)), &Line::new((20.0, 0.0), (0.0, 0.0))), Some(Intersection::Point(Point::new(0.0, 0.0))));
+        assert_eq!(intersection(&Line::new((0.0, 5.0), (10.0, 5.0)), &Line::new((5.0, 5.0), (5.0, 10.0))), Some(Intersection::Point(Point::new(5.0, 5.0))));
+        assert_eq!(intersection(&Line::new((5.0, 0.0), (5.0, 5.0)), &Line::new((0.0, 5.0), (10.0, 5.0))), Some(Intersection::Point(Point::new(5.0, 5.0))));
+        assert!(intersects(&Line::new((0.0, 0.0), (10.0, 10.0)), &Line::new((10.0, 10.0), (20.0, 0.0))));
+        assert!(intersects(&Line::new((0.0, 0.0), (10.0, 10.0)), &Line::new((20.0, 0.0), (0.0, 0.0))));
+        assert!(intersects(&Line::new((0.0, 5.0), (10.0, 5.0)), &Line::new((5.0, 5.0), (5.0, 10.0))));
+        assert!(intersects(&Line::new((5.0, 0.0), (5.0, 5.0)), &Line::new((0.0, 5.0), (10.0, 5.0))));
+    }
+
+    #[test]
+    fn disjoint() {
+        assert_eq!(intersection(&Line::new((0.0, 0.0), (10.0, 10.0)), &Line::new((0.0, 1.0), (10.0, 11.0))), None);
+        assert_eq!(intersection(&Line::new((0.0, 0.0), (4.0, 4.0)), &Line::new((10.0, 0.0), (6.0, 4.0))), None);
+        assert_eq!(intersection(&Line::new((0.0, 5.0), (10.0, 5.0)), &Line::new((5.0, 6.0), (5.0, 10.0))), None);
+        assert_eq!(intersection(&Line::new((0.0, 0.0), (10.0, 0.0)), &Line::new((0.0, 1.0), (10.0, 1.0))), None);
+        assert!(!intersects(&Line::new((0.0, 0.0), (10.0, 10.0)), &Line::new((0.0, 1.0), (10.0, 11.0))));
+        assert!(!intersects(&Line::new((0.0, 0.0), (4.0, 4.0)), &Line::new((10.0, 0.0), (6.0, 4.0))));
+        assert!(!intersects(&Line::new((0.0, 5.0), (10.0, 5.0)), &Line::new((5.0, 6.0), (5.0, 10.0))));
+        assert!(!intersects(&Line::new((0.0, 0.0), (10.0, 0.0)), &Line::new((0.0, 1.0), (10.0, 1.0))));
+    }
+
+    #[test]
+    fn collinear() {
+        assert_eq!(intersection(&Line::new((0.0, 0.0), (10.0, 10.0)), &Line::new((5.0, 5.0), (15.0, 15.0))), Some(Intersection::Line(Line::new((5.0, 5.0), (10.0, 10.0)))));
+        assert_eq!(intersection(&Line::new((10.0, 10.0), (0.0, 0.0)), &Line::new((15.0, 15.0), (5.0, 5.0))), Some(Intersection::Line(Line::new((5.0, 5.0), (10.0, 10.0)))));
+        assert_eq!(intersection(&Line::new((0.0, 0.0), (10.0, 10.0)), &Line::new((2.0, 2.0), (8.0, 8.0))), Some(Intersection::Line(Line::new((2.0, 2.0), (8.0, 8.0)))));
+        assert_eq!(intersection(&Line::new((5.0, 0.0), (5.0, 10.0)), &Line::new((5.0, 5.0), (5.0, 20.0))), Some(Intersection::Line(Line::new((5.0, 5.0), (5.0, 10.0)))));
+        assert_eq!(intersection(&Line::new((0.0, 5.0), (10.0, 5.0)), &Line::new((0.0, 5.0), (10.0, 5.0))), Some(Intersection::Line(Line::new((0.0, 5.0), (10.0, 5.0)))));
+        assert_eq!(intersection(&Line::new((0.0, 0.0), (10.0, 10.0)), &Line::new((10.0, 10.0), (20.0, 20.0))), Some(Intersection::Point(Point::new(10.0, 10.0))));
+        assert_eq!(intersection(&Line::new((0.0, 0.0), (10.0, 10.0)), &Line::new((11.0, 11.0), (20.0, 20.0))), None);
+        assert_eq!(intersection(&Line::new((5.0, 0.0), (5.0, 10.0)), &Line::new((5.0, 11.0), (5.0, 20.0))), None);
+        assert!(intersects(&Line::new((0.0, 0.0), (10.0, 10.0)), &Line::new((5.0, 5.0), (15.0, 15.0))));
+        assert!(intersects(&Line::new((0.0, 0.0), (10.0, 10.0)), &Line::new((10.0, 10.0), (20.0, 20.0))));
+        assert!(!intersects(&Line::new((0.0, 0.0), (10.0, 10.0)), &Line::new((11.0, 11.0), (20.0, 20.0))));
+        assert!(!intersects(&Line::new((5.0, 0.0), (5.0, 10.0)), &Line::new((5.0, 11.0), (5.0, 20.0))));
+    }
+
+    #[test]
+    fn degenerate() {
+        assert_eq!(intersection(&Line::new((5.0, 5.0), (5.0, 5.0)), &Line::new((0.0, 0.0), (10.0, 10.0))), Some(Intersection::Point(Point::new(5.0, 5.0))));
+        assert_eq!(intersection(&Line::new((0.0, 0.0), (10.0, 10.0)), &Line::new((5.0, 5.0), (5.0, 5.0))), Some(Intersection::Point(Point::new(5.0, 5.0))));
+        assert_eq!(intersection(&Line::new((5.0, 5.0), (5.0, 5.0)), &Line::new((5.0, 5.0), (5.0, 5.0))), Some(Intersection::Point(Point::new(5.0, 5.0))));
+        assert_eq!(intersection(&Line::new((5.0, 6.0), (5.0, 6.0)), &Line::new((0.0, 0.0), (10.0, 10.0))), None);
+        assert_eq!(intersection(&Line::new((5.0, 5.0), (5.0, 5.0)), &Line::new((5.0, 6.0), (5.0, 6.0))), None);
+        assert!(intersects(&Line::new((5.0, 5.0), (5.0, 5.0)), &Line::new((0.0, 0.0), (10.0, 10.0))));
+        assert!(!intersects(&Line::new((5.0, 6.0), (5.0, 6.0)), &Line::new((0.0, 0.0), (10.0, 10.0))));
+    }
+
+    #[test]
+    fn bounds() {
+        let diagonal = Line::largest();
+        assert_eq!(intersection(&diagonal, &Line::new((MIN, MAX), (MAX, MIN))), Some(Intersection::Point(Point::new(0.0, 0.0))));
+        assert_eq!(intersection(&diagonal, &Line::new((MIN + 1.0, MIN), (MAX, MAX))), Some(Intersection::Point(Point::max())));
+        assert_eq!(intersection(&diagonal, &Line::new((MIN, 0.0), (0.0, MAX))), None);
+        assert_eq!(intersection(&diagonal, &Line::new((MIN + 1.0, MIN + 1.0), (MAX - 1.0, MAX - 1.0))), Some(Intersection::Line(Line::new((MIN + 1.0, MIN + 1.0), (MAX - 1.0, MAX - 1.0)))));
+        assert!(intersects(&diagonal, &Line::new((MIN + 1.0, MIN), (MAX, MAX))));
+        assert!(!intersects(&diagonal, &Line::new((MIN, 0.0), (0.0, MAX))));
+        assert!(!intersects(&Line::min(), &Line::max()));
+    }
+
+    #[test]
+    fn rounding() {
+        let l = Line::new((0.1, 0.3), (0.3, 0.9));
+        assert_eq!(intersection(&l, &Line::new((0.2, 0.6), (0.4, 1.2))), Some(Intersection::Line(Line::new((0.2, 0.6), (0.3, 0.9)))));
+        assert!(intersects(&l, &Line::new((0.2, 0.6), (0.4, 1.2))));
+    }
+
+    #[test]
+    fn exact_rounding() {
+        let l = Line::new((0.1, 0.3), (0.3, 0.9));
+        assert_eq!(exact_intersection(&l, &Line::new((0.2, 0.6), (0.4, 1.2))), None);
+        assert!(!exact_intersects(&l, &Line::new((0.2, 0.6), (0.4, 1.2))));
+        let l = Line::new((0.1, 0.2), (0.3, 0.6));
+        assert_eq!(exact_intersection(&l, &Line::new((0.1, 0.2), (0.300_000_04, 0.6))), Some(Intersection::Point(Point::new(0.1, 0.2))));
+        assert!(exact_intersects(&l, &Line::new((0.1, 0.2), (0.300_000_04, 0.6))));
+        assert_eq!(exact_intersection(&Line::new((0.0, 0.0), (3.0, 1.0)), &Line::new((1.0, 0.333_333_3), (2.0, 0.0))), None);
+        assert!(!exact_intersects(&Line::new((0.0, 0.0), (3.0, 1.0)), &Line::new((1.0, 0.333_333_3), (2.0, 0.0))));
+    }
+
+    #[test]
+    fn exact() {
+        assert_eq!(exact_intersection(&Line::new((0.0, 0.0), (10.0, 10.0)), &Line::new((0.0, 10.0), (10.0, 0.0))), Some(Intersection::Point(Point::new(5.0, 5.0))));
+        assert_eq!(exact_intersection(&Line::new((0.0, 0.0), (10.0, 10.0)), &Line::new((10.0, 10.0), (20.0, 0.0))), Some(Intersection::Point(Point::new(10.0, 10.0))));
+        assert_eq!(exact_intersection(&Line::new((0.0, 0.0), (10.0, 10.0)), &Line::new((5.0, 5.0), (15.0, 15.0))), Some(Intersection::Line(Line::new((5.0, 5.0), (10.0, 10.0)))));
+        assert_eq!(exact_intersection(&Line::new((0.0, 0.0), (10.0, 10.0)), &Line::new((0.0, 1.0), (10.0, 11.0))), None);
+        assert!(exact_intersects(&Line::new((0.0, 0.0), (10.0, 10.0)), &Line::new((0.0, 10.0), (10.0, 0.0))));
+        assert!(!exact_intersects(&Line::new((0.0, 0.0), (10.0, 10.0)), &Line::new((11.0, 11.0), (20.0, 20.0))));
+    }
+}
@@ -1,5 +1,13 @@
 use crate::cartesian::d2::{line::line_f32, point::point_f64::Point};
 
+mod all_intersections;
+mod clip;
+mod intersection;
+
+pub use self::all_intersections::all_intersections;
+pub use self::clip::clip;
+pub use self::intersection::{Intersection, exact_intersection, exact_intersects, intersection, intersects};
+
 #[derive(PartialEq, Debug, Clone)]
 pub struct Line {
     pub min: Point,
@@ -0,0 +1,68 @@
+use super::{Intersection, Line, intersection};
+use crate::{bentley_ottmann, expansion};
+
+pub fn all_intersections(lines: &[Line]) -> Vec<(usize, usize, Intersection)> {
+    let segments = lines
+        .iter()
+        .map(|l| [expansion::from_i128(i128::from(l.min.x)), expansion::from_i128(i128::from(l.min.y)), expansion::from_i128(i128::from(l.max.x)), expansion::from_i128(i128::from(l.max.y))])
+        .collect();
+    bentley_ottmann::intersecting_pairs(segments).into_iter().filter_map(|(a, b, _)| intersection(&lines[a], &lines[b]).map(|i| (a, b, i))).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::all_intersections;
+    use crate::cartesian::d2::{
+        line::line_i16::{Intersection, Line},
+        point::point_f64::Point,
+    };
+
+    #[test]
+    fn test_all_intersections() {
+        let lines = [
+            Line::new((0, 0), (8, 8)),
+            Line::new((0, 8), (8, 0)),
+            Line::new((0, 2), (8, 2)),
+            Line::new((4, 0), (4, 8)),
+            Line::new((12, 0), (14, 0)),
+            Line::new((8, 2), (10, 2)),
+            Line::new((1, 2), (3, 2)),
+        ];
+        assert_eq!(
+            all_intersections(&lines),
+            vec![
+                (0, 1, Intersection::Point(Point::new(4.0, 4.0))),
+                (0, 2, Intersection::Point(Point::new(2.0, 2.0))),
+                (0, 3, Intersection::Point(Point::new(4.0, 4.0))),
+                (0, 6, Intersection::Point(Point::new(2.0, 2.0))),
+                (1, 2, Intersection::Point(Point::new(6.0, 2.0))),
+                (1, 3, Intersection::Point(Point::new(4.0, 4.0))),
+                (2, 3, Intersection::Point(Point::new(4.0, 2.0))),
+                (2, 5, Intersection::Point(Point::new(8.0, 2.0))),
+                (2, 6, Intersection::Line(Line::new((1, 2), (3, 2)))),
+            ]
+        );
+        assert_eq!(all_intersections(&[]), vec![]);
+        assert_eq!(all_intersections(&[Line::new((0, 0), (8, 8)), Line::new((0, 1), (8, 9)), Line::new((9, 9), (10, 10))]), vec![]);
+    }
+
+    #[test]
+    fn all_intersections_grid() {
+        let mut lines = Vec::new();
+        for i in 0..5u8 {
+            lines.push(Line::new((0, 2 * i as i16), (10, 2 * i as i16)));
+            lines.push(Line::new((2 * i as i16 + 1, 0), (2 * i as i16 + 1, 10)));
+        }
+        let result = all_intersections(&lines);
+        assert_eq!(result.len(), 25);
+        assert!(result.iter().all(|(a, b, _)| a % 2 != b % 2));
+    }
+
+    #[test]
+    fn all_intersections_bounds() {
+        let lines = [Line::largest(), Line::new((Line::min().min.x, Line::max().max.y), (Line::max().max.x, Line::min().min.y)), Line::min(), Line::max()];
+        let result = all_intersections(&lines);
+        assert_eq!(result.iter().map(|(a, b, _)| (*a, *b)).collect::<Vec<_>>(), vec![(0, 1), (0, 2), (0, 3)]);
+        assert!(matches!(result[1].2, Intersection::Point(_)));
+    }
+}
@@ -0,0 +1,115 @@
+use super::Line;
+use crate::cartesian::d2::{point::point_i16::Point, rect::rect_i16::Rect};
+use std::cmp::Ordering;
+
+fn cmp_fractions(a: (i64, i64), b: (i64, i64)) -> Ordering {
+    (a.0 * b.1).cmp(&(b.0 * a.1))
+}
+
+fn at(from: i16, delta: i64, t: (i64, i64)) -> i16 {
+    let offset = delta * t.0;
+    let rounding = i64::from(2 * offset.rem_euclid(t.1) >= t.1);
+    (i64::from(from) + offset.div_euclid(t.1) + rounding) as i16
+}
+
+pub fn clip(l: &Line, r: &Rect) -> Option<Line> {
+    let dx = i64::from(l.max.x) - i64::from(l.min.x);
+    let dy = i64::from(l.max.y) - i64::from(l.min.y);
+    let edges =
+        [(-dx, i64::from(l.min.x) - i64::from(r.min.x)), (dx, i64::from(r.max.x) - i64::from(l.min.x)), (-dy, i64::from(l.min.y) - i64::from(r.min.y)), (dy, i64::from(r.max.y) - i64::from(l.min.y))];
+    let mut enter: (i64, i64) = (0, 1);
+    let mut exit: (i64, i64) = (1, 1);
+    for (p, q) in edges {
+        match p.cmp(&0) {
+            Ordering::Equal => {
+                if q < 0 {
+                    return None;
+                }
+            }
+            Ordering::Less => {
+                if cmp_fractions((-q, -p), enter) == Ordering::Greater {
+                    enter = (-q, -p);
+                }
+            }
+            Ordering::Greater => {
+                if cmp_fractions((q, p), exit) == Ordering::Less {
+                    exit = (q, p);
+                }
+            }
+        }
+    }
+    if cmp_fractions(enter, exit) == Ordering::Greater {
+        return None;
+    }
+    Some(Line { min: Point::new(at(l.min.x, dx, enter), at(l.min.y, dy, enter)), max: Point::new(at(l.min.x, dx, exit), at(l.min.y, dy, exit)) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::clip;
+    use crate::cartesian::{
+        d1::point::point_i16::{MAX, MIN},
+        d2::{line::line_i16::Line, rect::rect_i16::Rect},
+    };
+
+    #[test]
+    fn clip_inside() {
+        let r = Rect::new((2, 2), (6, 6));
+        assert_eq!(clip(&Line::new((3, 3), (5, 5)), &r), Some(Line::new((3, 3), (5, 5))));
+        assert_eq!(clip(&Line::new((2, 2), (6, 6)), &r), Some(Line::new((2, 2), (6, 6))));
+        assert_eq!(clip(&Line::new((6, 2), (2, 6)), &r), Some(Line::new((6, 2), (2, 6))));
+        assert_eq!(clip(&Line::new((4, 4), (4, 4)), &r), Some(Line::new((4, 4), (4, 4))));
+    }
+
+    #[test]
+    fn clip_outside() {
+        let r = Rect::new((2, 2), (6, 6));
+        assert_eq!(clip(&Line::new((7, 7), (9, 9)), &r), None);
+        assert_eq!(clip(&Line::new((0, 7), (8, 7)), &r), None);
+        assert_eq!(clip(&Line::new((1, 0), (1, 8)), &r), None);
+        assert_eq!(clip(&Line::new((0, 5), (3, 8)), &r), None);
+        assert_eq!(clip(&Line::new((1, 1), (1, 1)), &r), None);
+    }
+
+    #[test]
+    fn clip_crossing() {
+        let r = Rect::new((2, 2), (6, 6));
+        assert_eq!(clip(&Line::new((0, 4), (8, 4)), &r), Some(Line::new((2, 4), (6, 4))));
+        assert_eq!(clip(&Line::new((8, 4), (0, 4)), &r), Some(Line::new((6, 4), (2, 4))));
+        assert_eq!(clip(&Line::new((4, 0), (4, 8)), &r), Some(Line::new((4, 2), (4, 6))));
+        assert_eq!(clip(&Line::new((0, 0), (8, 8)), &r), Some(Line::new((2, 2), (6, 6))));
+        assert_eq!(clip(&Line::new((0, 2), (8, 10)), &r), Some(Line::new((2, 4), (4, 6))));
+        assert_eq!(clip(&Line::new((4, 4), (9, 4)), &r), Some(Line::new((4, 4), (6, 4))));
+        assert_eq!(clip(&Line::new((0, 6), (8, 6)), &r), Some(Line::new((2, 6), (6, 6))));
+    }
+
+    #[test]
+    fn clip_corner() {
+        let r = Rect::new((2, 2), (6, 6));
+        assert_eq!(clip(&Line::new((0, 4), (4, 0)), &r), Some(Line::new((2, 2), (2, 2))));
+        assert_eq!(clip(&Line::new((4, 8), (8, 4)), &r), Some(Line::new((6, 6), (6, 6))));
+        assert_eq!(clip(&Line::new((0, 5), (5, 0)), &r), Some(Line::new((2, 3), (3, 2))));
+    }
+
+    #[test]
+    fn clip_rounding() {
+        let r = Rect::new((2, 0), (6, 6));
+        assert_eq!(clip(&Line::new((0, 0), (8, 3)), &r), Some(Line::new((2, 1), (6, 2))));
+        assert_eq!(clip(&Line::new((8, 3), (0, 0)), &r), Some(Line::new((6, 2), (2, 1))));
+        assert_eq!(clip(&Line::new((0, 0), (4, 1)), &r), Some(Line::new((2, 1), (4, 1))));
+        assert_eq!(clip(&Line::new((4, 1), (0, 0)), &r), Some(Line::new((4, 1), (2, 1))));
+        assert_eq!(clip(&Line::new((0, 0), (4, -1)), &Rect::new((2, -4), (6, 6))), Some(Line::new((2, 0), (4, -1))));
+        assert_eq!(clip(&Line::new((4, -1), (0, 0)), &Rect::new((2, -4), (6, 6))), Some(Line::new((4, -1), (2, 0))));
+        assert_eq!(clip(&Line::new((0, 0), (8, -3)), &Rect::new((2, -6), (6, 6))), Some(Line::new((2, -1), (6, -2))));
+    }
+
+    #[test]
+    fn clip_bounds() {
+        assert_eq!(clip(&Line::largest(), &Rect::largest()), Some(Line::largest()));
+        assert_eq!(clip(&Line::new((MIN, MAX), (MAX, MIN)), &Rect::largest()), Some(Line::new((MIN, MAX), (MAX, MIN))));
+        assert_eq!(clip(&Line::largest(), &Rect::new((MIN + 1, MIN + 1), (MAX - 1, MAX - 1))), Some(Line::new((MIN + 1, MIN + 1), (MAX - 1, MAX - 1))));
+        assert_eq!(clip(&Line::new((MAX, MAX), (MIN, MIN)), &Rect::new((MIN + 1, MIN + 1), (MAX - 1, MAX - 1))), Some(Line::new((MAX - 1, MAX - 1), (MIN + 1, MIN + 1))));
+        assert_eq!(clip(&Line::new((MIN, MAX), (MAX, MAX)), &Rect::new((MIN, MIN), (MAX, MAX - 1))), None);
+        assert_eq!(clip(&Line::max(), &Rect::largest()), Some(Line::max()));
+    }
+}
@@ -3,6 +3,14 @@ use crate::cartesian::d2::{
     point::point_i32::Point,
 };
 
+mod all_intersections;
+mod clip;
+mod intersection;
+
+pub use self::all_intersections::all_intersections;
+pub use self::clip::clip;
+pub use self::intersection::{Intersection, intersection, intersects};
+
 #[derive(Eq, PartialEq, Debug, Clone)]
 pub struct Line {
     pub min: Point,
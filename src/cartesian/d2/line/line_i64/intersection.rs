@@ -0,0 +1,162 @@
+use super::Line;
+use crate::cartesian::d2::point::{
+    point_f64,
+    point_i64::{Point, orient2d},
+};
+use std::cmp::Ordering;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum Intersection {
+    Point(point_f64::Point),
+    Line(Line),
+}
+
+fn crossing(a: &Point, b: &Point, c: &Point, d: &Point) -> point_f64::Point {
+    let (d1_x, d1_y) = (b.x as f64 - a.x as f64, b.y as f64 - a.y as f64);
+    let (d2_x, d2_y) = (d.x as f64 - c.x as f64, d.y as f64 - c.y as f64);
+    let t = ((c.x as f64 - a.x as f64) * d2_y - (c.y as f64 - a.y as f64) * d2_x) / (d1_x * d2_y - d1_y * d2_x);
+    point_f64::Point::new(t.mul_add(d1_x, a.x as f64), t.mul_add(d1_y, a.y as f64))
+}
+
+fn to_point(p: &Point) -> point_f64::Point {
+    point_f64::Point::new(p.x as f64, p.y as f64)
+}
+
+fn sorted<'a>(p1: &'a Point, p2: &'a Point) -> (&'a Point, &'a Point) {
+    if (p1.x, p1.y) <= (p2.x, p2.y) { (p1, p2) } else { (p2, p1) }
+}
+
+fn overlap(l1: &Line, l2: &Line) -> Option<Intersection> {
+    let (start1, end1) = sorted(&l1.min, &l1.max);
+    let (start2, end2) = sorted(&l2.min, &l2.max);
+    let start = if (start1.x, start1.y) >= (start2.x, start2.y) { start1 } else { start2 };
+    let end = if (end1.x, end1.y) <= (end2.x, end2.y) { end1 } else { end2 };
+    if (start.x, start.y) > (end.x, end.y) {
+        None
+    } else if start == end {
+        Some(Intersection::Point(to_point(start)))
+    } else {
+        Some(Intersection::Line(Line { min: start.clone(), max: end.clone() }))
+    }
+}
+
+pub fn intersects(l1: &Line, l2: &Line) -> bool {
+    let o1 = orient2d(&l1.min, &l1.max, &l2.min);
+    let o2 = orient2d(&l1.min, &l1.max, &l2.max);
+    let o3 = orient2d(&l2.min, &l2.max, &l1.min);
+    let o4 = orient2d(&l2.min, &l2.max, &l1.max);
+    if o1 == Ordering::Equal && o2 == Ordering::Equal && o3 == Ordering::Equal && o4 == Ordering::Equal {
+        return overlap(l1, l2).is_some();
+    }
+    o1 != o2 && o3 != o4
+}
+
+pub fn intersection(l1: &Line, l2: &Line) -> Option<Intersection> {
+    let o1 = orient2d(&l1.min, &l1.max, &l2.min);
+    let o2 = orient2d(&l1.min, &l1.max, &l2.max);
+    let o3 = orient2d(&l2.min, &l2.max, &l1.min);
+    let o4 = orient2d(&l2.min, &l2.max, &l1.max);
+    if o1 == Ordering::Equal && o2 == Ordering::Equal && o3 == Ordering::Equal && o4 == Ordering::Equal {
+        return overlap(l1, l2);
+    }
+    if o1 == o2 || o3 == o4 {
+        return None;
+    }
+    if o1 == Ordering::Equal {
+        return Some(Intersection::Point(to_point(&l2.min)));
+    }
+    if o2 == Ordering::Equal {
+        return Some(Intersection::Point(to_point(&l2.max)));
+    }
+    if o3 == Ordering::Equal {
+        return Some(Intersection::Point(to_point(&l1.min)));
+    }
+    if o4 == Ordering::Equal {
+        return Some(Intersection::Point(to_point(&l1.max)));
+    }
+    Some(Intersection::Point(crossing(&l1.min, &l1.max, &l2.min, &l2.max)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Intersection, intersection, intersects};
+    use crate::cartesian::{
+        d1::point::point_i64::{MAX, MIN},
+        d2::{line::line_i64::Line, point::point_f64},
+    };
+
+    #[test]
+    fn crossing() {
+        assert_eq!(intersection(&Line::new((0, 0), (10, 10)), &Line::new((0, 10), (10, 0))), Some(Intersection::Point(point_f64::Point::new(5.0, 5.0))));
+        assert_eq!(intersection(&Line::new((0, 5), (10, 5)), &Line::new((5, 0), (5, 10))), Some(Intersection::Point(point_f64::Point::new(5.0, 5.0))));
+        assert_eq!(intersection(&Line::new((0, 0), (3, 1)), &Line::new((0, 1), (3, 0))), Some(Intersection::Point(point_f64::Point::new(1.5, 0.5))));
+        assert_eq!(intersection(&Line::new((10, 10), (0, 0)), &Line::new((10, 0), (0, 10))), Some(Intersection::Point(point_f64::Point::new(5.0, 5.0))));
+        assert!(intersects(&Line::new((0, 0), (10, 10)), &Line::new((0, 10), (10, 0))));
+        assert!(intersects(&Line::new((0, 5), (10, 5)), &Line::new((5, 0), (5, 10))));
+        assert!(intersects(&Line::new((0, 0), (3, 1)), &Line::new((0, 1), (3, 0))));
+        assert!(intersects(&Line::new((10, 10), (0, 0)), &Line::new((10, 0), (0, 10))));
+    }
+
+    #[test]
+    fn touching() {
+        assert_eq!(intersection(&Line::new((0, 0), (10, 10)), &Line::new((10, 10), (20, 0))), Some(Intersection::Point(point_f64::Point::new(10.0, 10.0))));
+        assert_eq!(intersection(&Line::new((0, 0), (10, 10)), &Line::new((20, 0), (0, 0))), Some(Intersection::Point(point_f64::Point::new(0.0, 0.0))));
+        assert_eq!(intersection(&Line::new((0, 5), (10, 5)), &Line::new((5, 5), (5, 10))), Some(Intersection::Point(point_f64::Point::new(5.0, 5.0))));
+        assert_eq!(intersection(&Line::new((5, 0), (5, 5)), &Line::new((0, 5), (10, 5))), Some(Intersection::Point(point_f64::Point::new(5.0, 5.0))));
+        assert!(intersects(&Line::new((0, 0), (10, 10)), &Line::new((10, 10), (20, 0))));
+        assert!(intersects(&Line::new((0, 0), (10, 10)), &Line::new((20, 0), (0, 0))));
+        assert!(intersects(&Line::new((0, 5), (10, 5)), &Line::new((5, 5), (5, 10))));
+        assert!(intersects(&Line::new((5, 0), (5, 5)), &Line::new((0, 5), (10, 5))));
+    }
+
+    #[test]
+    fn disjoint() {
+        assert_eq!(intersection(&Line::new((0, 0), (10, 10)), &Line::new((0, 1), (10, 11))), None);
+        assert_eq!(intersection(&Line::new((0, 0), (4, 4)), &Line::new((10, 0), (6, 4))), None);
+        assert_eq!(intersection(&Line::new((0, 5), (10, 5)), &Line::new((5, 6), (5, 10))), None);
+        assert_eq!(intersection(&Line::new((0, 0), (10, 0)), &Line::new((0, 1), (10, 1))), None);
+        assert!(!intersects(&Line::new((0, 0), (10, 10)), &Line::new((0, 1), (10, 11))));
+        assert!(!intersects(&Line::new((0, 0), (4, 4)), &Line::new((10, 0), (6, 4))));
+        assert!(!intersects(&Line::new((0, 5), (10, 5)), &Line::new((5, 6), (5, 10))));
+        assert!(!intersects(&Line::new((0, 0), (10, 0)), &Line::new((0, 1), (10, 1))));
+    }
+
+    #[test]
+    fn collinear() {
+        assert_eq!(intersection(&Line::new((0, 0), (10, 10)), &Line::new((5, 5), (15, 15))), Some(Intersection::Line(Line::new((5, 5), (10, 10)))));
+        assert_eq!(intersection(&Line::new((10, 10), (0, 0)), &Line::new((15, 15), (5, 5))), Some(Intersection::Line(Line::new((5, 5), (10, 10)))));
+        assert_eq!(intersection(&Line::new((0, 0), (10, 10)), &Line::new((2, 2), (8, 8))), Some(Intersection::Line(Line::new((2, 2), (8, 8)))));
+        assert_eq!(intersection(&Line::new((5, 0), (5, 10)), &Line::new((5, 5), (5, 20))), Some(Intersection::Line(Line::new((5, 5), (5, 10)))));
+        assert_eq!(intersection(&Line::new((0, 5), (10, 5)), &Line::new((0, 5), (10, 5))), Some(Intersection::Line(Line::new((0, 5), (10, 5)))));
+        assert_eq!(intersection(&Line::new((0, 0), (10, 10)), &Line::new((10, 10), (20, 20))), Some(Intersection::Point(point_f64::Point::new(10.0, 10.0))));
+        assert_eq!(intersection(&Line::new((0, 0), (10, 10)), &Line::new((11, 11), (20, 20))), None);
+        assert_eq!(intersection(&Line::new((5, 0), (5, 10)), &Line::new((5, 11), (5, 20))), None);
+        assert!(intersects(&Line::new((0, 0), (10, 10)), &Line::new((5, 5), (15, 15))));
+        assert!(intersects(&Line::new((0, 0), (10, 10)), &Line::new((10, 10), (20, 20))));
+        assert!(!intersects(&Line::new((0, 0), (10, 10)), &Line::new((11, 11), (20, 20))));
+        assert!(!intersects(&Line::new((5, 0), (5, 10)), &Line::new((5, 11), (5, 20))));
+    }
+
+    #[test]
+    fn degenerate() {
+        assert_eq!(intersection(&Line::new((5, 5), (5, 5)), &Line::new((0, 0), (10, 10))), Some(Intersection::Point(point_f64::Point::new(5.0, 5.0))));
+        assert_eq!(intersection(&Line::new((0, 0), (10, 10)), &Line::new((5, 5), (5, 5))), Some(Intersection::Point(point_f64::Point::new(5.0, 5.0))));
+        assert_eq!(intersection(&Line::new((5, 5), (5, 5)), &Line::new((5, 5), (5, 5))), Some(Intersection::Point(point_f64::Point::new(5.0, 5.0))));
+        assert_eq!(intersection(&Line::new((5, 6), (5, 6)), &Line::new((0, 0), (10, 10))), None);
+        assert_eq!(intersection(&Line::new((5, 5), (5, 5)), &Line::new((5, 6), (5, 6))), None);
+        assert!(intersects(&Line::new((5, 5), (5, 5)), &Line::new((0, 0), (10, 10))));
+        assert!(!intersects(&Line::new((5, 6), (5, 6)), &Line::new((0, 0), (10, 10))));
+    }
+
+    #[test]
+    fn bounds() {
+        let diagonal = Line::largest();
+        assert_eq!(intersection(&diagonal, &Line::new((MIN, MAX), (MAX, MIN))), Some(Intersection::Point(point_f64::Point::new(0.0, 0.0))));
+        assert_eq!(intersection(&diagonal, &Line::new((MIN + 1, MIN), (MAX, MAX))), Some(Intersection::Point(point_f64::Point::new(MAX as f64, MAX as f64))));
+        assert_eq!(intersection(&diagonal, &Line::new((MIN + 1, MIN), (MAX, MAX - 1))), None);
+        assert_eq!(intersection(&diagonal, &Line::new((MIN + 1, MIN + 1), (MAX - 1, MAX - 1))), Some(Intersection::Line(Line::new((MIN + 1, MIN + 1), (MAX - 1, MAX - 1)))));
+        assert!(intersects(&diagonal, &Line::new((MIN + 1, MIN), (MAX, MAX))));
+        assert!(!intersects(&diagonal, &Line::new((MIN + 1, MIN), (MAX, MAX - 1))));
+        assert!(!intersects(&Line::min(), &Line::max()));
+    }
+}
@@ -1,4 +1,11 @@
+pub mod bezier;
+pub mod bvh;
 pub mod circle;
 pub mod line;
 pub mod point;
+pub mod polygon;
+pub mod polyline;
+pub mod quadtree;
+pub mod ray;
 pub mod rect;
+pub mod rtree;
@@ -3,10 +3,12 @@ use crate::cartesian::d1::point::point_f32::{MAX, MIN};
 mod add;
 mod delta;
 mod distance;
+mod predicates;
 
 pub use self::add::{checked_add, checked_add_assign, saturating_add, saturating_add_assign, try_checked_add, try_checked_add_assign, wrapping_add, wrapping_add_assign};
 pub use self::delta::{delta, delta_max, delta_min, delta_x, delta_y};
 pub use self::distance::distance;
+pub use self::predicates::{incircle, orient2d};
 
 #[derive(PartialEq, Debug, Clone)]
 pub struct Point {
@@ -0,0 +1,86 @@
+use super::Point;
+use crate::cartesian::d2::point::point_f64;
+use std::cmp::Ordering;
+
+pub fn orient2d(a: &Point, b: &Point, c: &Point) -> Ordering {
+    point_f64::orient2d(&point_f64::Point::from(a.clone()), &point_f64::Point::from(b.clone()), &point_f64::Point::from(c.clone()))
+}
+
+pub fn incircle(a: &Point, b: &Point, c: &Point, d: &Point) -> Ordering {
+    point_f64::incircle(&point_f64::Point::from(a.clone()), &point_f64::Point::from(b.clone()), &point_f64::Point::from(c.clone()), &point_f64::Point::from(d.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{incircle, orient2d};
+    use crate::cartesian::{
+        d1::point::point_f32::{MAX, MIN},
+        d2::point::point_f32::Point,
+    };
+    use std::cmp::Ordering;
+
+    #[test]
+    fn test_orient2d() {
+        assert_eq!(orient2d(&Point::new(0.0, 0.0), &Point::new(4.0, 0.0), &Point::new(2.0, 3.0)), Ordering::Greater);
+        assert_eq!(orient2d(&Point::new(4.0, 0.0), &Point::new(0.0, 0.0), &Point::new(2.0, 3.0)), Ordering::Less);
+        assert_eq!(orient2d(&Point::new(0.0, 0.0), &Point::new(4.0, 0.0), &Point::new(2.0, 0.0)), Ordering::Equal);
+        assert_eq!(orient2d(&Point::new(0.0, 0.0), &Point::new(4.0, 0.0), &Point::new(9.0, 0.0)), Ordering::Equal);
+        assert_eq!(orient2d(&Point::new(1.0, 1.0), &Point::new(3.0, 3.0), &Point::new(5.0, 6.0)), Ordering::Greater);
+        assert_eq!(orient2d(&Point::new(1.0, 1.0), &Point::new(3.0, 3.0), &Point::new(6.0, 5.0)), Ordering::Less);
+        assert_eq!(orient2d(&Point::new(1.0, 1.0), &Point::new(1.0, 1.0), &Point::new(6.0, 5.0)), Ordering::Equal);
+    }
+
+    #[test]
+    fn orient2d_bounds() {
+        assert_eq!(orient2d(&Point::min(), &Point::new(MAX, MIN), &Point::new(MIN, MAX)), Ordering::Greater);
+        assert_eq!(orient2d(&Point::min(), &Point::new(MIN, MAX), &Point::new(MAX, MIN)), Ordering::Less);
+        assert_eq!(orient2d(&Point::min(), &Point::max(), &Point::new(MIN + 1.0, MIN + 1.0)), Ordering::Equal);
+        assert_eq!(orient2d(&Point::min(), &Point::max(), &Point::new(MIN + 1.0, MIN)), Ordering::Less);
+        assert_eq!(orient2d(&Point::min(), &Point::max(), &Point::new(MAX - 1.0, MAX)), Ordering::Greater);
+        assert_eq!(orient2d(&Point::new(MIN, MAX), &Point::new(MAX, MIN), &Point::new(MAX - 1.0, MIN + 1.0)), Ordering::Equal);
+        assert_eq!(orient2d(&Point::new(MIN, MAX), &Point::new(MAX, MIN), &Point::max()), Ordering::Greater);
+    }
+
+    #[test]
+    fn orient2d_precision() {
+        let a = Point::new(0.1, 0.1);
+        let b = Point::new(0.2, 0.2);
+        assert_eq!(orient2d(&a, &b, &Point::new(0.3, 0.3)), Ordering::Equal);
+        assert_eq!(orient2d(&a, &b, &Point::new(0.3, f32::from_bits(0.3_f32.to_bits() + 1))), Ordering::Greater);
+        assert_eq!(orient2d(&a, &b, &Point::new(0.3, f32::from_bits(0.3_f32.to_bits() - 1))), Ordering::Less);
+        assert_eq!(orient2d(&Point::new(0.5, 0.5), &Point::new(12.0, 12.0), &Point::new(24.0, 24.0)), Ordering::Equal);
+        assert_eq!(orient2d(&Point::new(f32::from_bits(0.5_f32.to_bits() + 1), 0.5), &Point::new(12.0, 12.0), &Point::new(24.0, 24.0)), Ordering::Less);
+    }
+
+    #[test]
+    fn test_incircle() {
+        let (a, b, c) = (Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(4.0, 4.0));
+        assert_eq!(incircle(&a, &b, &c, &Point::new(2.0, 2.0)), Ordering::Greater);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(1.0, 3.0)), Ordering::Greater);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(0.0, 4.0)), Ordering::Equal);
+        assert_eq!(incircle(&a, &b, &c, &a), Ordering::Equal);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(5.0, 5.0)), Ordering::Less);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(6.0, 0.0)), Ordering::Less);
+        assert_eq!(incircle(&a, &c, &b, &Point::new(2.0, 2.0)), Ordering::Less);
+        assert_eq!(incircle(&a, &c, &b, &Point::new(5.0, 5.0)), Ordering::Greater);
+    }
+
+    #[test]
+    fn incircle_bounds() {
+        let (a, b, c) = (Point::min(), Point::new(MAX, MIN), Point::max());
+        assert_eq!(incircle(&a, &b, &c, &Point::new(MIN, MAX)), Ordering::Equal);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(MIN + 1.0, MAX)), Ordering::Greater);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(MIN, MAX - 1.0)), Ordering::Greater);
+        assert_eq!(incircle(&c, &b, &a, &Point::new(MIN + 1.0, MAX)), Ordering::Less);
+        assert_eq!(incircle(&Point::new(MIN + 1.0, MIN), &b, &c, &Point::new(MIN, MAX)), Ordering::Less);
+    }
+
+    #[test]
+    fn incircle_precision() {
+        let (a, b, c) = (Point::new(0.0, 0.0), Point::new(1.0, 0.0), Point::new(1.0, 1.0));
+        assert_eq!(incircle(&a, &b, &c, &Point::new(0.0, 1.0)), Ordering::Equal);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(0.0, f32::from_bits(1.0_f32.to_bits() + 1))), Ordering::Less);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(0.0, f32::from_bits(1.0_f32.to_bits() - 1))), Ordering::Greater);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(f32::from_bits(1.0_f32.to_bits() + 1), 1.0)), Ordering::Less);
+    }
+}
@@ -0,0 +1,114 @@
+use super::Point;
+use crate::expansion;
+use std::cmp::Ordering;
+
+pub fn orient2d(a: &Point, b: &Point, c: &Point) -> Ordering {
+    let left = (a.x - c.x) * (b.y - c.y);
+    let right = (a.y - c.y) * (b.x - c.x);
+    let det = left - right;
+    let err_bound = (3.0 + 16.0 * f64::EPSILON) * f64::EPSILON * (left.abs() + right.abs());
+    if det > err_bound {
+        return Ordering::Greater;
+    }
+    if det < -err_bound {
+        return Ordering::Less;
+    }
+    expansion::orient2d(&expansion::diff(a.x, c.x), &expansion::diff(a.y, c.y), &expansion::diff(b.x, c.x), &expansion::diff(b.y, c.y))
+}
+
+pub fn incircle(a: &Point, b: &Point, c: &Point, d: &Point) -> Ordering {
+    let (adx, ady) = (a.x - d.x, a.y - d.y);
+    let (bdx, bdy) = (b.x - d.x, b.y - d.y);
+    let (cdx, cdy) = (c.x - d.x, c.y - d.y);
+    let (bdx_cdy, cdx_bdy) = (bdx * cdy, cdx * bdy);
+    let (cdx_ady, adx_cdy) = (cdx * ady, adx * cdy);
+    let (adx_bdy, bdx_ady) = (adx * bdy, bdx * ady);
+    let a_lift = adx * adx + ady * ady;
+    let b_lift = bdx * bdx + bdy * bdy;
+    let c_lift = cdx * cdx + cdy * cdy;
+    let det = a_lift * (bdx_cdy - cdx_bdy) + b_lift * (cdx_ady - adx_cdy) + c_lift * (adx_bdy - bdx_ady);
+    let permanent = (bdx_cdy.abs() + cdx_bdy.abs()) * a_lift + (cdx_ady.abs() + adx_cdy.abs()) * b_lift + (adx_bdy.abs() + bdx_ady.abs()) * c_lift;
+    let err_bound = (10.0 + 96.0 * f64::EPSILON) * f64::EPSILON * permanent;
+    if det > err_bound {
+        return Ordering::Greater;
+    }
+    if det < -err_bound {
+        return Ordering::Less;
+    }
+    expansion::incircle(&expansion::diff(a.x, d.x), &expansion::diff(a.y, d.y), &expansion::diff(b.x, d.x), &expansion::diff(b.y, d.y), &expansion::diff(c.x, d.x), &expansion::diff(c.y, d.y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{incircle, orient2d};
+    use crate::cartesian::{
+        d1::point::point_f64::{MAX, MIN},
+        d2::point::point_f64::Point,
+    };
+    use std::cmp::Ordering;
+
+    #[test]
+    fn test_orient2d() {
+        assert_eq!(orient2d(&Point::new(0.0, 0.0), &Point::new(4.0, 0.0), &Point::new(2.0, 3.0)), Ordering::Greater);
+        assert_eq!(orient2d(&Point::new(4.0, 0.0), &Point::new(0.0, 0.0), &Point::new(2.0, 3.0)), Ordering::Less);
+        assert_eq!(orient2d(&Point::new(0.0, 0.0), &Point::new(4.0, 0.0), &Point::new(2.0, 0.0)), Ordering::Equal);
+        assert_eq!(orient2d(&Point::new(0.0, 0.0), &Point::new(4.0, 0.0), &Point::new(9.0, 0.0)), Ordering::Equal);
+        assert_eq!(orient2d(&Point::new(1.0, 1.0), &Point::new(3.0, 3.0), &Point::new(5.0, 6.0)), Ordering::Greater);
+        assert_eq!(orient2d(&Point::new(1.0, 1.0), &Point::new(3.0, 3.0), &Point::new(6.0, 5.0)), Ordering::Less);
+        assert_eq!(orient2d(&Point::new(1.0, 1.0), &Point::new(1.0, 1.0), &Point::new(6.0, 5.0)), Ordering::Equal);
+    }
+
+    #[test]
+    fn orient2d_bounds() {
+        assert_eq!(orient2d(&Point::min(), &Point::new(MAX, MIN), &Point::new(MIN, MAX)), Ordering::Greater);
+        assert_eq!(orient2d(&Point::min(), &Point::new(MIN, MAX), &Point::new(MAX, MIN)), Ordering::Less);
+        assert_eq!(orient2d(&Point::min(), &Point::max(), &Point::new(MIN + 1.0, MIN + 1.0)), Ordering::Equal);
+        assert_eq!(orient2d(&Point::min(), &Point::max(), &Point::new(MIN + 1.0, MIN)), Ordering::Less);
+        assert_eq!(orient2d(&Point::min(), &Point::max(), &Point::new(MAX - 1.0, MAX)), Ordering::Greater);
+        assert_eq!(orient2d(&Point::new(MIN, MAX), &Point::new(MAX, MIN), &Point::new(MAX - 1.0, MIN + 1.0)), Ordering::Equal);
+        assert_eq!(orient2d(&Point::new(MIN, MAX), &Point::new(MAX, MIN), &Point::max()), Ordering::Greater);
+    }
+
+    #[test]
+    fn orient2d_precision() {
+        let a = Point::new(0.1, 0.1);
+        let b = Point::new(0.2, 0.2);
+        assert_eq!(orient2d(&a, &b, &Point::new(0.3, 0.3)), Ordering::Equal);
+        assert_eq!(orient2d(&a, &b, &Point::new(0.3, f64::from_bits(0.3_f64.to_bits() + 1))), Ordering::Greater);
+        assert_eq!(orient2d(&a, &b, &Point::new(0.3, f64::from_bits(0.3_f64.to_bits() - 1))), Ordering::Less);
+        assert_eq!(orient2d(&Point::new(0.5, 0.5), &Point::new(12.0, 12.0), &Point::new(24.0, 24.0)), Ordering::Equal);
+        assert_eq!(orient2d(&Point::new(f64::from_bits(0.5_f64.to_bits() + 1), 0.5), &Point::new(12.0, 12.0), &Point::new(24.0, 24.0)), Ordering::Less);
+    }
+
+    #[test]
+    fn test_incircle() {
+        let (a, b, c) = (Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(4.0, 4.0));
+        assert_eq!(incircle(&a, &b, &c, &Point::new(2.0, 2.0)), Ordering::Greater);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(1.0, 3.0)), Ordering::Greater);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(0.0, 4.0)), Ordering::Equal);
+        assert_eq!(incircle(&a, &b, &c, &a), Ordering::Equal);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(5.0, 5.0)), Ordering::Less);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(6.0, 0.0)), Ordering::Less);
+        assert_eq!(incircle(&a, &c, &b, &Point::new(2.0, 2.0)), Ordering::Less);
+        assert_eq!(incircle(&a, &c, &b, &Point::new(5.0, 5.0)), Ordering::Greater);
+    }
+
+    #[test]
+    fn incircle_bounds() {
+        let (a, b, c) = (Point::min(), Point::new(MAX, MIN), Point::max());
+        assert_eq!(incircle(&a, &b, &c, &Point::new(MIN, MAX)), Ordering::Equal);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(MIN + 1.0, MAX)), Ordering::Greater);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(MIN, MAX - 1.0)), Ordering::Greater);
+        assert_eq!(incircle(&c, &b, &a, &Point::new(MIN + 1.0, MAX)), Ordering::Less);
+        assert_eq!(incircle(&Point::new(MIN + 1.0, MIN), &b, &c, &Point::new(MIN, MAX)), Ordering::Less);
+    }
+
+    #[test]
+    fn incircle_precision() {
+        let (a, b, c) = (Point::new(0.0, 0.0), Point::new(1.0, 0.0), Point::new(1.0, 1.0));
+        assert_eq!(incircle(&a, &b, &c, &Point::new(0.0, 1.0)), Ordering::Equal);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(0.0, f64::from_bits(1.0_f64.to_bits() + 1))), Ordering::Less);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(0.0, f64::from_bits(1.0_f64.to_bits() - 1))), Ordering::Greater);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(f64::from_bits(1.0_f64.to_bits() + 1), 1.0)), Ordering::Less);
+    }
+}
@@ -0,0 +1,75 @@
+use super::Point;
+use crate::expansion;
+use std::cmp::Ordering;
+
+pub fn orient2d(a: &Point, b: &Point, c: &Point) -> Ordering {
+    let (acx, acy) = (i128::from(a.x) - i128::from(c.x), i128::from(a.y) - i128::from(c.y));
+    let (bcx, bcy) = (i128::from(b.x) - i128::from(c.x), i128::from(b.y) - i128::from(c.y));
+    (acx * bcy - acy * bcx).cmp(&0)
+}
+
+pub fn incircle(a: &Point, b: &Point, c: &Point, d: &Point) -> Ordering {
+    expansion::incircle(
+        &expansion::from_f64((i64::from(a.x) - i64::from(d.x)) as f64),
+        &expansion::from_f64((i64::from(a.y) - i64::from(d.y)) as f64),
+        &expansion::from_f64((i64::from(b.x) - i64::from(d.x)) as f64),
+        &expansion::from_f64((i64::from(b.y) - i64::from(d.y)) as f64),
+        &expansion::from_f64((i64::from(c.x) - i64::from(d.x)) as f64),
+        &expansion::from_f64((i64::from(c.y) - i64::from(d.y)) as f64),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{incircle, orient2d};
+    use crate::cartesian::{
+        d1::point::point_i32::{MAX, MIN},
+        d2::point::point_i32::Point,
+    };
+    use std::cmp::Ordering;
+
+    #[test]
+    fn test_orient2d() {
+        assert_eq!(orient2d(&Point::new(0, 0), &Point::new(4, 0), &Point::new(2, 3)), Ordering::Greater);
+        assert_eq!(orient2d(&Point::new(4, 0), &Point::new(0, 0), &Point::new(2, 3)), Ordering::Less);
+        assert_eq!(orient2d(&Point::new(0, 0), &Point::new(4, 0), &Point::new(2, 0)), Ordering::Equal);
+        assert_eq!(orient2d(&Point::new(0, 0), &Point::new(4, 0), &Point::new(9, 0)), Ordering::Equal);
+        assert_eq!(orient2d(&Point::new(1, 1), &Point::new(3, 3), &Point::new(5, 6)), Ordering::Greater);
+        assert_eq!(orient2d(&Point::new(1, 1), &Point::new(3, 3), &Point::new(6, 5)), Ordering::Less);
+        assert_eq!(orient2d(&Point::new(1, 1), &Point::new(1, 1), &Point::new(6, 5)), Ordering::Equal);
+    }
+
+    #[test]
+    fn orient2d_bounds() {
+        assert_eq!(orient2d(&Point::min(), &Point::new(MAX, MIN), &Point::new(MIN, MAX)), Ordering::Greater);
+        assert_eq!(orient2d(&Point::min(), &Point::new(MIN, MAX), &Point::new(MAX, MIN)), Ordering::Less);
+        assert_eq!(orient2d(&Point::min(), &Point::max(), &Point::new(MIN + 1, MIN + 1)), Ordering::Equal);
+        assert_eq!(orient2d(&Point::min(), &Point::max(), &Point::new(MIN + 1, MIN)), Ordering::Less);
+        assert_eq!(orient2d(&Point::min(), &Point::max(), &Point::new(MAX - 1, MAX)), Ordering::Greater);
+        assert_eq!(orient2d(&Point::new(MIN, MAX), &Point::new(MAX, MIN), &Point::new(MAX - 1, MIN + 1)), Ordering::Equal);
+        assert_eq!(orient2d(&Point::new(MIN, MAX), &Point::new(MAX, MIN), &Point::max()), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_incircle() {
+        let (a, b, c) = (Point::new(0, 0), Point::new(4, 0), Point::new(4, 4));
+        assert_eq!(incircle(&a, &b, &c, &Point::new(2, 2)), Ordering::Greater);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(1, 3)), Ordering::Greater);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(0, 4)), Ordering::Equal);
+        assert_eq!(incircle(&a, &b, &c, &a), Ordering::Equal);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(5, 5)), Ordering::Less);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(6, 0)), Ordering::Less);
+        assert_eq!(incircle(&a, &c, &b, &Point::new(2, 2)), Ordering::Less);
+        assert_eq!(incircle(&a, &c, &b, &Point::new(5, 5)), Ordering::Greater);
+    }
+
+    #[test]
+    fn incircle_bounds() {
+        let (a, b, c) = (Point::min(), Point::new(MAX, MIN), Point::max());
+        assert_eq!(incircle(&a, &b, &c, &Point::new(MIN, MAX)), Ordering::Equal);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(MIN + 1, MAX)), Ordering::Greater);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(MIN, MAX - 1)), Ordering::Greater);
+        assert_eq!(incircle(&c, &b, &a, &Point::new(MIN + 1, MAX)), Ordering::Less);
+        assert_eq!(incircle(&Point::new(MIN + 1, MIN), &b, &c, &Point::new(MIN, MAX)), Ordering::Less);
+    }
+}
@@ -0,0 +1,71 @@
+use super::Point;
+use std::cmp::Ordering;
+
+pub fn orient2d(a: &Point, b: &Point, c: &Point) -> Ordering {
+    let (acx, acy) = (i64::from(a.x) - i64::from(c.x), i64::from(a.y) - i64::from(c.y));
+    let (bcx, bcy) = (i64::from(b.x) - i64::from(c.x), i64::from(b.y) - i64::from(c.y));
+    (acx * bcy - acy * bcx).cmp(&0)
+}
+
+pub fn incircle(a: &Point, b: &Point, c: &Point, d: &Point) -> Ordering {
+    let (adx, ady) = (i128::from(a.x) - i128::from(d.x), i128::from(a.y) - i128::from(d.y));
+    let (bdx, bdy) = (i128::from(b.x) - i128::from(d.x), i128::from(b.y) - i128::from(d.y));
+    let (cdx, cdy) = (i128::from(c.x) - i128::from(d.x), i128::from(c.y) - i128::from(d.y));
+    let a_lift = adx * adx + ady * ady;
+    let b_lift = bdx * bdx + bdy * bdy;
+    let c_lift = cdx * cdx + cdy * cdy;
+    let det = a_lift * (bdx * cdy - cdx * bdy) + b_lift * (cdx * ady - adx * cdy) + c_lift * (adx * bdy - bdx * ady);
+    det.cmp(&0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{incircle, orient2d};
+    use crate::cartesian::{d1::point::point_u16::MAX, d2::point::point_u16::Point};
+    use std::cmp::Ordering;
+
+    #[test]
+    fn test_orient2d() {
+        assert_eq!(orient2d(&Point::new(0, 0), &Point::new(4, 0), &Point::new(2, 3)), Ordering::Greater);
+        assert_eq!(orient2d(&Point::new(4, 0), &Point::new(0, 0), &Point::new(2, 3)), Ordering::Less);
+        assert_eq!(orient2d(&Point::new(0, 0), &Point::new(4, 0), &Point::new(2, 0)), Ordering::Equal);
+        assert_eq!(orient2d(&Point::new(0, 0), &Point::new(4, 0), &Point::new(9, 0)), Ordering::Equal);
+        assert_eq!(orient2d(&Point::new(1, 1), &Point::new(3, 3), &Point::new(5, 6)), Ordering::Greater);
+        assert_eq!(orient2d(&Point::new(1, 1), &Point::new(3, 3), &Point::new(6, 5)), Ordering::Less);
+        assert_eq!(orient2d(&Point::new(1, 1), &Point::new(1, 1), &Point::new(6, 5)), Ordering::Equal);
+    }
+
+    #[test]
+    fn orient2d_bounds() {
+        assert_eq!(orient2d(&Point::min(), &Point::new(MAX, 0), &Point::new(0, MAX)), Ordering::Greater);
+        assert_eq!(orient2d(&Point::min(), &Point::new(0, MAX), &Point::new(MAX, 0)), Ordering::Less);
+        assert_eq!(orient2d(&Point::min(), &Point::max(), &Point::new(1, 1)), Ordering::Equal);
+        assert_eq!(orient2d(&Point::min(), &Point::max(), &Point::new(1, 0)), Ordering::Less);
+        assert_eq!(orient2d(&Point::min(), &Point::max(), &Point::new(MAX - 1, MAX)), Ordering::Greater);
+        assert_eq!(orient2d(&Point::new(0, MAX), &Point::new(MAX, 0), &Point::new(MAX - 1, 1)), Ordering::Equal);
+        assert_eq!(orient2d(&Point::new(0, MAX), &Point::new(MAX, 0), &Point::max()), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_incircle() {
+        let (a, b, c) = (Point::new(0, 0), Point::new(4, 0), Point::new(4, 4));
+        assert_eq!(incircle(&a, &b, &c, &Point::new(2, 2)), Ordering::Greater);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(1, 3)), Ordering::Greater);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(0, 4)), Ordering::Equal);
+        assert_eq!(incircle(&a, &b, &c, &a), Ordering::Equal);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(5, 5)), Ordering::Less);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(6, 0)), Ordering::Less);
+        assert_eq!(incircle(&a, &c, &b, &Point::new(2, 2)), Ordering::Less);
+        assert_eq!(incircle(&a, &c, &b, &Point::new(5, 5)), Ordering::Greater);
+    }
+
+    #[test]
+    fn incircle_bounds() {
+        let (a, b, c) = (Point::min(), Point::new(MAX, 0), Point::max());
+        assert_eq!(incircle(&a, &b, &c, &Point::new(0, MAX)), Ordering::Equal);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(1, MAX)), Ordering::Greater);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(0, MAX - 1)), Ordering::Greater);
+        assert_eq!(incircle(&c, &b, &a, &Point::new(1, MAX)), Ordering::Less);
+        assert_eq!(incircle(&Point::new(1, 0), &b, &c, &Point::new(0, MAX)), Ordering::Less);
+    }
+}
@@ -0,0 +1,19 @@
+pub mod polygon_u16;
+pub mod polygon_u32;
+pub mod polygon_u64;
+pub mod polygon_u8;
+
+pub mod polygon_i16;
+pub mod polygon_i32;
+pub mod polygon_i64;
+pub mod polygon_i8;
+
+pub mod polygon_f32;
+pub mod polygon_f64;
+
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub enum Winding {
+    Clockwise,
+    CounterClockwise,
+    Degenerate,
+}
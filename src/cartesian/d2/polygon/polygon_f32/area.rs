@@ -0,0 +1,53 @@
+use super::Polygon;
+
+pub fn signed_double_area(p: &Polygon) -> f32 {
+    p.points.iter().zip(p.points.iter().cycle().skip(1)).map(|(a, b)| a.x * b.y - b.x * a.y).sum()
+}
+
+pub fn area(p: &Polygon) -> f32 {
+    signed_double_area(p).abs() / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{area, signed_double_area};
+    use crate::cartesian::{
+        d1::point::point_f32::{MAX, MIN},
+        d2::polygon::polygon_f32::Polygon,
+    };
+
+    #[test]
+    fn test_signed_double_area() {
+        assert_eq!(signed_double_area(&Polygon::new(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)])), 32.0);
+        assert_eq!(signed_double_area(&Polygon::new(&[(0.0, 4.0), (4.0, 4.0), (4.0, 0.0), (0.0, 0.0)])), -32.0);
+        assert_eq!(signed_double_area(&Polygon::new(&[(0.0, 0.0), (6.0, 0.0), (0.0, 3.0)])), 18.0);
+        assert_eq!(signed_double_area(&Polygon::new(&[(0.0, 0.0), (4.0, 0.0), (4.0, 2.0), (2.0, 2.0), (2.0, 4.0), (0.0, 4.0)])), 24.0);
+        assert_eq!(signed_double_area(&Polygon::new(&[(2.0, 0.0), (5.0, 9.0), (8.0, 0.0), (0.0, 6.0), (10.0, 6.0)])), -78.0);
+    }
+
+    #[test]
+    fn signed_double_area_degenerate() {
+        assert_eq!(signed_double_area(&Polygon::new(&[])), 0.0);
+        assert_eq!(signed_double_area(&Polygon::new(&[(1.0, 1.0)])), 0.0);
+        assert_eq!(signed_double_area(&Polygon::new(&[(1.0, 1.0), (3.0, 3.0)])), 0.0);
+        assert_eq!(signed_double_area(&Polygon::new(&[(0.0, 0.0), (2.0, 0.0), (4.0, 0.0)])), 0.0);
+    }
+
+    #[test]
+    fn signed_double_area_bounds() {
+        assert_eq!(signed_double_area(&Polygon::new(&[(MIN, MIN), (0.0, MIN), (0.0, 0.0), (MIN, 0.0)])), 2.0 * MIN * MIN);
+        assert_eq!(signed_double_area(&Polygon::new(&[(0.0, 0.0), (MAX, 0.0), (0.0, MAX)])), MAX * MAX);
+        assert_eq!(signed_double_area(&Polygon::new(&[(0.0, 0.0), (0.0, MAX), (MAX, 0.0)])), -MAX * MAX);
+        assert_eq!(area(&Polygon::new(&[(MIN, MIN), (0.0, MIN), (0.0, 0.0), (MIN, 0.0)])), MIN * MIN);
+    }
+
+    #[test]
+    fn test_area() {
+        assert_eq!(area(&Polygon::new(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)])), 16.0);
+        assert_eq!(area(&Polygon::new(&[(0.0, 4.0), (4.0, 4.0), (4.0, 0.0), (0.0, 0.0)])), 16.0);
+        assert_eq!(area(&Polygon::new(&[(0.0, 0.0), (6.0, 0.0), (0.0, 3.0)])), 9.0);
+        assert_eq!(area(&Polygon::new(&[(0.0, 0.0), (4.0, 0.0), (4.0, 2.0), (2.0, 2.0), (2.0, 4.0), (0.0, 4.0)])), 12.0);
+        assert_eq!(area(&Polygon::new(&[(0.0, 0.0), (2.0, 0.0), (4.0, 0.0)])), 0.0);
+        assert_eq!(area(&Polygon::new(&[])), 0.0);
+    }
+}
@@ -0,0 +1,121 @@
+use super::Polygon;
+use crate::cartesian::d2::point::point_f32::{Point, orient2d};
+use std::cmp::Ordering;
+
+fn on_edge(a: &Point, b: &Point, p: &Point) -> bool {
+    orient2d(a, b, p) == Ordering::Equal && p.x >= a.x.min(b.x) && p.x <= a.x.max(b.x) && p.y >= a.y.min(b.y) && p.y <= a.y.max(b.y)
+}
+
+pub fn contains_point_even_odd(polygon: &Polygon, p: &Point) -> bool {
+    let mut inside = false;
+    for (a, b) in polygon.points.iter().zip(polygon.points.iter().cycle().skip(1)) {
+        if on_edge(a, b, p) {
+            return true;
+        }
+        if (a.y > p.y) != (b.y > p.y) {
+            let side = if b.y > a.y { Ordering::Greater } else { Ordering::Less };
+            if orient2d(a, b, p) == side {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+pub fn contains_point_non_zero(polygon: &Polygon, p: &Point) -> bool {
+    let mut winding_number = 0;
+    for (a, b) in polygon.points.iter().zip(polygon.points.iter().cycle().skip(1)) {
+        if on_edge(a, b, p) {
+            return true;
+        }
+        if a.y <= p.y {
+            if b.y > p.y && orient2d(a, b, p) == Ordering::Greater {
+                winding_number += 1;
+            }
+        } else if b.y <= p.y && orient2d(a, b, p) == Ordering::Less {
+            winding_number -= 1;
+        }
+    }
+    winding_number != 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{contains_point_even_odd, contains_point_non_zero};
+    use crate::cartesian::{
+        d1::point::point_f32::{MAX, MIN},
+        d2::{point::point_f32::Point, polygon::polygon_f32::Polygon},
+    };
+
+    #[test]
+    fn inside() {
+        assert!(contains_point_even_odd(&Polygon::new(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)]), &Point::new(2.0, 2.0)));
+        assert!(contains_point_even_odd(&Polygon::new(&[(0.0, 4.0), (4.0, 4.0), (4.0, 0.0), (0.0, 0.0)]), &Point::new(1.0, 3.0)));
+        assert!(contains_point_even_odd(&Polygon::new(&[(0.0, 0.0), (4.0, 0.0), (4.0, 2.0), (2.0, 2.0), (2.0, 4.0), (0.0, 4.0)]), &Point::new(1.0, 3.0)));
+        assert!(contains_point_even_odd(&Polygon::new(&[(0.0, 0.0), (4.0, 0.0), (4.0, 2.0), (2.0, 2.0), (2.0, 4.0), (0.0, 4.0)]), &Point::new(3.0, 1.0)));
+        assert!(contains_point_non_zero(&Polygon::new(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)]), &Point::new(2.0, 2.0)));
+        assert!(contains_point_non_zero(&Polygon::new(&[(0.0, 4.0), (4.0, 4.0), (4.0, 0.0), (0.0, 0.0)]), &Point::new(1.0, 3.0)));
+        assert!(contains_point_non_zero(&Polygon::new(&[(0.0, 0.0), (4.0, 0.0), (4.0, 2.0), (2.0, 2.0), (2.0, 4.0), (0.0, 4.0)]), &Point::new(1.0, 3.0)));
+        assert!(contains_point_non_zero(&Polygon::new(&[(0.0, 0.0), (4.0, 0.0), (4.0, 2.0), (2.0, 2.0), (2.0, 4.0), (0.0, 4.0)]), &Point::new(3.0, 1.0)));
+    }
+
+    #[test]
+    fn borders() {
+        for p in [Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(4.0, 2.0), Point::new(2.0, 4.0), Point::new(0.0, 1.0)] {
+            assert!(contains_point_even_odd(&Polygon::new(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)]), &p));
+            assert!(contains_point_non_zero(&Polygon::new(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)]), &p));
+        }
+        for p in [Point::new(2.0, 2.0), Point::new(3.0, 2.0), Point::new(2.0, 3.0), Point::new(0.0, 4.0)] {
+            assert!(contains_point_even_odd(&Polygon::new(&[(0.0, 0.0), (4.0, 0.0), (4.0, 2.0), (2.0, 2.0), (2.0, 4.0), (0.0, 4.0)]), &p));
+            assert!(contains_point_non_zero(&Polygon::new(&[(0.0, 0.0), (4.0, 0.0), (4.0, 2.0), (2.0, 2.0), (2.0, 4.0), (0.0, 4.0)]), &p));
+        }
+    }
+
+    #[test]
+    fn outside() {
+        for p in [Point::new(5.0, 2.0), Point::new(2.0, 5.0), Point::new(5.0, 5.0), Point::new(6.0, 0.0), Point::new(0.0, 6.0)] {
+            assert!(!contains_point_even_odd(&Polygon::new(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)]), &p));
+            assert!(!contains_point_non_zero(&Polygon::new(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)]), &p));
+        }
+        for p in [Point::new(3.0, 3.0), Point::new(4.0, 4.0), Point::new(3.0, 4.0)] {
+            assert!(!contains_point_even_odd(&Polygon::new(&[(0.0, 0.0), (4.0, 0.0), (4.0, 2.0), (2.0, 2.0), (2.0, 4.0), (0.0, 4.0)]), &p));
+            assert!(!contains_point_non_zero(&Polygon::new(&[(0.0, 0.0), (4.0, 0.0), (4.0, 2.0), (2.0, 2.0), (2.0, 4.0), (0.0, 4.0)]), &p));
+        }
+    }
+
+    #[test]
+    fn fill_rules() {
+        let star = Polygon::new(&[(2.0, 0.0), (5.0, 9.0), (8.0, 0.0), (0.0, 6.0), (10.0, 6.0)]);
+        assert!(!contains_point_even_odd(&star, &Point::new(5.0, 4.0)));
+        assert!(contains_point_non_zero(&star, &Point::new(5.0, 4.0)));
+        assert!(contains_point_even_odd(&star, &Point::new(5.0, 7.0)));
+        assert!(contains_point_non_zero(&star, &Point::new(5.0, 7.0)));
+        assert!(contains_point_even_odd(&star, &Point::new(2.0, 5.0)));
+        assert!(contains_point_non_zero(&star, &Point::new(2.0, 5.0)));
+        assert!(!contains_point_even_odd(&star, &Point::new(5.0, 1.0)));
+        assert!(!contains_point_non_zero(&star, &Point::new(5.0, 1.0)));
+    }
+
+    #[test]
+    fn degenerate() {
+        assert!(!contains_point_even_odd(&Polygon::new(&[]), &Point::new(0.0, 0.0)));
+        assert!(!contains_point_non_zero(&Polygon::new(&[]), &Point::new(0.0, 0.0)));
+        assert!(contains_point_even_odd(&Polygon::new(&[(1.0, 1.0)]), &Point::new(1.0, 1.0)));
+        assert!(contains_point_non_zero(&Polygon::new(&[(1.0, 1.0)]), &Point::new(1.0, 1.0)));
+        assert!(contains_point_even_odd(&Polygon::new(&[(0.0, 0.0), (2.0, 0.0), (4.0, 0.0)]), &Point::new(3.0, 0.0)));
+        assert!(!contains_point_non_zero(&Polygon::new(&[(0.0, 0.0), (2.0, 0.0), (4.0, 0.0)]), &Point::new(3.0, 1.0)));
+    }
+
+    #[test]
+    fn bounds() {
+        let largest = Polygon::new(&[(MIN, MIN), (MAX, MIN), (MAX, MAX), (MIN, MAX)]);
+        assert!(contains_point_even_odd(&largest, &Point::min()));
+        assert!(contains_point_non_zero(&largest, &Point::max()));
+        assert!(contains_point_even_odd(&largest, &Point::new(MIN, MAX)));
+        assert!(contains_point_non_zero(&largest, &Point::new(MAX, MIN)));
+        let triangle = Polygon::new(&[(MIN, MIN), (MAX, MIN), (MIN, MAX)]);
+        assert!(contains_point_even_odd(&triangle, &Point::new(MIN, MAX)));
+        assert!(!contains_point_even_odd(&triangle, &Point::max()));
+        assert!(!contains_point_non_zero(&triangle, &Point::max()));
+    }
+}
@@ -0,0 +1,56 @@
+use crate::cartesian::d2::point::point_f32::Point;
+
+mod area;
+mod centroid;
+mod contains_point;
+mod convex;
+mod convex_hull;
+mod winding;
+
+pub use self::area::{area, signed_double_area};
+pub use self::centroid::centroid;
+pub use self::contains_point::{contains_point_even_odd, contains_point_non_zero};
+pub use self::convex::is_convex;
+pub use self::convex_hull::convex_hull;
+pub use self::winding::winding;
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Polygon {
+    pub points: Vec<Point>,
+}
+
+impl Polygon {
+    pub fn new(points: &[(f32, f32)]) -> Self {
+        Polygon { points: points.iter().map(|p| Point::new(p.0, p.1)).collect() }
+    }
+}
+
+impl std::fmt::Display for Polygon {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let points: Vec<String> = self.points.iter().map(|p| p.to_string()).collect();
+        write!(f, "({})", points.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Polygon;
+    use crate::cartesian::{
+        d1::point::point_f32::{MAX, MIN},
+        d2::point::point_f32::Point,
+    };
+
+    #[test]
+    fn polygon() {
+        assert_eq!(Polygon::new(&[]), Polygon { points: vec![] });
+        assert_eq!(Polygon::new(&[(0.0, 0.0), (4.0, 0.0), (0.0, 3.0)]), Polygon { points: vec![Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(0.0, 3.0)] });
+        assert_eq!(Polygon::new(&[(MIN, MIN), (MAX, MAX)]), Polygon { points: vec![Point::min(), Point::max()] });
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(Polygon::new(&[]).to_string(), "()");
+        assert_eq!(Polygon::new(&[(0.0, 0.0), (4.0, 0.0), (0.0, 3.0)]).to_string(), "((0, 0), (4, 0), (0, 3))");
+        assert_eq!(Polygon::new(&[(MIN, MIN), (MAX, MAX)]).to_string(), format!("({}, {})", Point::min(), Point::max()));
+    }
+}
@@ -0,0 +1,51 @@
+use super::Polygon;
+use crate::cartesian::d2::point::point_f64::Point;
+
+pub fn centroid(p: &Polygon) -> Option<Point> {
+    let origin = p.points.first()?;
+    let mut double_area = 0.0;
+    let mut x = 0.0;
+    let mut y = 0.0;
+    for (a, b) in p.points.iter().zip(p.points.iter().cycle().skip(1)) {
+        let (a_x, a_y) = (a.x - origin.x, a.y - origin.y);
+        let (b_x, b_y) = (b.x - origin.x, b.y - origin.y);
+        let cross = a_x * b_y - b_x * a_y;
+        double_area += cross;
+        x += (a_x + b_x) * cross;
+        y += (a_y + b_y) * cross;
+    }
+    if double_area == 0.0 {
+        return None;
+    }
+    Some(Point::new(x / (3.0 * double_area) + origin.x, y / (3.0 * double_area) + origin.y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::centroid;
+    use crate::cartesian::{
+        d1::point::point_f64::{MAX, MIN},
+        d2::{point::point_f64::Point, polygon::polygon_f64::Polygon},
+    };
+
+    #[test]
+    fn test_centroid() {
+        assert_eq!(centroid(&Polygon::new(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)])), Some(Point::new(2.0, 2.0)));
+        assert_eq!(centroid(&Polygon::new(&[(0.0, 4.0), (4.0, 4.0), (4.0, 0.0), (0.0, 0.0)])), Some(Point::new(2.0, 2.0)));
+        assert_eq!(centroid(&Polygon::new(&[(0.0, 0.0), (6.0, 0.0), (0.0, 3.0)])), Some(Point::new(2.0, 1.0)));
+        assert_eq!(centroid(&Polygon::new(&[(10.0, 10.0), (14.0, 10.0), (14.0, 14.0), (10.0, 14.0)])), Some(Point::new(12.0, 12.0)));
+    }
+
+    #[test]
+    fn degenerate() {
+        assert_eq!(centroid(&Polygon::new(&[])), None);
+        assert_eq!(centroid(&Polygon::new(&[(1.0, 1.0)])), None);
+        assert_eq!(centroid(&Polygon::new(&[(1.0, 1.0), (3.0, 3.0)])), None);
+        assert_eq!(centroid(&Polygon::new(&[(0.0, 0.0), (2.0, 0.0), (4.0, 0.0)])), None);
+    }
+
+    #[test]
+    fn bounds() {
+        assert_eq!(centroid(&Polygon::new(&[(MIN, MIN), (MAX, MIN), (MAX, MAX), (MIN, MAX)])), Some(Point::new(0.0, 0.0)));
+    }
+}
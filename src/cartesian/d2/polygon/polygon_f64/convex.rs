@@ -0,0 +1,81 @@
+use super::Polygon;
+use crate::cartesian::d2::point::point_f64::orient2d;
+use std::cmp::Ordering;
+
+fn direction(from: f64, to: f64) -> Ordering {
+    if to > from {
+        Ordering::Greater
+    } else if to < from {
+        Ordering::Less
+    } else {
+        Ordering::Equal
+    }
+}
+
+fn direction_changes(directions: impl Iterator<Item = Ordering>) -> usize {
+    let directions: Vec<Ordering> = directions.filter(|d| *d != Ordering::Equal).collect();
+    directions.iter().zip(directions.iter().cycle().skip(1)).filter(|(a, b)| a != b).count()
+}
+
+pub fn is_convex(p: &Polygon) -> bool {
+    let n = p.points.len();
+    let mut turn = Ordering::Equal;
+    for i in 0..n {
+        let o = orient2d(&p.points[i], &p.points[(i + 1) % n], &p.points[(i + 2) % n]);
+        if o == Ordering::Equal {
+            continue;
+        }
+        if turn == Ordering::Equal {
+            turn = o;
+        } else if turn != o {
+            return false;
+        }
+    }
+    let edges = || p.points.iter().zip(p.points.iter().cycle().skip(1));
+    turn != Ordering::Equal && direction_changes(edges().map(|(a, b)| direction(a.x, b.x))) <= 2 && direction_changes(edges().map(|(a, b)| direction(a.y, b.y))) <= 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_convex;
+    use crate::cartesian::{
+        d1::point::point_f64::{MAX, MIN},
+        d2::polygon::polygon_f64::Polygon,
+    };
+
+    #[test]
+    fn convex() {
+        assert!(is_convex(&Polygon::new(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)])));
+        assert!(is_convex(&Polygon::new(&[(0.0, 4.0), (4.0, 4.0), (4.0, 0.0), (0.0, 0.0)])));
+        assert!(is_convex(&Polygon::new(&[(0.0, 0.0), (6.0, 0.0), (0.0, 3.0)])));
+        assert!(is_convex(&Polygon::new(&[(2.0, 0.0), (4.0, 1.0), (4.0, 3.0), (2.0, 4.0), (0.0, 3.0), (0.0, 1.0)])));
+        assert!(is_convex(&Polygon::new(&[(0.0, 0.0), (2.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)])));
+    }
+
+    #[test]
+    fn concave() {
+        assert!(!is_convex(&Polygon::new(&[(0.0, 0.0), (4.0, 0.0), (4.0, 2.0), (2.0, 2.0), (2.0, 4.0), (0.0, 4.0)])));
+        assert!(!is_convex(&Polygon::new(&[(0.0, 0.0), (4.0, 0.0), (2.0, 1.0), (4.0, 4.0), (0.0, 4.0)])));
+    }
+
+    #[test]
+    fn self_intersecting() {
+        assert!(!is_convex(&Polygon::new(&[(2.0, 0.0), (5.0, 9.0), (8.0, 0.0), (0.0, 6.0), (10.0, 6.0)])));
+        assert!(!is_convex(&Polygon::new(&[(0.0, 0.0), (4.0, 4.0), (4.0, 0.0), (0.0, 4.0)])));
+    }
+
+    #[test]
+    fn degenerate() {
+        assert!(!is_convex(&Polygon::new(&[])));
+        assert!(!is_convex(&Polygon::new(&[(1.0, 1.0)])));
+        assert!(!is_convex(&Polygon::new(&[(1.0, 1.0), (3.0, 3.0)])));
+        assert!(!is_convex(&Polygon::new(&[(0.0, 0.0), (2.0, 0.0), (4.0, 0.0)])));
+    }
+
+    #[test]
+    fn bounds() {
+        assert!(is_convex(&Polygon::new(&[(MIN, MIN), (MAX, MIN), (MAX, MAX), (MIN, MAX)])));
+        assert!(is_convex(&Polygon::new(&[(MIN, MIN), (MAX, MIN), (MIN, MAX)])));
+        assert!(!is_convex(&Polygon::new(&[(MIN, MIN), (MAX, MAX), (MAX, MIN), (MIN, MAX)])));
+    }
+}
@@ -0,0 +1,52 @@
+use super::Polygon;
+
+pub fn signed_double_area(p: &Polygon) -> i128 {
+    p.points.iter().zip(p.points.iter().cycle().skip(1)).map(|(a, b)| i128::from(a.x) * i128::from(b.y) - i128::from(b.x) * i128::from(a.y)).sum()
+}
+
+pub fn area(p: &Polygon) -> f64 {
+    signed_double_area(p).unsigned_abs() as f64 / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{area, signed_double_area};
+    use crate::cartesian::{
+        d1::point::point_i32::{MAX, MIN},
+        d2::polygon::polygon_i32::Polygon,
+    };
+
+    #[test]
+    fn test_signed_double_area() {
+        assert_eq!(signed_double_area(&Polygon::new(&[(0, 0), (4, 0), (4, 4), (0, 4)])), 32);
+        assert_eq!(signed_double_area(&Polygon::new(&[(0, 4), (4, 4), (4, 0), (0, 0)])), -32);
+        assert_eq!(signed_double_area(&Polygon::new(&[(0, 0), (6, 0), (0, 3)])), 18);
+        assert_eq!(signed_double_area(&Polygon::new(&[(0, 0), (4, 0), (4, 2), (2, 2), (2, 4), (0, 4)])), 24);
+        assert_eq!(signed_double_area(&Polygon::new(&[(2, 0), (5, 9), (8, 0), (0, 6), (10, 6)])), -78);
+    }
+
+    #[test]
+    fn signed_double_area_degenerate() {
+        assert_eq!(signed_double_area(&Polygon::new(&[])), 0);
+        assert_eq!(signed_double_area(&Polygon::new(&[(1, 1)])), 0);
+        assert_eq!(signed_double_area(&Polygon::new(&[(1, 1), (3, 3)])), 0);
+        assert_eq!(signed_double_area(&Polygon::new(&[(0, 0), (2, 0), (4, 0)])), 0);
+    }
+
+    #[test]
+    fn signed_double_area_bounds() {
+        assert_eq!(signed_double_area(&Polygon::new(&[(MIN, MIN), (MAX, MIN), (MAX, MAX), (MIN, MAX)])), 36893488130239234050);
+        assert_eq!(signed_double_area(&Polygon::new(&[(MIN, MAX), (MAX, MAX), (MAX, MIN), (MIN, MIN)])), -36893488130239234050);
+        assert_eq!(area(&Polygon::new(&[(MIN, MIN), (MAX, MIN), (MAX, MAX), (MIN, MAX)])), 18446744065119617025.0);
+    }
+
+    #[test]
+    fn test_area() {
+        assert_eq!(area(&Polygon::new(&[(0, 0), (4, 0), (4, 4), (0, 4)])), 16.0);
+        assert_eq!(area(&Polygon::new(&[(0, 4), (4, 4), (4, 0), (0, 0)])), 16.0);
+        assert_eq!(area(&Polygon::new(&[(0, 0), (6, 0), (0, 3)])), 9.0);
+        assert_eq!(area(&Polygon::new(&[(0, 0), (4, 0), (4, 2), (2, 2), (2, 4), (0, 4)])), 12.0);
+        assert_eq!(area(&Polygon::new(&[(0, 0), (2, 0), (4, 0)])), 0.0);
+        assert_eq!(area(&Polygon::new(&[])), 0.0);
+    }
+}
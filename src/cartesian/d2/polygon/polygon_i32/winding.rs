@@ -0,0 +1,50 @@
+use super::{Polygon, signed_double_area};
+use crate::cartesian::d2::polygon::Winding;
+
+pub fn winding(p: &Polygon) -> Winding {
+    let double_area = signed_double_area(p);
+    if double_area > 0 {
+        Winding::CounterClockwise
+    } else if double_area < 0 {
+        Winding::Clockwise
+    } else {
+        Winding::Degenerate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::winding;
+    use crate::cartesian::{
+        d1::point::point_i32::{MAX, MIN},
+        d2::polygon::{Winding, polygon_i32::Polygon},
+    };
+
+    #[test]
+    fn counter_clockwise() {
+        assert_eq!(winding(&Polygon::new(&[(0, 0), (4, 0), (4, 4), (0, 4)])), Winding::CounterClockwise);
+        assert_eq!(winding(&Polygon::new(&[(0, 0), (6, 0), (0, 3)])), Winding::CounterClockwise);
+        assert_eq!(winding(&Polygon::new(&[(0, 0), (4, 0), (4, 2), (2, 2), (2, 4), (0, 4)])), Winding::CounterClockwise);
+    }
+
+    #[test]
+    fn clockwise() {
+        assert_eq!(winding(&Polygon::new(&[(0, 4), (4, 4), (4, 0), (0, 0)])), Winding::Clockwise);
+        assert_eq!(winding(&Polygon::new(&[(0, 0), (0, 3), (6, 0)])), Winding::Clockwise);
+        assert_eq!(winding(&Polygon::new(&[(2, 0), (5, 9), (8, 0), (0, 6), (10, 6)])), Winding::Clockwise);
+    }
+
+    #[test]
+    fn degenerate() {
+        assert_eq!(winding(&Polygon::new(&[])), Winding::Degenerate);
+        assert_eq!(winding(&Polygon::new(&[(1, 1), (3, 3)])), Winding::Degenerate);
+        assert_eq!(winding(&Polygon::new(&[(0, 0), (2, 0), (4, 0)])), Winding::Degenerate);
+    }
+
+    #[test]
+    fn bounds() {
+        assert_eq!(winding(&Polygon::new(&[(MIN, MIN), (MAX, MIN), (MIN, MAX)])), Winding::CounterClockwise);
+        assert_eq!(winding(&Polygon::new(&[(MIN, MIN), (MIN, MAX), (MAX, MIN)])), Winding::Clockwise);
+        assert_eq!(winding(&Polygon::new(&[(MIN, MIN), (MAX, MAX)])), Winding::Degenerate);
+    }
+}
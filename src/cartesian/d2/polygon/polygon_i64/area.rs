@@ -0,0 +1,81 @@
+use super::Polygon;
+
+fn add(high: &mut i128, low: &mut u128, value: i128) {
+    let (sum, carry) = low.overflowing_add(value as u128);
+    *low = sum;
+    *high += i128::from(carry) - i128::from(value < 0);
+}
+
+pub fn double_area(p: &Polygon) -> (i128, u128) {
+    let mut high = 0;
+    let mut low = 0;
+    for (a, b) in p.points.iter().zip(p.points.iter().cycle().skip(1)) {
+        add(&mut high, &mut low, i128::from(a.x) * i128::from(b.y));
+        add(&mut high, &mut low, -(i128::from(b.x) * i128::from(a.y)));
+    }
+    (high, low)
+}
+
+pub fn try_signed_double_area(p: &Polygon) -> Option<i128> {
+    match double_area(p) {
+        (0, low) if low <= i128::MAX as u128 => Some(low as i128),
+        (-1, low) if low > i128::MAX as u128 => Some(low as i128),
+        _ => None,
+    }
+}
+
+pub fn area(p: &Polygon) -> f64 {
+    let (mut high, mut low) = double_area(p);
+    if high < 0 {
+        low = (!low).wrapping_add(1);
+        high = !high + i128::from(low == 0);
+    }
+    (high as f64).mul_add(2.0_f64.powi(128), low as f64) / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{area, try_signed_double_area};
+    use crate::cartesian::{
+        d1::point::point_i64::{MAX, MIN},
+        d2::polygon::polygon_i64::Polygon,
+    };
+
+    #[test]
+    fn test_try_signed_double_area() {
+        assert_eq!(try_signed_double_area(&Polygon::new(&[(0, 0), (4, 0), (4, 4), (0, 4)])), Some(32));
+        assert_eq!(try_signed_double_area(&Polygon::new(&[(0, 4), (4, 4), (4, 0), (0, 0)])), Some(-32));
+        assert_eq!(try_signed_double_area(&Polygon::new(&[(0, 0), (6, 0), (0, 3)])), Some(18));
+        assert_eq!(try_signed_double_area(&Polygon::new(&[(0, 0), (4, 0), (4, 2), (2, 2), (2, 4), (0, 4)])), Some(24));
+        assert_eq!(try_signed_double_area(&Polygon::new(&[(2, 0), (5, 9), (8, 0), (0, 6), (10, 6)])), Some(-78));
+    }
+
+    #[test]
+    fn try_signed_double_area_degenerate() {
+        assert_eq!(try_signed_double_area(&Polygon::new(&[])), Some(0));
+        assert_eq!(try_signed_double_area(&Polygon::new(&[(1, 1)])), Some(0));
+        assert_eq!(try_signed_double_area(&Polygon::new(&[(1, 1), (3, 3)])), Some(0));
+        assert_eq!(try_signed_double_area(&Polygon::new(&[(0, 0), (2, 0), (4, 0)])), Some(0));
+    }
+
+    #[test]
+    fn try_signed_double_area_bounds() {
+        assert_eq!(try_signed_double_area(&Polygon::new(&[(MIN, MIN), (MAX, MIN), (MIN, -1)])), Some(170141183460469231704017187605319778305));
+        assert_eq!(try_signed_double_area(&Polygon::new(&[(MIN, MIN), (MIN, -1), (MAX, MIN)])), Some(-170141183460469231704017187605319778305));
+        assert_eq!(try_signed_double_area(&Polygon::new(&[(MIN, MIN), (MAX, MIN), (MAX, MAX), (MIN, MAX)])), None);
+        assert_eq!(try_signed_double_area(&Polygon::new(&[(MIN, MAX), (MAX, MAX), (MAX, MIN), (MIN, MIN)])), None);
+        assert_eq!(area(&Polygon::new(&[(MIN, MIN), (MAX, MIN), (MIN, -1)])), 170141183460469231704017187605319778305.0 / 2.0);
+        assert_eq!(area(&Polygon::new(&[(MIN, MIN), (MAX, MIN), (MAX, MAX), (MIN, MAX)])), u64::MAX as f64 * u64::MAX as f64);
+        assert_eq!(area(&Polygon::new(&[(MIN, MAX), (MAX, MAX), (MAX, MIN), (MIN, MIN)])), u64::MAX as f64 * u64::MAX as f64);
+    }
+
+    #[test]
+    fn test_area() {
+        assert_eq!(area(&Polygon::new(&[(0, 0), (4, 0), (4, 4), (0, 4)])), 16.0);
+        assert_eq!(area(&Polygon::new(&[(0, 4), (4, 4), (4, 0), (0, 0)])), 16.0);
+        assert_eq!(area(&Polygon::new(&[(0, 0), (6, 0), (0, 3)])), 9.0);
+        assert_eq!(area(&Polygon::new(&[(0, 0), (4, 0), (4, 2), (2, 2), (2, 4), (0, 4)])), 12.0);
+        assert_eq!(area(&Polygon::new(&[(0, 0), (2, 0), (4, 0)])), 0.0);
+        assert_eq!(area(&Polygon::new(&[])), 0.0);
+    }
+}
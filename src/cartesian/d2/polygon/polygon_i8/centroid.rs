@@ -0,0 +1,52 @@
+use super::Polygon;
+use crate::cartesian::d2::point::point_f64;
+
+pub fn centroid(p: &Polygon) -> Option<point_f64::Point> {
+    let origin = p.points.first()?;
+    let (origin_x, origin_y) = (f64::from(origin.x), f64::from(origin.y));
+    let mut double_area = 0.0;
+    let mut x = 0.0;
+    let mut y = 0.0;
+    for (a, b) in p.points.iter().zip(p.points.iter().cycle().skip(1)) {
+        let (a_x, a_y) = (f64::from(a.x) - origin_x, f64::from(a.y) - origin_y);
+        let (b_x, b_y) = (f64::from(b.x) - origin_x, f64::from(b.y) - origin_y);
+        let cross = a_x * b_y - b_x * a_y;
+        double_area += cross;
+        x += (a_x + b_x) * cross;
+        y += (a_y + b_y) * cross;
+    }
+    if double_area == 0.0 {
+        return None;
+    }
+    Some(point_f64::Point::new(x / (3.0 * double_area) + origin_x, y / (3.0 * double_area) + origin_y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::centroid;
+    use crate::cartesian::{
+        d1::point::point_i8::{MAX, MIN},
+        d2::{point::point_f64, polygon::polygon_i8::Polygon},
+    };
+
+    #[test]
+    fn test_centroid() {
+        assert_eq!(centroid(&Polygon::new(&[(0, 0), (4, 0), (4, 4), (0, 4)])), Some(point_f64::Point::new(2.0, 2.0)));
+        assert_eq!(centroid(&Polygon::new(&[(0, 4), (4, 4), (4, 0), (0, 0)])), Some(point_f64::Point::new(2.0, 2.0)));
+        assert_eq!(centroid(&Polygon::new(&[(0, 0), (6, 0), (0, 3)])), Some(point_f64::Point::new(2.0, 1.0)));
+        assert_eq!(centroid(&Polygon::new(&[(10, 10), (14, 10), (14, 14), (10, 14)])), Some(point_f64::Point::new(12.0, 12.0)));
+    }
+
+    #[test]
+    fn degenerate() {
+        assert_eq!(centroid(&Polygon::new(&[])), None);
+        assert_eq!(centroid(&Polygon::new(&[(1, 1)])), None);
+        assert_eq!(centroid(&Polygon::new(&[(1, 1), (3, 3)])), None);
+        assert_eq!(centroid(&Polygon::new(&[(0, 0), (2, 0), (4, 0)])), None);
+    }
+
+    #[test]
+    fn bounds() {
+        assert_eq!(centroid(&Polygon::new(&[(MIN, MIN), (MAX, MIN), (MAX, MAX), (MIN, MAX)])), Some(point_f64::Point::new(-0.5, -0.5)));
+    }
+}
@@ -0,0 +1,68 @@
+use super::Polygon;
+use crate::cartesian::d2::point::point_u16::orient2d;
+use std::cmp::Ordering;
+
+fn direction_changes(directions: impl Iterator<Item = Ordering>) -> usize {
+    let directions: Vec<Ordering> = directions.filter(|d| *d != Ordering::Equal).collect();
+    directions.iter().zip(directions.iter().cycle().skip(1)).filter(|(a, b)| a != b).count()
+}
+
+pub fn is_convex(p: &Polygon) -> bool {
+    let n = p.points.len();
+    let mut turn = Ordering::Equal;
+    for i in 0..n {
+        let o = orient2d(&p.points[i], &p.points[(i + 1) % n], &p.points[(i + 2) % n]);
+        if o == Ordering::Equal {
+            continue;
+        }
+        if turn == Ordering::Equal {
+            turn = o;
+        } else if turn != o {
+            return false;
+        }
+    }
+    let edges = || p.points.iter().zip(p.points.iter().cycle().skip(1));
+    turn != Ordering::Equal && direction_changes(edges().map(|(a, b)| b.x.cmp(&a.x))) <= 2 && direction_changes(edges().map(|(a, b)| b.y.cmp(&a.y))) <= 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_convex;
+    use crate::cartesian::{d1::point::point_u16::MAX, d2::polygon::polygon_u16::Polygon};
+
+    #[test]
+    fn convex() {
+        assert!(is_convex(&Polygon::new(&[(0, 0), (4, 0), (4, 4), (0, 4)])));
+        assert!(is_convex(&Polygon::new(&[(0, 4), (4, 4), (4, 0), (0, 0)])));
+        assert!(is_convex(&Polygon::new(&[(0, 0), (6, 0), (0, 3)])));
+        assert!(is_convex(&Polygon::new(&[(2, 0), (4, 1), (4, 3), (2, 4), (0, 3), (0, 1)])));
+        assert!(is_convex(&Polygon::new(&[(0, 0), (2, 0), (4, 0), (4, 4), (0, 4)])));
+    }
+
+    #[test]
+    fn concave() {
+        assert!(!is_convex(&Polygon::new(&[(0, 0), (4, 0), (4, 2), (2, 2), (2, 4), (0, 4)])));
+        assert!(!is_convex(&Polygon::new(&[(0, 0), (4, 0), (2, 1), (4, 4), (0, 4)])));
+    }
+
+    #[test]
+    fn self_intersecting() {
+        assert!(!is_convex(&Polygon::new(&[(2, 0), (5, 9), (8, 0), (0, 6), (10, 6)])));
+        assert!(!is_convex(&Polygon::new(&[(0, 0), (4, 4), (4, 0), (0, 4)])));
+    }
+
+    #[test]
+    fn degenerate() {
+        assert!(!is_convex(&Polygon::new(&[])));
+        assert!(!is_convex(&Polygon::new(&[(1, 1)])));
+        assert!(!is_convex(&Polygon::new(&[(1, 1), (3, 3)])));
+        assert!(!is_convex(&Polygon::new(&[(0, 0), (2, 0), (4, 0)])));
+    }
+
+    #[test]
+    fn bounds() {
+        assert!(is_convex(&Polygon::new(&[(0, 0), (MAX, 0), (MAX, MAX), (0, MAX)])));
+        assert!(is_convex(&Polygon::new(&[(0, 0), (MAX, 0), (0, MAX)])));
+        assert!(!is_convex(&Polygon::new(&[(0, 0), (MAX, MAX), (MAX, 0), (0, MAX)])));
+    }
+}
@@ -0,0 +1,83 @@
+use super::Polygon;
+use crate::cartesian::d2::point::point_u16::{Point, orient2d};
+use std::cmp::Ordering;
+
+pub fn convex_hull(points: &[Point]) -> Polygon {
+    let mut sorted = points.to_vec();
+    sorted.sort_by_key(|p| (p.x, p.y));
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return Polygon { points: sorted };
+    }
+    let mut hull: Vec<Point> = Vec::with_capacity(sorted.len() + 1);
+    for p in sorted.iter() {
+        while hull.len() >= 2 && orient2d(&hull[hull.len() - 2], &hull[hull.len() - 1], p) != Ordering::Greater {
+            hull.pop();
+        }
+        hull.push(p.clone());
+    }
+    let lower_len = hull.len() + 1;
+    for p in sorted.iter().rev().skip(1) {
+        while hull.len() >= lower_len && orient2d(&hull[hull.len() - 2], &hull[hull.len() - 1], p) != Ordering::Greater {
+            hull.pop();
+        }
+        hull.push(p.clone());
+    }
+    hull.pop();
+    Polygon { points: hull }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::convex_hull;
+    use crate::cartesian::{
+        d1::point::point_u16::MAX,
+        d2::{point::point_u16::Point, polygon::polygon_u16::Polygon},
+    };
+
+    #[test]
+    fn test_convex_hull() {
+        assert_eq!(convex_hull(&[Point::new(0, 0), Point::new(4, 0), Point::new(4, 4), Point::new(0, 4)]), Polygon::new(&[(0, 0), (4, 0), (4, 4), (0, 4)]));
+        assert_eq!(convex_hull(&[Point::new(4, 4), Point::new(0, 0), Point::new(0, 4), Point::new(4, 0)]), Polygon::new(&[(0, 0), (4, 0), (4, 4), (0, 4)]));
+        assert_eq!(
+            convex_hull(&[Point::new(0, 0), Point::new(2, 1), Point::new(1, 3), Point::new(4, 0), Point::new(3, 2), Point::new(4, 4), Point::new(2, 2), Point::new(0, 4)]),
+            Polygon::new(&[(0, 0), (4, 0), (4, 4), (0, 4)])
+        );
+        assert_eq!(convex_hull(&[Point::new(3, 1), Point::new(0, 0), Point::new(6, 0), Point::new(3, 5), Point::new(2, 1), Point::new(4, 2)]), Polygon::new(&[(0, 0), (6, 0), (3, 5)]));
+        assert_eq!(convex_hull(&[Point::new(2, 0), Point::new(5, 9), Point::new(8, 0), Point::new(0, 6), Point::new(10, 6)]), Polygon::new(&[(0, 6), (2, 0), (8, 0), (10, 6), (5, 9)]));
+    }
+
+    #[test]
+    fn collinear_points() {
+        assert_eq!(
+            convex_hull(&[Point::new(0, 0), Point::new(2, 0), Point::new(4, 0), Point::new(4, 2), Point::new(4, 4), Point::new(2, 4), Point::new(0, 4), Point::new(0, 2)]),
+            Polygon::new(&[(0, 0), (4, 0), (4, 4), (0, 4)])
+        );
+        assert_eq!(convex_hull(&[Point::new(0, 0), Point::new(1, 1), Point::new(2, 2), Point::new(3, 3)]), Polygon::new(&[(0, 0), (3, 3)]));
+        assert_eq!(convex_hull(&[Point::new(3, 0), Point::new(1, 0), Point::new(2, 0), Point::new(0, 0)]), Polygon::new(&[(0, 0), (3, 0)]));
+    }
+
+    #[test]
+    fn duplicates() {
+        assert_eq!(
+            convex_hull(&[Point::new(0, 0), Point::new(4, 0), Point::new(0, 0), Point::new(4, 4), Point::new(4, 0), Point::new(0, 4), Point::new(4, 4)]),
+            Polygon::new(&[(0, 0), (4, 0), (4, 4), (0, 4)])
+        );
+        assert_eq!(convex_hull(&[Point::new(1, 1), Point::new(1, 1), Point::new(1, 1)]), Polygon::new(&[(1, 1)]));
+    }
+
+    #[test]
+    fn degenerate() {
+        assert_eq!(convex_hull(&[]), Polygon::new(&[]));
+        assert_eq!(convex_hull(&[Point::new(1, 1)]), Polygon::new(&[(1, 1)]));
+        assert_eq!(convex_hull(&[Point::new(3, 3), Point::new(1, 1)]), Polygon::new(&[(1, 1), (3, 3)]));
+    }
+
+    #[test]
+    fn bounds() {
+        assert_eq!(convex_hull(&[Point::max(), Point::new(MAX / 2, MAX / 2), Point::min(), Point::new(0, MAX), Point::new(MAX, 0)]), Polygon::new(&[(0, 0), (MAX, 0), (MAX, MAX), (0, MAX)]));
+        assert_eq!(convex_hull(&[Point::min(), Point::new(MAX, 0), Point::new(MAX - 1, 1), Point::new(0, MAX)]), Polygon::new(&[(0, 0), (MAX, 0), (0, MAX)]));
+        assert_eq!(convex_hull(&[Point::min(), Point::new(MAX, 0), Point::new(MAX - 1, 2), Point::new(0, MAX)]), Polygon::new(&[(0, 0), (MAX, 0), (MAX - 1, 2), (0, MAX)]));
+        assert_eq!(convex_hull(&[Point::min(), Point::new(MAX / 2, MAX / 2), Point::max()]), Polygon::new(&[(0, 0), (MAX, MAX)]));
+    }
+}
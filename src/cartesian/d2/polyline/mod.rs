@@ -0,0 +1,2 @@
+pub mod polyline_f32;
+pub mod polyline_f64;
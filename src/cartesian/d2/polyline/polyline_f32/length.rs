@@ -0,0 +1,22 @@
+use super::Polyline;
+use crate::cartesian::d2::point::point_f32::distance;
+
+pub fn length(p: &Polyline) -> f32 {
+    p.points.windows(2).map(|w| distance(&w[0], &w[1])).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::length;
+    use crate::cartesian::d2::polyline::polyline_f32::Polyline;
+
+    #[test]
+    fn test_length() {
+        assert_eq!(length(&Polyline::new(&[])), 0.0);
+        assert_eq!(length(&Polyline::new(&[(3.0, 4.0)])), 0.0);
+        assert_eq!(length(&Polyline::new(&[(0.0, 0.0), (3.0, 4.0)])), 5.0);
+        assert_eq!(length(&Polyline::new(&[(0.0, 0.0), (3.0, 4.0), (3.0, -1.0), (-3.0, -9.0)])), 20.0);
+        assert_eq!(length(&Polyline::new(&[(0.0, 0.0), (3.0, 4.0), (0.0, 0.0)])), 10.0);
+        assert_eq!(length(&Polyline::new(&[(1.0, 1.0), (1.0, 1.0), (1.0, 1.0)])), 0.0);
+    }
+}
@@ -0,0 +1,198 @@
+use super::Polyline;
+use crate::cartesian::d2::point::point_f32::{Point, distance};
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+fn segment_distance(p: &Point, a: &Point, b: &Point) -> f32 {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let len_squared = dx.mul_add(dx, dy * dy);
+    if len_squared == 0.0 {
+        return distance(p, a);
+    }
+    let t = ((p.x - a.x).mul_add(dx, (p.y - a.y) * dy) / len_squared).clamp(0.0, 1.0);
+    distance(p, &Point::new(t.mul_add(dx, a.x), t.mul_add(dy, a.y)))
+}
+
+fn triangle_area(a: &Point, b: &Point, c: &Point) -> f32 {
+    ((b.x - a.x).mul_add(c.y - a.y, -(b.y - a.y) * (c.x - a.x)) / 2.0).abs()
+}
+
+pub fn simplify_douglas_peucker_assign(p: &mut Polyline, epsilon: f32) {
+    *p = simplify_douglas_peucker(p, epsilon);
+}
+
+pub fn simplify_douglas_peucker(p: &Polyline, epsilon: f32) -> Polyline {
+    let n = p.points.len();
+    if n < 3 {
+        return p.clone();
+    }
+    let mut keep = vec![false; n];
+    keep[0] = true;
+    keep[n - 1] = true;
+    let mut stack = vec![(0, n - 1)];
+    while let Some((start, end)) = stack.pop() {
+        let mut farthest = start;
+        let mut farthest_distance = f32::NEG_INFINITY;
+        for i in start + 1..end {
+            let d = segment_distance(&p.points[i], &p.points[start], &p.points[end]);
+            if d > farthest_distance {
+                farthest = i;
+                farthest_distance = d;
+            }
+        }
+        if farthest > start && farthest_distance > epsilon {
+            keep[farthest] = true;
+            stack.push((start, farthest));
+            stack.push((farthest, end));
+        }
+    }
+    Polyline { points: p.points.iter().zip(keep).filter(|(_, k)| *k).map(|(point, _)| point.clone()).collect() }
+}
+
+struct Candidate {
+    area: f32,
+    index: usize,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.area.total_cmp(&self.area).then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+pub fn simplify_visvalingam_whyatt_assign(p: &mut Polyline, min_area: f32) {
+    *p = simplify_visvalingam_whyatt(p, min_area);
+}
+
+pub fn simplify_visvalingam_whyatt(p: &Polyline, min_area: f32) -> Polyline {
+    let n = p.points.len();
+    if n < 3 {
+        return p.clone();
+    }
+    let mut prev: Vec<usize> = (0..n).map(|i| i.saturating_sub(1)).collect();
+    let mut next: Vec<usize> = (0..n).map(|i| i + 1).collect();
+    let mut areas: Vec<f32> = (0..n).map(|i| if i == 0 || i == n - 1 { f32::INFINITY } else { triangle_area(&p.points[i - 1], &p.points[i], &p.points[i + 1]) }).collect();
+    let mut heap: BinaryHeap<Candidate> = (1..n - 1).map(|index| Candidate { area: areas[index], index }).collect();
+    let mut removed = vec![false; n];
+    let mut last_area = f32::NEG_INFINITY;
+    while let Some(Candidate { area, index }) = heap.pop() {
+        if removed[index] || area != areas[index] {
+            continue;
+        }
+        if area >= min_area {
+            break;
+        }
+        removed[index] = true;
+        last_area = last_area.max(area);
+        let (before, after) = (prev[index], next[index]);
+        next[before] = after;
+        prev[after] = before;
+        for neighbor in [before, after] {
+            if neighbor == 0 || neighbor == n - 1 {
+                continue;
+            }
+            let neighbor_area = triangle_area(&p.points[prev[neighbor]], &p.points[neighbor], &p.points[next[neighbor]]).max(last_area);
+            areas[neighbor] = neighbor_area;
+            heap.push(Candidate { area: neighbor_area, index: neighbor });
+        }
+    }
+    Polyline { points: p.points.iter().zip(removed).filter(|(_, r)| !r).map(|(point, _)| point.clone()).collect() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{simplify_douglas_peucker, simplify_douglas_peucker_assign, simplify_visvalingam_whyatt, simplify_visvalingam_whyatt_assign};
+    use crate::cartesian::d2::polyline::polyline_f32::Polyline;
+
+    #[test]
+    fn test_simplify_douglas_peucker_assign() {
+        let mut p = Polyline::new(&[(0.0, 0.0), (1.0, 0.25), (2.0, 0.0), (3.0, 2.0), (4.0, 0.0)]);
+        simplify_douglas_peucker_assign(&mut p, 0.5);
+        assert_eq!(p, Polyline::new(&[(0.0, 0.0), (2.0, 0.0), (3.0, 2.0), (4.0, 0.0)]));
+        simplify_douglas_peucker_assign(&mut p, 2.0);
+        assert_eq!(p, Polyline::new(&[(0.0, 0.0), (4.0, 0.0)]));
+    }
+
+    #[test]
+    fn test_simplify_douglas_peucker() {
+        let p = Polyline::new(&[(0.0, 0.0), (1.0, 0.25), (2.0, 0.0), (3.0, 2.0), (4.0, 0.0)]);
+        assert_eq!(simplify_douglas_peucker(&p, 0.0), p);
+        assert_eq!(simplify_douglas_peucker(&p, 0.2), p);
+        assert_eq!(simplify_douglas_peucker(&p, 0.5), Polyline::new(&[(0.0, 0.0), (2.0, 0.0), (3.0, 2.0), (4.0, 0.0)]));
+        assert_eq!(simplify_douglas_peucker(&p, 1.5), Polyline::new(&[(0.0, 0.0), (3.0, 2.0), (4.0, 0.0)]));
+        assert_eq!(simplify_douglas_peucker(&p, 2.0), Polyline::new(&[(0.0, 0.0), (4.0, 0.0)]));
+    }
+
+    #[test]
+    fn simplify_douglas_peucker_collinear() {
+        assert_eq!(simplify_douglas_peucker(&Polyline::new(&[(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (3.0, 3.0)]), 0.0), Polyline::new(&[(0.0, 0.0), (3.0, 3.0)]));
+        assert_eq!(simplify_douglas_peucker(&Polyline::new(&[(0.0, 0.0), (3.0, 0.0), (1.0, 0.0)]), 0.0), Polyline::new(&[(0.0, 0.0), (3.0, 0.0), (1.0, 0.0)]));
+    }
+
+    #[test]
+    fn simplify_douglas_peucker_negative_epsilon() {
+        let p = Polyline::new(&[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (3.0, 0.0)]);
+        assert_eq!(simplify_douglas_peucker(&p, -1.0), p);
+        assert_eq!(simplify_douglas_peucker(&p, f32::NEG_INFINITY), p);
+        assert_eq!(simplify_douglas_peucker(&p, f32::NAN), Polyline::new(&[(0.0, 0.0), (3.0, 0.0)]));
+    }
+
+    #[test]
+    fn simplify_douglas_peucker_closed() {
+        let p = Polyline::new(&[(0.0, 0.0), (4.0, 0.0), (4.0, 3.0), (0.0, 0.0)]);
+        assert_eq!(simplify_douglas_peucker(&p, 1.0), p);
+        assert_eq!(simplify_douglas_peucker(&p, 5.0), Polyline::new(&[(0.0, 0.0), (0.0, 0.0)]));
+    }
+
+    #[test]
+    fn simplify_douglas_peucker_small() {
+        assert_eq!(simplify_douglas_peucker(&Polyline::new(&[]), 1.0), Polyline::new(&[]));
+        assert_eq!(simplify_douglas_peucker(&Polyline::new(&[(1.0, 1.0)]), 1.0), Polyline::new(&[(1.0, 1.0)]));
+        assert_eq!(simplify_douglas_peucker(&Polyline::new(&[(1.0, 1.0), (5.0, 5.0)]), 10.0), Polyline::new(&[(1.0, 1.0), (5.0, 5.0)]));
+    }
+
+    #[test]
+    fn test_simplify_visvalingam_whyatt_assign() {
+        let mut p = Polyline::new(&[(0.0, 0.0), (1.0, 0.25), (2.0, 0.0), (3.0, 2.0), (4.0, 0.0)]);
+        simplify_visvalingam_whyatt_assign(&mut p, 1.0);
+        assert_eq!(p, Polyline::new(&[(0.0, 0.0), (2.0, 0.0), (3.0, 2.0), (4.0, 0.0)]));
+        simplify_visvalingam_whyatt_assign(&mut p, 5.0);
+        assert_eq!(p, Polyline::new(&[(0.0, 0.0), (4.0, 0.0)]));
+    }
+
+    #[test]
+    fn test_simplify_visvalingam_whyatt() {
+        let p = Polyline::new(&[(0.0, 0.0), (1.0, 0.25), (2.0, 0.0), (3.0, 2.0), (4.0, 0.0)]);
+        assert_eq!(simplify_visvalingam_whyatt(&p, 0.0), p);
+        assert_eq!(simplify_visvalingam_whyatt(&p, 0.25), p);
+        assert_eq!(simplify_visvalingam_whyatt(&p, 1.0), Polyline::new(&[(0.0, 0.0), (2.0, 0.0), (3.0, 2.0), (4.0, 0.0)]));
+        assert_eq!(simplify_visvalingam_whyatt(&p, 3.0), Polyline::new(&[(0.0, 0.0), (3.0, 2.0), (4.0, 0.0)]));
+        assert_eq!(simplify_visvalingam_whyatt(&p, 5.0), Polyline::new(&[(0.0, 0.0), (4.0, 0.0)]));
+    }
+
+    #[test]
+    fn simplify_visvalingam_whyatt_collinear() {
+        assert_eq!(simplify_visvalingam_whyatt(&Polyline::new(&[(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (3.0, 3.0)]), 0.5), Polyline::new(&[(0.0, 0.0), (3.0, 3.0)]));
+        assert_eq!(simplify_visvalingam_whyatt(&Polyline::new(&[(0.0, 0.0), (3.0, 0.0), (1.0, 0.0)]), 0.5), Polyline::new(&[(0.0, 0.0), (1.0, 0.0)]));
+    }
+
+    #[test]
+    fn simplify_visvalingam_whyatt_small() {
+        assert_eq!(simplify_visvalingam_whyatt(&Polyline::new(&[]), 1.0), Polyline::new(&[]));
+        assert_eq!(simplify_visvalingam_whyatt(&Polyline::new(&[(1.0, 1.0)]), 1.0), Polyline::new(&[(1.0, 1.0)]));
+        assert_eq!(simplify_visvalingam_whyatt(&Polyline::new(&[(1.0, 1.0), (5.0, 5.0)]), 10.0), Polyline::new(&[(1.0, 1.0), (5.0, 5.0)]));
+    }
+}
@@ -0,0 +1,50 @@
+use crate::cartesian::d2::point::point_f64::Point;
+
+mod length;
+mod resample;
+mod simplify;
+
+pub use self::length::length;
+pub use self::resample::{resample, resample_assign, try_resample, try_resample_assign};
+pub use self::simplify::{simplify_douglas_peucker, simplify_douglas_peucker_assign, simplify_visvalingam_whyatt, simplify_visvalingam_whyatt_assign};
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Polyline {
+    pub points: Vec<Point>,
+}
+
+impl Polyline {
+    pub fn new(points: &[(f64, f64)]) -> Self {
+        Polyline { points: points.iter().map(|p| Point::new(p.0, p.1)).collect() }
+    }
+}
+
+impl std::fmt::Display for Polyline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let points: Vec<String> = self.points.iter().map(|p| p.to_string()).collect();
+        write!(f, "({})", points.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Polyline;
+    use crate::cartesian::{
+        d1::point::point_f64::{MAX, MIN},
+        d2::point::point_f64::Point,
+    };
+
+    #[test]
+    fn polyline() {
+        assert_eq!(Polyline::new(&[]), Polyline { points: vec![] });
+        assert_eq!(Polyline::new(&[(0.0, 0.0), (4.0, 0.0), (0.0, 3.0)]), Polyline { points: vec![Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(0.0, 3.0)] });
+        assert_eq!(Polyline::new(&[(MIN, MIN), (MAX, MAX)]), Polyline { points: vec![Point::min(), Point::max()] });
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(Polyline::new(&[]).to_string(), "()");
+        assert_eq!(Polyline::new(&[(0.0, 0.0), (4.0, 0.0), (0.0, 3.0)]).to_string(), "((0, 0), (4, 0), (0, 3))");
+        assert_eq!(Polyline::new(&[(MIN, MIN), (MAX, MAX)]).to_string(), format!("({}, {})", Point::min(), Point::max()));
+    }
+}
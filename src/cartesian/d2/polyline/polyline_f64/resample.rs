@@ -0,0 +1,107 @@
+use super::{Polyline, length};
+use crate::cartesian::d2::point::point_f64::{Point, distance};
+
+pub fn try_resample_assign(p: &mut Polyline, spacing: f64) -> Option<()> {
+    *p = try_resample(p, spacing)?;
+    Some(())
+}
+
+pub fn try_resample(p: &Polyline, spacing: f64) -> Option<Polyline> {
+    if spacing <= 0.0 || !spacing.is_finite() {
+        return None;
+    }
+    let Some(last) = p.points.last() else {
+        return Some(Polyline { points: Vec::new() });
+    };
+    let total = length(p);
+    let mut points = Vec::new();
+    let mut segment = 0;
+    let mut segment_start = 0.0;
+    let mut sample = 0;
+    loop {
+        let target = spacing * sample as f64;
+        if target > total {
+            break;
+        }
+        while segment + 2 < p.points.len() && segment_start + distance(&p.points[segment], &p.points[segment + 1]) < target {
+            segment_start += distance(&p.points[segment], &p.points[segment + 1]);
+            segment += 1;
+        }
+        if segment + 1 == p.points.len() {
+            points.push(last.clone());
+            break;
+        }
+        let (a, b) = (&p.points[segment], &p.points[segment + 1]);
+        let segment_len = distance(a, b);
+        let t = if segment_len == 0.0 { 0.0 } else { ((target - segment_start) / segment_len).min(1.0) };
+        points.push(Point::new(t.mul_add(b.x - a.x, a.x), t.mul_add(b.y - a.y, a.y)));
+        sample += 1;
+    }
+    if points.last() != Some(last) {
+        points.push(last.clone());
+    }
+    Some(Polyline { points })
+}
+
+pub fn resample_assign(p: &mut Polyline, spacing: f64) {
+    try_resample_assign(p, spacing).unwrap()
+}
+
+pub fn resample(p: &Polyline, spacing: f64) -> Polyline {
+    try_resample(p, spacing).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{resample, resample_assign, try_resample, try_resample_assign};
+    use crate::cartesian::d2::polyline::polyline_f64::Polyline;
+
+    #[test]
+    fn test_try_resample_assign() {
+        let mut p = Polyline::new(&[(0.0, 0.0), (4.0, 0.0)]);
+        assert_eq!(try_resample_assign(&mut p, 1.0), Some(()));
+        assert_eq!(p, Polyline::new(&[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (3.0, 0.0), (4.0, 0.0)]));
+        assert_eq!(try_resample_assign(&mut p, 0.0), None);
+        assert_eq!(p, Polyline::new(&[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (3.0, 0.0), (4.0, 0.0)]));
+    }
+
+    #[test]
+    fn test_try_resample() {
+        assert_eq!(try_resample(&Polyline::new(&[(0.0, 0.0), (4.0, 0.0)]), 2.0), Some(Polyline::new(&[(0.0, 0.0), (2.0, 0.0), (4.0, 0.0)])));
+        assert_eq!(try_resample(&Polyline::new(&[(0.0, 0.0), (4.0, 0.0)]), 3.0), Some(Polyline::new(&[(0.0, 0.0), (3.0, 0.0), (4.0, 0.0)])));
+        assert_eq!(try_resample(&Polyline::new(&[(0.0, 0.0), (4.0, 0.0)]), 5.0), Some(Polyline::new(&[(0.0, 0.0), (4.0, 0.0)])));
+        assert_eq!(
+            try_resample(&Polyline::new(&[(0.0, 0.0), (2.0, 0.0), (2.0, 3.0), (0.0, 3.0)]), 1.5),
+            Some(Polyline::new(&[(0.0, 0.0), (1.5, 0.0), (2.0, 1.0), (2.0, 2.5), (1.0, 3.0), (0.0, 3.0)]))
+        );
+    }
+
+    #[test]
+    fn try_resample_degenerate() {
+        assert_eq!(try_resample(&Polyline::new(&[]), 1.0), Some(Polyline::new(&[])));
+        assert_eq!(try_resample(&Polyline::new(&[(1.0, 2.0)]), 1.0), Some(Polyline::new(&[(1.0, 2.0)])));
+        assert_eq!(try_resample(&Polyline::new(&[(1.0, 2.0), (1.0, 2.0)]), 1.0), Some(Polyline::new(&[(1.0, 2.0)])));
+        assert_eq!(try_resample(&Polyline::new(&[(0.0, 0.0), (0.0, 0.0), (2.0, 0.0)]), 1.0), Some(Polyline::new(&[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0)])));
+    }
+
+    #[test]
+    fn try_resample_invalid_spacing() {
+        let p = Polyline::new(&[(0.0, 0.0), (4.0, 0.0)]);
+        assert_eq!(try_resample(&p, 0.0), None);
+        assert_eq!(try_resample(&p, -1.0), None);
+        assert_eq!(try_resample(&p, f64::NAN), None);
+        assert_eq!(try_resample(&p, f64::INFINITY), None);
+    }
+
+    #[test]
+    fn test_resample_assign() {
+        let mut p = Polyline::new(&[(0.0, 0.0), (0.0, 4.0)]);
+        resample_assign(&mut p, 2.0);
+        assert_eq!(p, Polyline::new(&[(0.0, 0.0), (0.0, 2.0), (0.0, 4.0)]));
+    }
+
+    #[test]
+    fn test_resample() {
+        assert_eq!(resample(&Polyline::new(&[(0.0, 0.0), (0.0, 4.0)]), 2.0), Polyline::new(&[(0.0, 0.0), (0.0, 2.0), (0.0, 4.0)]));
+    }
+}
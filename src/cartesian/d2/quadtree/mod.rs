@@ -0,0 +1,12 @@
+pub mod quadtree_u16;
+pub mod quadtree_u32;
+pub mod quadtree_u64;
+pub mod quadtree_u8;
+
+pub mod quadtree_i16;
+pub mod quadtree_i32;
+pub mod quadtree_i64;
+pub mod quadtree_i8;
+
+pub mod quadtree_f32;
+pub mod quadtree_f64;
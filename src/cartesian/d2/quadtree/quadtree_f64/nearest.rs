@@ -0,0 +1,83 @@
+use super::{Node, Quadtree};
+use crate::cartesian::d2::{point::point_f64::Point, rect::rect_f64::Rect};
+
+fn gap(v: f64, min: f64, max: f64) -> f64 {
+    if v < min {
+        min - v
+    } else if v > max {
+        v - max
+    } else {
+        0.0
+    }
+}
+
+fn distance_squared(p: &Point, r: &Rect) -> f64 {
+    let dx = gap(p.x, r.min.x, r.max.x);
+    let dy = gap(p.y, r.min.y, r.max.y);
+    dx.mul_add(dx, dy * dy)
+}
+
+fn visit<'a, P>(node: &'a Node<P>, p: &Point, best: &mut Option<(f64, &'a Rect, &'a P)>) {
+    for (r, payload) in &node.entries {
+        let distance = distance_squared(p, r);
+        if best.as_ref().is_none_or(|(best_distance, _, _)| distance < *best_distance) {
+            *best = Some((distance, r, payload));
+        }
+    }
+    if let Some(children) = &node.children {
+        let mut candidates: Vec<(f64, &Node<P>)> = children.iter().map(|child| (distance_squared(p, &child.bounds), child)).collect();
+        candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+        for (distance, child) in candidates {
+            if best.as_ref().is_some_and(|(best_distance, _, _)| distance >= *best_distance) {
+                break;
+            }
+            visit(child, p, best);
+        }
+    }
+}
+
+pub fn nearest<'a, P>(q: &'a Quadtree<P>, p: &Point) -> Option<(&'a Rect, &'a P)> {
+    let mut best = None;
+    visit(&q.root, p, &mut best);
+    best.map(|(_, r, payload)| (r, payload))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::nearest;
+    use crate::cartesian::d2::{
+        point::point_f64::Point,
+        quadtree::quadtree_f64::{Quadtree, insert},
+        rect::rect_f64::Rect,
+    };
+
+    #[test]
+    fn test_nearest() {
+        let mut q = Quadtree::new(Rect::new((0.0, 0.0), (100.0, 100.0)));
+        assert_eq!(nearest(&q, &Point::new(50.0, 50.0)), None);
+        for i in 0..9u8 {
+            let offset = 10.0 * i as f64;
+            insert(&mut q, Rect::new((offset, offset), (offset + 5.0, offset + 5.0)), i);
+        }
+        insert(&mut q, Rect::new((90.0, 0.0), (100.0, 10.0)), 9);
+        assert!(q.root.children.is_some());
+        assert_eq!(nearest(&q, &Point::new(0.0, 0.0)), Some((&Rect::new((0.0, 0.0), (5.0, 5.0)), &0)));
+        assert_eq!(nearest(&q, &Point::new(3.0, 4.0)), Some((&Rect::new((0.0, 0.0), (5.0, 5.0)), &0)));
+        assert_eq!(nearest(&q, &Point::new(43.0, 41.0)), Some((&Rect::new((40.0, 40.0), (45.0, 45.0)), &4)));
+        assert_eq!(nearest(&q, &Point::new(98.0, 20.0)), Some((&Rect::new((90.0, 0.0), (100.0, 10.0)), &9)));
+        assert_eq!(nearest(&q, &Point::new(100.0, 100.0)), Some((&Rect::new((80.0, 80.0), (85.0, 85.0)), &8)));
+        assert_eq!(nearest(&q, &Point::new(60.0, 20.0)), Some((&Rect::new((40.0, 40.0), (45.0, 45.0)), &4)));
+    }
+
+    #[test]
+    fn nearest_bounds() {
+        let mut q = Quadtree::new(Rect::largest());
+        for i in 0..9u8 {
+            insert(&mut q, Rect::max(), i);
+        }
+        insert(&mut q, Rect::min(), 9);
+        assert_eq!(nearest(&q, &Point::min()), Some((&Rect::min(), &9)));
+        assert_eq!(nearest(&q, &Point::max()), Some((&Rect::max(), &0)));
+        assert_eq!(nearest(&q, &Point::new(Point::min().x, Point::max().y / 2.0)), Some((&Rect::min(), &9)));
+    }
+}
@@ -0,0 +1,88 @@
+use super::{Node, Quadtree};
+use crate::cartesian::d2::{
+    point::point_f64::Point,
+    rect::rect_f64::{Rect, contains_point, intersects},
+};
+
+fn query<P>(q: &Quadtree<P>, entry_matches: impl Fn(&Rect) -> bool) -> Vec<(&Rect, &P)> {
+    let mut result = Vec::new();
+    let mut stack: Vec<&Node<P>> = vec![&q.root];
+    while let Some(node) = stack.pop() {
+        result.extend(node.entries.iter().filter(|(r, _)| entry_matches(r)).map(|(r, payload)| (r, payload)));
+        if let Some(children) = &node.children {
+            stack.extend(children.iter().rev().filter(|child| entry_matches(&child.bounds)));
+        }
+    }
+    result
+}
+
+pub fn query_rect<'a, P>(q: &'a Quadtree<P>, r: &Rect) -> Vec<(&'a Rect, &'a P)> {
+    query(q, |entry| intersects(entry, r))
+}
+
+pub fn query_point<'a, P>(q: &'a Quadtree<P>, p: &Point) -> Vec<(&'a Rect, &'a P)> {
+    query(q, |entry| contains_point(entry, p))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{query_point, query_rect};
+    use crate::cartesian::d2::{
+        point::point_f64::Point,
+        quadtree::quadtree_f64::{Quadtree, insert},
+        rect::rect_f64::Rect,
+    };
+
+    fn payloads(result: Vec<(&Rect, &u8)>) -> Vec<u8> {
+        let mut payloads: Vec<u8> = result.into_iter().map(|(_, payload)| *payload).collect();
+        payloads.sort();
+        payloads
+    }
+
+    fn grid() -> Quadtree<u8> {
+        let mut q = Quadtree::new(Rect::new((0.0, 0.0), (100.0, 100.0)));
+        for i in 0..9u8 {
+            let offset = 10.0 * i as f64;
+            insert(&mut q, Rect::new((offset, offset), (offset + 5.0, offset + 5.0)), i);
+        }
+        insert(&mut q, Rect::new((40.0, 40.0), (60.0, 60.0)), 9);
+        insert(&mut q, Rect::new((90.0, 90.0), (110.0, 110.0)), 10);
+        q
+    }
+
+    #[test]
+    fn test_query_rect() {
+        let q = grid();
+        assert!(q.root.children.is_some());
+        assert_eq!(payloads(query_rect(&q, &Rect::new((0.0, 0.0), (100.0, 100.0)))), vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        assert_eq!(payloads(query_rect(&q, &Rect::new((0.0, 0.0), (12.0, 12.0)))), vec![0, 1]);
+        assert_eq!(payloads(query_rect(&q, &Rect::new((46.0, 46.0), (49.0, 49.0)))), vec![9]);
+        assert_eq!(payloads(query_rect(&q, &Rect::new((35.0, 35.0), (40.0, 40.0)))), vec![3, 4, 9]);
+        assert_eq!(payloads(query_rect(&q, &Rect::new((100.0, 100.0), (120.0, 120.0)))), vec![10]);
+        assert_eq!(payloads(query_rect(&q, &Rect::new((6.0, 0.0), (9.0, 100.0)))), vec![]);
+    }
+
+    #[test]
+    fn test_query_point() {
+        let q = grid();
+        assert_eq!(payloads(query_point(&q, &Point::new(0.0, 0.0))), vec![0]);
+        assert_eq!(payloads(query_point(&q, &Point::new(45.0, 45.0))), vec![4, 9]);
+        assert_eq!(payloads(query_point(&q, &Point::new(50.0, 50.0))), vec![5, 9]);
+        assert_eq!(payloads(query_point(&q, &Point::new(105.0, 105.0))), vec![10]);
+        assert_eq!(payloads(query_point(&q, &Point::new(7.0, 7.0))), vec![]);
+        assert_eq!(payloads(query_point(&q, &Point::new(70.0, 30.0))), vec![]);
+    }
+
+    #[test]
+    fn query_bounds() {
+        let mut q = Quadtree::new(Rect::largest());
+        for i in 0..9u8 {
+            insert(&mut q, Rect::min(), i);
+        }
+        insert(&mut q, Rect::max(), 9);
+        insert(&mut q, Rect::largest(), 10);
+        assert_eq!(payloads(query_point(&q, &Point::max())), vec![9, 10]);
+        assert_eq!(payloads(query_point(&q, &Point::min())), vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 10]);
+        assert_eq!(payloads(query_rect(&q, &Rect::largest())), vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    }
+}
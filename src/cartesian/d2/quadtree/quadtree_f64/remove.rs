@@ -0,0 +1,82 @@
+use super::{CAPACITY, Node, Quadtree};
+use crate::cartesian::d2::rect::rect_f64::Rect;
+
+fn collapse<P>(node: &mut Node<P>) {
+    if let Some(children) = node.children.take() {
+        for mut child in *children {
+            collapse(&mut child);
+            node.entries.append(&mut child.entries);
+        }
+    }
+}
+
+fn remove_node<P: PartialEq>(node: &mut Node<P>, r: &Rect, payload: &P) -> Option<P> {
+    if let Some(i) = node.entries.iter().position(|(entry_r, entry_payload)| entry_r == r && entry_payload == payload) {
+        node.count -= 1;
+        return Some(node.entries.remove(i).1);
+    }
+    let removed = remove_node(node.child_for(r)?, r, payload)?;
+    node.count -= 1;
+    if node.count <= CAPACITY {
+        collapse(node);
+    }
+    Some(removed)
+}
+
+pub fn remove<P: PartialEq>(q: &mut Quadtree<P>, r: &Rect, payload: &P) -> Option<P> {
+    remove_node(&mut q.root, r, payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::remove;
+    use crate::cartesian::d2::{
+        quadtree::quadtree_f64::{Quadtree, insert},
+        rect::rect_f64::Rect,
+    };
+
+    #[test]
+    fn test_remove() {
+        let mut q = Quadtree::new(Rect::new((0.0, 0.0), (100.0, 100.0)));
+        insert(&mut q, Rect::new((10.0, 10.0), (20.0, 20.0)), 1);
+        insert(&mut q, Rect::new((10.0, 10.0), (20.0, 20.0)), 2);
+        insert(&mut q, Rect::new((60.0, 60.0), (70.0, 70.0)), 3);
+        assert_eq!(remove(&mut q, &Rect::new((10.0, 10.0), (20.0, 20.0)), &2), Some(2));
+        assert_eq!(remove(&mut q, &Rect::new((10.0, 10.0), (20.0, 20.0)), &2), None);
+        assert_eq!(remove(&mut q, &Rect::new((10.0, 10.0), (20.0, 21.0)), &1), None);
+        assert_eq!(remove(&mut q, &Rect::new((10.0, 10.0), (20.0, 20.0)), &3), None);
+        assert_eq!(q.root.entries, vec![(Rect::new((10.0, 10.0), (20.0, 20.0)), 1), (Rect::new((60.0, 60.0), (70.0, 70.0)), 3)]);
+        assert_eq!(q.root.count, 2);
+    }
+
+    #[test]
+    fn remove_collapse() {
+        let mut q = Quadtree::new(Rect::new((0.0, 0.0), (100.0, 100.0)));
+        for i in 0..9u8 {
+            insert(&mut q, Rect::new((10.0 * i as f64, 10.0), (10.0 * i as f64 + 5.0, 15.0)), i);
+        }
+        insert(&mut q, Rect::new((60.0, 60.0), (70.0, 70.0)), 9);
+        assert!(q.root.children.is_some());
+        assert_eq!(remove(&mut q, &Rect::new((60.0, 60.0), (70.0, 70.0)), &9), Some(9));
+        assert!(q.root.children.is_some());
+        assert_eq!(q.root.count, 9);
+        assert_eq!(remove(&mut q, &Rect::new((0.0, 10.0), (5.0, 15.0)), &0), Some(0));
+        assert_eq!(q.root.children, None);
+        assert_eq!(q.root.count, 8);
+        let mut payloads: Vec<_> = q.root.entries.iter().map(|e| e.1).collect();
+        payloads.sort();
+        assert_eq!(payloads, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn remove_bounds() {
+        let mut q = Quadtree::new(Rect::largest());
+        for i in 0..9u8 {
+            insert(&mut q, Rect::largest(), i);
+        }
+        insert(&mut q, Rect::max(), 9);
+        assert_eq!(remove(&mut q, &Rect::max(), &9), Some(9));
+        assert_eq!(remove(&mut q, &Rect::largest(), &0), Some(0));
+        assert_eq!(remove(&mut q, &Rect::min(), &0), None);
+    }
+}
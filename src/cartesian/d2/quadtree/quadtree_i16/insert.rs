@@ -0,0 +1,86 @@
+use super::{CAPACITY, MAX_DEPTH, Node, Quadtree, can_split, split};
+use crate::cartesian::d2::rect::rect_i16::Rect;
+
+fn insert_node<P>(node: &mut Node<P>, r: Rect, payload: P, depth: u32) {
+    node.count += 1;
+    if let Some(child) = node.child_for(&r) {
+        return insert_node(child, r, payload, depth + 1);
+    }
+    node.entries.push((r, payload));
+    if node.children.is_none() && node.entries.len() > CAPACITY && depth < MAX_DEPTH && can_split(&node.bounds) {
+        split(node);
+    }
+}
+
+pub fn insert<P>(q: &mut Quadtree<P>, r: Rect, payload: P) {
+    insert_node(&mut q.root, r, payload, 0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::insert;
+    use crate::cartesian::d2::{quadtree::quadtree_i16::Quadtree, rect::rect_i16::Rect};
+
+    #[test]
+    fn test_insert() {
+        let mut q = Quadtree::new(Rect::new((0, 0), (100, 100)));
+        insert(&mut q, Rect::new((10, 10), (20, 20)), 1);
+        insert(&mut q, Rect::new((60, 60), (70, 70)), 2);
+        assert_eq!(q.root.entries, vec![(Rect::new((10, 10), (20, 20)), 1), (Rect::new((60, 60), (70, 70)), 2)]);
+        assert_eq!(q.root.children, None);
+    }
+
+    #[test]
+    fn insert_split() {
+        let mut q = Quadtree::new(Rect::new((0, 0), (100, 100)));
+        for i in 0..4u8 {
+            insert(&mut q, Rect::new((10 + 10 * i as i16, 10), (15 + 10 * i as i16, 15)), i);
+        }
+        insert(&mut q, Rect::new((60, 10), (70, 20)), 4);
+        insert(&mut q, Rect::new((10, 60), (20, 70)), 5);
+        insert(&mut q, Rect::new((60, 60), (70, 70)), 6);
+        insert(&mut q, Rect::new((40, 40), (60, 60)), 7);
+        assert_eq!(q.root.children, None);
+        insert(&mut q, Rect::new((80, 80), (90, 90)), 8);
+        let children = q.root.children.as_ref().unwrap();
+        assert_eq!(q.root.count, 9);
+        assert_eq!(children.iter().map(|child| child.count).collect::<Vec<_>>(), vec![4, 1, 1, 2]);
+        assert_eq!(children[0].bounds, Rect::new((0, 0), (50, 50)));
+        assert_eq!(children[1].bounds, Rect::new((50, 0), (100, 50)));
+        assert_eq!(children[2].bounds, Rect::new((0, 50), (50, 100)));
+        assert_eq!(children[3].bounds, Rect::new((50, 50), (100, 100)));
+        assert_eq!(q.root.entries, vec![(Rect::new((40, 40), (60, 60)), 7)]);
+        assert_eq!(children[0].entries.iter().map(|e| e.1).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+        assert_eq!(children[1].entries, vec![(Rect::new((60, 10), (70, 20)), 4)]);
+        assert_eq!(children[2].entries, vec![(Rect::new((10, 60), (20, 70)), 5)]);
+        assert_eq!(children[3].entries, vec![(Rect::new((60, 60), (70, 70)), 6), (Rect::new((80, 80), (90, 90)), 8)]);
+        insert(&mut q, Rect::new((1, 1), (2, 2)), 9);
+        assert_eq!(q.root.children.as_ref().unwrap()[0].entries.last(), Some(&(Rect::new((1, 1), (2, 2)), 9)));
+        assert_eq!(q.root.count, 10);
+        assert_eq!(q.root.children.as_ref().unwrap()[0].count, 5);
+    }
+
+    #[test]
+    fn insert_outside_bounds() {
+        let mut q = Quadtree::new(Rect::new((0, 0), (100, 100)));
+        for i in 0..9u8 {
+            insert(&mut q, Rect::new((10, 10), (20, 20)), i);
+        }
+        insert(&mut q, Rect::new((90, 90), (110, 110)), 9);
+        assert_eq!(q.root.entries, vec![(Rect::new((90, 90), (110, 110)), 9)]);
+    }
+
+    #[test]
+    fn insert_bounds() {
+        let mut q = Quadtree::new(Rect::largest());
+        for i in 0..9u8 {
+            insert(&mut q, Rect::largest(), i);
+        }
+        insert(&mut q, Rect::max(), 9);
+        insert(&mut q, Rect::min(), 10);
+        let children = q.root.children.as_ref().unwrap();
+        assert_eq!(q.root.entries.len(), 9);
+        assert_eq!(children[0].entries, vec![(Rect::min(), 10)]);
+        assert_eq!(children[3].entries, vec![(Rect::max(), 9)]);
+    }
+}
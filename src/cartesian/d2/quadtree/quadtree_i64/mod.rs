@@ -0,0 +1,84 @@
+use crate::cartesian::d2::rect::rect_i64::{Rect, contains_rect, delta_x, delta_y};
+
+mod insert;
+mod nearest;
+mod query;
+mod remove;
+
+pub use self::insert::insert;
+pub use self::nearest::nearest;
+pub use self::query::{query_point, query_rect};
+pub use self::remove::remove;
+
+const CAPACITY: usize = 8;
+const MAX_DEPTH: u32 = 16;
+
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct Node<P> {
+    pub bounds: Rect,
+    pub entries: Vec<(Rect, P)>,
+    pub children: Option<Box<[Node<P>; 4]>>,
+    pub count: usize,
+}
+
+impl<P> Node<P> {
+    fn new(bounds: Rect) -> Self {
+        Node { bounds, entries: Vec::new(), children: None, count: 0 }
+    }
+
+    fn child_for(&mut self, r: &Rect) -> Option<&mut Node<P>> {
+        self.children.as_mut()?.iter_mut().find(|child| contains_rect(&child.bounds, r))
+    }
+}
+
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct Quadtree<P> {
+    pub root: Node<P>,
+}
+
+impl<P> Quadtree<P> {
+    pub fn new(bounds: Rect) -> Self {
+        Quadtree { root: Node::new(bounds) }
+    }
+}
+
+fn midpoint(min: i64, max: i64) -> i64 {
+    (i128::from(min) + i128::from(max)).div_euclid(2) as i64
+}
+
+fn can_split(r: &Rect) -> bool {
+    delta_x(r) >= 2 && delta_y(r) >= 2
+}
+
+fn split<P>(node: &mut Node<P>) {
+    let (min, max) = (&node.bounds.min, &node.bounds.max);
+    let (mid_x, mid_y) = (midpoint(min.x, max.x), midpoint(min.y, max.y));
+    node.children = Some(Box::new([
+        Node::new(Rect::new((min.x, min.y), (mid_x, mid_y))),
+        Node::new(Rect::new((mid_x, min.y), (max.x, mid_y))),
+        Node::new(Rect::new((min.x, mid_y), (mid_x, max.y))),
+        Node::new(Rect::new((mid_x, mid_y), (max.x, max.y))),
+    ]));
+    for (r, payload) in std::mem::take(&mut node.entries) {
+        match node.child_for(&r) {
+            Some(child) => {
+                child.count += 1;
+                child.entries.push((r, payload));
+            }
+            None => node.entries.push((r, payload)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Node, Quadtree};
+    use crate::cartesian::d2::rect::rect_i64::Rect;
+
+    #[test]
+    fn quadtree() {
+        let bounds = Rect::new((0, 0), (100, 100));
+        assert_eq!(Quadtree::<u8>::new(bounds.clone()), Quadtree { root: Node { bounds, entries: Vec::new(), children: None, count: 0 } });
+        assert_eq!(Quadtree::<u8>::new(Rect::largest()), Quadtree { root: Node { bounds: Rect::largest(), entries: Vec::new(), children: None, count: 0 } });
+    }
+}
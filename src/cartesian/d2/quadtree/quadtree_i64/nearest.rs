@@ -0,0 +1,84 @@
+use super::{Node, Quadtree};
+use crate::cartesian::d2::{point::point_i64::Point, rect::rect_i64::Rect};
+
+fn gap(v: i64, min: i64, max: i64) -> u64 {
+    if v < min {
+        min.abs_diff(v)
+    } else if v > max {
+        v.abs_diff(max)
+    } else {
+        0
+    }
+}
+
+fn distance_squared(p: &Point, r: &Rect) -> (bool, u128) {
+    let dx = u128::from(gap(p.x, r.min.x, r.max.x));
+    let dy = u128::from(gap(p.y, r.min.y, r.max.y));
+    let (sum, overflow) = (dx * dx).overflowing_add(dy * dy);
+    (overflow, sum)
+}
+
+fn visit<'a, P>(node: &'a Node<P>, p: &Point, best: &mut Option<((bool, u128), &'a Rect, &'a P)>) {
+    for (r, payload) in &node.entries {
+        let distance = distance_squared(p, r);
+        if best.as_ref().is_none_or(|(best_distance, _, _)| distance < *best_distance) {
+            *best = Some((distance, r, payload));
+        }
+    }
+    if let Some(children) = &node.children {
+        let mut candidates: Vec<((bool, u128), &Node<P>)> = children.iter().map(|child| (distance_squared(p, &child.bounds), child)).collect();
+        candidates.sort_by_key(|candidate| candidate.0);
+        for (distance, child) in candidates {
+            if best.as_ref().is_some_and(|(best_distance, _, _)| distance >= *best_distance) {
+                break;
+            }
+            visit(child, p, best);
+        }
+    }
+}
+
+pub fn nearest<'a, P>(q: &'a Quadtree<P>, p: &Point) -> Option<(&'a Rect, &'a P)> {
+    let mut best = None;
+    visit(&q.root, p, &mut best);
+    best.map(|(_, r, payload)| (r, payload))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::nearest;
+    use crate::cartesian::d2::{
+        point::point_i64::Point,
+        quadtree::quadtree_i64::{Quadtree, insert},
+        rect::rect_i64::Rect,
+    };
+
+    #[test]
+    fn test_nearest() {
+        let mut q = Quadtree::new(Rect::new((0, 0), (100, 100)));
+        assert_eq!(nearest(&q, &Point::new(50, 50)), None);
+        for i in 0..9u8 {
+            let offset = 10 * i as i64;
+            insert(&mut q, Rect::new((offset, offset), (offset + 5, offset + 5)), i);
+        }
+        insert(&mut q, Rect::new((90, 0), (100, 10)), 9);
+        assert!(q.root.children.is_some());
+        assert_eq!(nearest(&q, &Point::new(0, 0)), Some((&Rect::new((0, 0), (5, 5)), &0)));
+        assert_eq!(nearest(&q, &Point::new(3, 4)), Some((&Rect::new((0, 0), (5, 5)), &0)));
+        assert_eq!(nearest(&q, &Point::new(43, 41)), Some((&Rect::new((40, 40), (45, 45)), &4)));
+        assert_eq!(nearest(&q, &Point::new(98, 20)), Some((&Rect::new((90, 0), (100, 10)), &9)));
+        assert_eq!(nearest(&q, &Point::new(100, 100)), Some((&Rect::new((80, 80), (85, 85)), &8)));
+        assert_eq!(nearest(&q, &Point::new(60, 20)), Some((&Rect::new((40, 40), (45, 45)), &4)));
+    }
+
+    #[test]
+    fn nearest_bounds() {
+        let mut q = Quadtree::new(Rect::largest());
+        for i in 0..9u8 {
+            insert(&mut q, Rect::max(), i);
+        }
+        insert(&mut q, Rect::min(), 9);
+        assert_eq!(nearest(&q, &Point::min()), Some((&Rect::min(), &9)));
+        assert_eq!(nearest(&q, &Point::max()), Some((&Rect::max(), &0)));
+        assert_eq!(nearest(&q, &Point::new(Point::min().x, Point::max().y / 2)), Some((&Rect::min(), &9)));
+    }
+}
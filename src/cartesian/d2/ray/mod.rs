@@ -0,0 +1,2 @@
+pub mod ray_f32;
+pub mod ray_f64;
@@ -0,0 +1,74 @@
+use super::{Hit, Ray, at};
+use crate::cartesian::d2::{circle::circle_f64::Circle, point::point_f64::Point};
+
+pub fn cast_circle(ray: &Ray, c: &Circle) -> Option<Hit> {
+    let (dx, dy) = (ray.direction.x, ray.direction.y);
+    let a = dx.mul_add(dx, dy * dy);
+    if a == 0.0 {
+        return None;
+    }
+    let (mx, my) = (ray.origin.x - c.p.x, ray.origin.y - c.p.y);
+    let half_b = mx.mul_add(dx, my * dy);
+    let k = mx.mul_add(mx, my * my) - c.r * c.r;
+    let discriminant = half_b.mul_add(half_b, -a * k);
+    if discriminant < 0.0 {
+        return None;
+    }
+    let root = discriminant.sqrt();
+    let enter = (-half_b - root) / a;
+    let exit = (-half_b + root) / a;
+    if exit < 0.0 {
+        return None;
+    }
+    let p = at(ray, enter);
+    let normal = if c.r == 0.0 {
+        let len = a.sqrt();
+        Point::new(-dx / len, -dy / len)
+    } else {
+        Point::new((p.x - c.p.x) / c.r, (p.y - c.p.y) / c.r)
+    };
+    Some(Hit { enter, exit, normal })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cast_circle;
+    use crate::cartesian::d2::{
+        circle::circle_f64::Circle,
+        point::point_f64::Point,
+        ray::ray_f64::{Hit, Ray},
+    };
+
+    #[test]
+    fn test_cast_circle() {
+        let c = Circle::new(Point::new(5.0, 0.0), 2.0);
+        assert_eq!(cast_circle(&Ray::new((0.0, 0.0), (1.0, 0.0)), &c), Some(Hit::new(3.0, 7.0, (-1.0, 0.0))));
+        assert_eq!(cast_circle(&Ray::new((0.0, 0.0), (2.0, 0.0)), &c), Some(Hit::new(1.5, 3.5, (-1.0, 0.0))));
+        assert_eq!(cast_circle(&Ray::new((10.0, 0.0), (-1.0, 0.0)), &c), Some(Hit::new(3.0, 7.0, (1.0, 0.0))));
+        assert_eq!(cast_circle(&Ray::new((5.0, -4.0), (0.0, 1.0)), &c), Some(Hit::new(2.0, 6.0, (0.0, -1.0))));
+        assert_eq!(cast_circle(&Ray::new((0.0, 5.0), (3.0, -4.0)), &Circle::new(Point::new(6.0, -3.0), 5.0)), Some(Hit::new(1.0, 3.0, (-0.6, 0.8))));
+    }
+
+    #[test]
+    fn cast_circle_miss() {
+        let c = Circle::new(Point::new(5.0, 0.0), 2.0);
+        assert_eq!(cast_circle(&Ray::new((0.0, 0.0), (-1.0, 0.0)), &c), None);
+        assert_eq!(cast_circle(&Ray::new((0.0, 3.0), (1.0, 0.0)), &c), None);
+        assert_eq!(cast_circle(&Ray::new((10.0, 0.0), (1.0, 0.0)), &c), None);
+        assert_eq!(cast_circle(&Ray::new((0.0, 0.0), (0.0, 0.0)), &c), None);
+    }
+
+    #[test]
+    fn cast_circle_inside() {
+        let c = Circle::new(Point::new(5.0, 0.0), 2.0);
+        assert_eq!(cast_circle(&Ray::new((5.0, 0.0), (1.0, 0.0)), &c), Some(Hit::new(-2.0, 2.0, (-1.0, 0.0))));
+        assert_eq!(cast_circle(&Ray::new((6.0, 0.0), (0.0, 1.0)), &Circle::new(Point::new(6.0, 0.0), 2.0)), Some(Hit::new(-2.0, 2.0, (0.0, -1.0))));
+    }
+
+    #[test]
+    fn cast_circle_touching() {
+        let c = Circle::new(Point::new(5.0, 0.0), 2.0);
+        assert_eq!(cast_circle(&Ray::new((0.0, 2.0), (1.0, 0.0)), &c), Some(Hit::new(5.0, 5.0, (0.0, 1.0))));
+        assert_eq!(cast_circle(&Ray::new((0.0, 0.0), (1.0, 0.0)), &Circle::new(Point::new(5.0, 0.0), 0.0)), Some(Hit::new(5.0, 5.0, (-1.0, 0.0))));
+    }
+}
@@ -0,0 +1,84 @@
+use super::{Hit, Ray};
+use crate::cartesian::d2::{line::line_f64::Line, point::point_f64::Point};
+
+fn cross(ax: f64, ay: f64, bx: f64, by: f64) -> f64 {
+    ax.mul_add(by, -ay * bx)
+}
+
+pub fn cast_line(ray: &Ray, l: &Line) -> Option<Hit> {
+    let (dx, dy) = (ray.direction.x, ray.direction.y);
+    let len_squared = dx.mul_add(dx, dy * dy);
+    if len_squared == 0.0 {
+        return None;
+    }
+    let (ex, ey) = (l.max.x - l.min.x, l.max.y - l.min.y);
+    let (ax, ay) = (l.min.x - ray.origin.x, l.min.y - ray.origin.y);
+    let denominator = cross(dx, dy, ex, ey);
+    if denominator == 0.0 {
+        if cross(ax, ay, dx, dy) != 0.0 {
+            return None;
+        }
+        let t_min = ax.mul_add(dx, ay * dy) / len_squared;
+        let t_max = (l.max.x - ray.origin.x).mul_add(dx, (l.max.y - ray.origin.y) * dy) / len_squared;
+        let (enter, exit) = if t_min <= t_max { (t_min, t_max) } else { (t_max, t_min) };
+        if exit < 0.0 {
+            return None;
+        }
+        let len = len_squared.sqrt();
+        return Some(Hit { enter, exit, normal: Point::new(-dx / len, -dy / len) });
+    }
+    let t = cross(ax, ay, ex, ey) / denominator;
+    let s = cross(ax, ay, dx, dy) / denominator;
+    if t < 0.0 || !(0.0..=1.0).contains(&s) {
+        return None;
+    }
+    let len = ex.hypot(ey);
+    let normal = if denominator > 0.0 { Point::new(-ey / len, ex / len) } else { Point::new(ey / len, -ex / len) };
+    Some(Hit { enter: t, exit: t, normal })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cast_line;
+    use crate::cartesian::d2::{
+        line::line_f64::Line,
+        ray::ray_f64::{Hit, Ray},
+    };
+
+    #[test]
+    fn test_cast_line() {
+        let l = Line::new((4.0, -2.0), (4.0, 2.0));
+        assert_eq!(cast_line(&Ray::new((0.0, 0.0), (1.0, 0.0)), &l), Some(Hit::new(4.0, 4.0, (-1.0, 0.0))));
+        assert_eq!(cast_line(&Ray::new((0.0, 0.0), (2.0, 1.0)), &l), Some(Hit::new(2.0, 2.0, (-1.0, 0.0))));
+        assert_eq!(cast_line(&Ray::new((8.0, 0.0), (-1.0, 0.0)), &l), Some(Hit::new(4.0, 4.0, (1.0, 0.0))));
+        assert_eq!(cast_line(&Ray::new((8.0, 0.0), (-1.0, 0.0)), &Line::new((4.0, 2.0), (4.0, -2.0))), Some(Hit::new(4.0, 4.0, (1.0, 0.0))));
+        assert_eq!(cast_line(&Ray::new((2.0, 5.0), (0.0, -1.0)), &Line::new((0.0, 0.0), (4.0, 0.0))), Some(Hit::new(5.0, 5.0, (0.0, 1.0))));
+    }
+
+    #[test]
+    fn cast_line_endpoints() {
+        let l = Line::new((4.0, -2.0), (4.0, 2.0));
+        assert_eq!(cast_line(&Ray::new((0.0, 0.0), (4.0, -2.0)), &l), Some(Hit::new(1.0, 1.0, (-1.0, 0.0))));
+        assert_eq!(cast_line(&Ray::new((4.0, 0.0), (1.0, 0.0)), &l), Some(Hit::new(0.0, 0.0, (-1.0, 0.0))));
+    }
+
+    #[test]
+    fn cast_line_miss() {
+        let l = Line::new((4.0, -2.0), (4.0, 2.0));
+        assert_eq!(cast_line(&Ray::new((0.0, 0.0), (-1.0, 0.0)), &l), None);
+        assert_eq!(cast_line(&Ray::new((0.0, 0.0), (1.0, 1.0)), &l), None);
+        assert_eq!(cast_line(&Ray::new((0.0, 0.0), (0.0, 1.0)), &l), None);
+        assert_eq!(cast_line(&Ray::new((0.0, 0.0), (0.0, 0.0)), &l), None);
+        assert_eq!(cast_line(&Ray::new((5.0, 0.0), (0.0, 1.0)), &l), None);
+    }
+
+    #[test]
+    fn cast_line_collinear() {
+        let l = Line::new((2.0, 0.0), (6.0, 0.0));
+        assert_eq!(cast_line(&Ray::new((0.0, 0.0), (1.0, 0.0)), &l), Some(Hit::new(2.0, 6.0, (-1.0, 0.0))));
+        assert_eq!(cast_line(&Ray::new((0.0, 0.0), (2.0, 0.0)), &Line::new((6.0, 0.0), (2.0, 0.0))), Some(Hit::new(1.0, 3.0, (-1.0, 0.0))));
+        assert_eq!(cast_line(&Ray::new((4.0, 0.0), (-1.0, 0.0)), &l), Some(Hit::new(-2.0, 2.0, (1.0, 0.0))));
+        assert_eq!(cast_line(&Ray::new((8.0, 0.0), (1.0, 0.0)), &l), None);
+        assert_eq!(cast_line(&Ray::new((0.0, 1.0), (1.0, 0.0)), &l), None);
+    }
+}
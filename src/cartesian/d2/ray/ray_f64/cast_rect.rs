@@ -0,0 +1,71 @@
+use super::{Hit, Ray};
+use crate::cartesian::d2::{point::point_f64::Point, rect::rect_f64::Rect};
+
+fn slab(origin: f64, direction: f64, min: f64, max: f64) -> Option<(f64, f64, f64)> {
+    if direction == 0.0 {
+        return if origin < min || origin > max { None } else { Some((f64::NEG_INFINITY, f64::INFINITY, 0.0)) };
+    }
+    let t_min = (min - origin) / direction;
+    let t_max = (max - origin) / direction;
+    if direction > 0.0 { Some((t_min, t_max, -1.0)) } else { Some((t_max, t_min, 1.0)) }
+}
+
+pub fn cast_rect(ray: &Ray, r: &Rect) -> Option<Hit> {
+    if ray.direction.x == 0.0 && ray.direction.y == 0.0 {
+        return None;
+    }
+    let (enter_x, exit_x, normal_x) = slab(ray.origin.x, ray.direction.x, r.min.x, r.max.x)?;
+    let (enter_y, exit_y, normal_y) = slab(ray.origin.y, ray.direction.y, r.min.y, r.max.y)?;
+    let exit = exit_x.min(exit_y);
+    let (enter, normal) = if enter_x >= enter_y { (enter_x, Point::new(normal_x, 0.0)) } else { (enter_y, Point::new(0.0, normal_y)) };
+    if enter > exit || exit < 0.0 {
+        return None;
+    }
+    Some(Hit { enter, exit, normal })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cast_rect;
+    use crate::cartesian::d2::{
+        ray::ray_f64::{Hit, Ray},
+        rect::rect_f64::Rect,
+    };
+
+    #[test]
+    fn test_cast_rect() {
+        let r = Rect::new((2.0, 2.0), (6.0, 4.0));
+        assert_eq!(cast_rect(&Ray::new((0.0, 3.0), (1.0, 0.0)), &r), Some(Hit::new(2.0, 6.0, (-1.0, 0.0))));
+        assert_eq!(cast_rect(&Ray::new((8.0, 3.0), (-2.0, 0.0)), &r), Some(Hit::new(1.0, 3.0, (1.0, 0.0))));
+        assert_eq!(cast_rect(&Ray::new((4.0, 0.0), (0.0, 1.0)), &r), Some(Hit::new(2.0, 4.0, (0.0, -1.0))));
+        assert_eq!(cast_rect(&Ray::new((4.0, 8.0), (0.0, -1.0)), &r), Some(Hit::new(4.0, 6.0, (0.0, 1.0))));
+        assert_eq!(cast_rect(&Ray::new((0.0, 0.0), (1.0, 1.0)), &r), Some(Hit::new(2.0, 4.0, (-1.0, 0.0))));
+        assert_eq!(cast_rect(&Ray::new((3.0, 0.0), (1.0, 1.0)), &r), Some(Hit::new(2.0, 3.0, (0.0, -1.0))));
+    }
+
+    #[test]
+    fn cast_rect_miss() {
+        let r = Rect::new((2.0, 2.0), (6.0, 4.0));
+        assert_eq!(cast_rect(&Ray::new((0.0, 3.0), (-1.0, 0.0)), &r), None);
+        assert_eq!(cast_rect(&Ray::new((0.0, 5.0), (1.0, 0.0)), &r), None);
+        assert_eq!(cast_rect(&Ray::new((0.0, 0.0), (1.0, 4.0)), &r), None);
+        assert_eq!(cast_rect(&Ray::new((8.0, 3.0), (1.0, 0.0)), &r), None);
+        assert_eq!(cast_rect(&Ray::new((4.0, 3.0), (0.0, 0.0)), &r), None);
+    }
+
+    #[test]
+    fn cast_rect_inside() {
+        let r = Rect::new((2.0, 2.0), (6.0, 4.0));
+        assert_eq!(cast_rect(&Ray::new((4.0, 3.0), (1.0, 0.0)), &r), Some(Hit::new(-2.0, 2.0, (-1.0, 0.0))));
+        assert_eq!(cast_rect(&Ray::new((4.0, 3.0), (0.0, -1.0)), &r), Some(Hit::new(-1.0, 1.0, (0.0, 1.0))));
+    }
+
+    #[test]
+    fn cast_rect_touching() {
+        let r = Rect::new((2.0, 2.0), (6.0, 4.0));
+        assert_eq!(cast_rect(&Ray::new((0.0, 4.0), (1.0, 0.0)), &r), Some(Hit::new(2.0, 6.0, (-1.0, 0.0))));
+        assert_eq!(cast_rect(&Ray::new((0.0, 0.0), (3.0, 2.0)), &r), Some(Hit::new(1.0, 2.0, (0.0, -1.0))));
+        assert_eq!(cast_rect(&Ray::new((4.0, 6.0), (1.0, -1.0)), &r), Some(Hit::new(2.0, 2.0, (0.0, 1.0))));
+        assert_eq!(cast_rect(&Ray::new((0.0, 3.0), (2.0, 0.0)), &Rect::new((2.0, 3.0), (2.0, 3.0))), Some(Hit::new(1.0, 1.0, (-1.0, 0.0))));
+    }
+}
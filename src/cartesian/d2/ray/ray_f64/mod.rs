@@ -0,0 +1,87 @@
+use crate::cartesian::d2::{point::point_f64::Point, ray::ray_f32};
+
+mod cast_circle;
+mod cast_line;
+mod cast_rect;
+
+pub use self::cast_circle::cast_circle;
+pub use self::cast_line::cast_line;
+pub use self::cast_rect::cast_rect;
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Ray {
+    pub origin: Point,
+    pub direction: Point,
+}
+
+impl Ray {
+    pub fn new(origin: (f64, f64), direction: (f64, f64)) -> Self {
+        Ray { origin: Point::new(origin.0, origin.1), direction: Point::new(direction.0, direction.1) }
+    }
+}
+
+impl std::fmt::Display for Ray {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.origin, self.direction)
+    }
+}
+
+impl From<ray_f32::Ray> for Ray {
+    fn from(r: ray_f32::Ray) -> Self {
+        Ray { origin: Point::from(r.origin), direction: Point::from(r.direction) }
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Hit {
+    pub enter: f64,
+    pub exit: f64,
+    pub normal: Point,
+}
+
+impl Hit {
+    pub fn new(enter: f64, exit: f64, normal: (f64, f64)) -> Self {
+        Hit { enter, exit, normal: Point::new(normal.0, normal.1) }
+    }
+}
+
+pub fn at(ray: &Ray, t: f64) -> Point {
+    Point::new(t.mul_add(ray.direction.x, ray.origin.x), t.mul_add(ray.direction.y, ray.origin.y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Hit, Ray, at};
+    use crate::cartesian::d2::{point::point_f64::Point, ray::ray_f32};
+
+    #[test]
+    fn ray() {
+        assert_eq!(Ray::new((-1.0, 2.0), (1.0, 0.0)), Ray { origin: Point::new(-1.0, 2.0), direction: Point::new(1.0, 0.0) });
+        assert_eq!(Ray::new((0.0, 0.0), (-3.0, 4.0)), Ray { origin: Point::zero(), direction: Point::new(-3.0, 4.0) });
+    }
+
+    #[test]
+    fn hit() {
+        assert_eq!(Hit::new(1.0, 3.0, (-1.0, 0.0)), Hit { enter: 1.0, exit: 3.0, normal: Point::new(-1.0, 0.0) });
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(Ray::new((-1.0, 2.0), (1.0, 0.0)).to_string(), "((-1, 2), (1, 0))");
+        assert_eq!(Ray::new((0.5, 0.0), (-3.0, 4.0)).to_string(), "((0.5, 0), (-3, 4))");
+    }
+
+    #[test]
+    fn from() {
+        assert_eq!(Ray::from(ray_f32::Ray::new((-1.0, 2.0), (1.0, 0.0))), Ray::new((-1.0, 2.0), (1.0, 0.0)));
+        assert_eq!(Ray::from(ray_f32::Ray::new((0.5, 0.0), (-3.0, 4.0))), Ray::new((0.5, 0.0), (-3.0, 4.0)));
+    }
+
+    #[test]
+    fn test_at() {
+        let ray = Ray::new((1.0, 2.0), (3.0, -4.0));
+        assert_eq!(at(&ray, 0.0), Point::new(1.0, 2.0));
+        assert_eq!(at(&ray, 0.5), Point::new(2.5, 0.0));
+        assert_eq!(at(&ray, 2.0), Point::new(7.0, -6.0));
+    }
+}
@@ -0,0 +1,121 @@
+use super::{Rect, saturating_translate};
+use crate::cartesian::d2::point::point_f32::Point;
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Sweep {
+    pub time: f32,
+    pub normal: Point,
+    pub position: Rect,
+}
+
+fn axis(min: f32, max: f32, velocity: f32, target_min: f32, target_max: f32) -> Option<(f32, f32, f32)> {
+    if velocity > 0.0 {
+        Some(((target_min - max) / velocity, (target_max - min) / velocity, target_min - max))
+    } else if velocity < 0.0 {
+        Some(((target_max - min) / velocity, (target_min - max) / velocity, target_max - min))
+    } else if max > target_min && min < target_max {
+        Some((f32::NEG_INFINITY, f32::INFINITY, 0.0))
+    } else {
+        None
+    }
+}
+
+pub fn sweep(moving: &Rect, velocity: &Point, target: &Rect) -> Option<Sweep> {
+    let (entry_x, exit_x, gap_x) = axis(moving.min.x, moving.max.x, velocity.x, target.min.x, target.max.x)?;
+    let (entry_y, exit_y, gap_y) = axis(moving.min.y, moving.max.y, velocity.y, target.min.y, target.max.y)?;
+    let entry = entry_x.max(entry_y);
+    if !(0.0..=1.0).contains(&entry) || entry >= exit_x.min(exit_y) {
+        return None;
+    }
+    if entry_x >= entry_y {
+        let position = saturating_translate(moving, &Point::new(gap_x, velocity.y));
+        Some(Sweep { time: entry, normal: Point::new(-velocity.x.signum(), 0.0), position })
+    } else {
+        let position = saturating_translate(moving, &Point::new(velocity.x, gap_y));
+        Some(Sweep { time: entry, normal: Point::new(0.0, -velocity.y.signum()), position })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Sweep, sweep};
+    use crate::cartesian::{
+        d1::point::point_f32::{MAX, MIN},
+        d2::{point::point_f32::Point, rect::rect_f32::Rect},
+    };
+
+    #[test]
+    fn test_sweep() {
+        let a = Rect::new((0.0, 0.0), (2.0, 2.0));
+        let b = Rect::new((5.0, 0.0), (7.0, 2.0));
+        assert_eq!(sweep(&a, &Point::new(5.0, 0.0), &b), Some(Sweep { time: 3.0 / 5.0, normal: Point::new(-1.0, 0.0), position: Rect::new((3.0, 0.0), (5.0, 2.0)) }));
+        assert_eq!(sweep(&a, &Point::new(3.0, 0.0), &b), Some(Sweep { time: 1.0, normal: Point::new(-1.0, 0.0), position: Rect::new((3.0, 0.0), (5.0, 2.0)) }));
+        assert_eq!(sweep(&b, &Point::new(-6.0, 0.0), &a), Some(Sweep { time: 3.0 / 6.0, normal: Point::new(1.0, 0.0), position: Rect::new((2.0, 0.0), (4.0, 2.0)) }));
+        assert_eq!(
+            sweep(&Rect::new((0.0, 4.0), (2.0, 6.0)), &Point::new(0.0, -4.0), &Rect::new((0.0, 0.0), (2.0, 2.0))),
+            Some(Sweep { time: 2.0 / 4.0, normal: Point::new(0.0, 1.0), position: Rect::new((0.0, 2.0), (2.0, 4.0)) })
+        );
+        assert_eq!(
+            sweep(&Rect::new((0.0, 0.0), (2.0, 2.0)), &Point::new(0.0, 4.0), &Rect::new((0.0, 4.0), (2.0, 6.0))),
+            Some(Sweep { time: 2.0 / 4.0, normal: Point::new(0.0, -1.0), position: Rect::new((0.0, 2.0), (2.0, 4.0)) })
+        );
+    }
+
+    #[test]
+    fn sweep_slide() {
+        let a = Rect::new((0.0, 0.0), (2.0, 2.0));
+        assert_eq!(sweep(&a, &Point::new(6.0, 1.0), &Rect::new((5.0, 0.0), (7.0, 2.0))), Some(Sweep { time: 3.0 / 6.0, normal: Point::new(-1.0, 0.0), position: Rect::new((3.0, 1.0), (5.0, 3.0)) }));
+        assert_eq!(
+            sweep(&Rect::new((0.0, 4.0), (2.0, 6.0)), &Point::new(1.0, -4.0), &Rect::new((-10.0, 0.0), (10.0, 2.0))),
+            Some(Sweep { time: 2.0 / 4.0, normal: Point::new(0.0, 1.0), position: Rect::new((1.0, 2.0), (3.0, 4.0)) })
+        );
+        assert_eq!(
+            sweep(&Rect::new((0.0, 2.0), (2.0, 4.0)), &Point::new(3.0, -1.0), &Rect::new((-10.0, 0.0), (10.0, 2.0))),
+            Some(Sweep { time: 0.0, normal: Point::new(0.0, 1.0), position: Rect::new((3.0, 2.0), (5.0, 4.0)) })
+        );
+    }
+
+    #[test]
+    fn sweep_corner() {
+        assert_eq!(
+            sweep(&Rect::new((0.0, 0.0), (2.0, 2.0)), &Point::new(2.0, 2.0), &Rect::new((4.0, 4.0), (6.0, 6.0))),
+            Some(Sweep { time: 1.0, normal: Point::new(-1.0, 0.0), position: Rect::new((2.0, 2.0), (4.0, 4.0)) })
+        );
+        assert_eq!(
+            sweep(&Rect::new((0.0, 0.0), (2.0, 2.0)), &Point::new(2.0, 4.0), &Rect::new((4.0, 4.0), (6.0, 6.0))),
+            Some(Sweep { time: 1.0, normal: Point::new(-1.0, 0.0), position: Rect::new((2.0, 4.0), (4.0, 6.0)) })
+        );
+        assert_eq!(sweep(&Rect::new((0.0, 0.0), (2.0, 2.0)), &Point::new(6.0, 2.0), &Rect::new((4.0, 4.0), (6.0, 6.0))), None);
+    }
+
+    #[test]
+    fn sweep_miss() {
+        let a = Rect::new((0.0, 0.0), (2.0, 2.0));
+        let b = Rect::new((5.0, 0.0), (7.0, 2.0));
+        assert_eq!(sweep(&a, &Point::new(2.0, 0.0), &b), None);
+        assert_eq!(sweep(&a, &Point::new(-5.0, 0.0), &b), None);
+        assert_eq!(sweep(&a, &Point::new(5.0, 4.0), &b), None);
+        assert_eq!(sweep(&a, &Point::new(0.0, 5.0), &b), None);
+        assert_eq!(sweep(&a, &Point::new(0.0, 0.0), &b), None);
+        assert_eq!(sweep(&Rect::new((0.0, 2.0), (2.0, 4.0)), &Point::new(3.0, 0.0), &Rect::new((-10.0, 0.0), (10.0, 2.0))), None);
+        assert_eq!(sweep(&Rect::new((0.0, 2.0), (2.0, 4.0)), &Point::new(3.0, 1.0), &Rect::new((-10.0, 0.0), (10.0, 2.0))), None);
+    }
+
+    #[test]
+    fn sweep_overlapping() {
+        assert_eq!(sweep(&Rect::new((0.0, 0.0), (2.0, 2.0)), &Point::new(1.0, 0.0), &Rect::new((1.0, 1.0), (3.0, 3.0))), None);
+        assert_eq!(sweep(&Rect::new((0.0, 0.0), (2.0, 2.0)), &Point::new(0.0, 0.0), &Rect::new((1.0, 1.0), (3.0, 3.0))), None);
+    }
+
+    #[test]
+    fn sweep_bounds() {
+        let left = Rect::new((MIN, 0.0), (-1.0, 1.0));
+        let right = Rect::new((1.0, 0.0), (MAX, 1.0));
+        assert_eq!(sweep(&left, &Point::new(MAX, 0.0), &right), Some(Sweep { time: 2.0 / MAX, normal: Point::new(-1.0, 0.0), position: Rect::new((MIN + 2.0, 0.0), (1.0, 1.0)) }));
+        assert_eq!(sweep(&right, &Point::new(MIN, 0.0), &left), Some(Sweep { time: -2.0 / MIN, normal: Point::new(1.0, 0.0), position: Rect::new((-1.0, 0.0), (MAX - 2.0, 1.0)) }));
+        assert_eq!(
+            sweep(&Rect::new((0.0, MAX - 3.0), (2.0, MAX - 2.0)), &Point::new(30.0, 10.0), &Rect::new((5.0, MAX - 10.0), (7.0, MAX))),
+            Some(Sweep { time: 3.0 / 30.0, normal: Point::new(-1.0, 0.0), position: Rect::new((3.0, MAX - 1.0), (5.0, MAX)) })
+        );
+    }
+}
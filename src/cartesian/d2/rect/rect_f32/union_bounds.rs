@@ -0,0 +1,66 @@
+use super::Rect;
+
+pub fn union_bounds_assign(r: &mut Rect, other: &Rect) {
+    r.min.x = r.min.x.min(other.min.x);
+    r.min.y = r.min.y.min(other.min.y);
+    r.max.x = r.max.x.max(other.max.x);
+    r.max.y = r.max.y.max(other.max.y);
+}
+
+pub fn union_bounds(a: &Rect, b: &Rect) -> Rect {
+    Rect::new((a.min.x.min(b.min.x), a.min.y.min(b.min.y)), (a.max.x.max(b.max.x), a.max.y.max(b.max.y)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{union_bounds, union_bounds_assign};
+    use crate::cartesian::{
+        d1::point::point_f32::{MAX, MIN},
+        d2::rect::rect_f32::Rect,
+    };
+
+    #[test]
+    fn test_union_bounds_assign() {
+        let mut r = Rect::new((10.0, 10.0), (20.0, 20.0));
+        union_bounds_assign(&mut r, &Rect::new((12.0, 12.0), (18.0, 18.0)));
+        assert_eq!(r, Rect::new((10.0, 10.0), (20.0, 20.0)));
+        union_bounds_assign(&mut r, &Rect::new((15.0, 15.0), (25.0, 25.0)));
+        assert_eq!(r, Rect::new((10.0, 10.0), (25.0, 25.0)));
+        union_bounds_assign(&mut r, &Rect::new((5.0, 5.0), (15.0, 15.0)));
+        assert_eq!(r, Rect::new((5.0, 5.0), (25.0, 25.0)));
+        union_bounds_assign(&mut r, &Rect::new((30.0, 0.0), (40.0, 1.0)));
+        assert_eq!(r, Rect::new((5.0, 0.0), (40.0, 25.0)));
+    }
+
+    #[test]
+    fn test_union_bounds() {
+        let r = Rect::new((10.0, 10.0), (20.0, 20.0));
+        assert_eq!(union_bounds(&r, &r), r);
+        assert_eq!(union_bounds(&r, &Rect::new((12.0, 12.0), (18.0, 18.0))), Rect::new((10.0, 10.0), (20.0, 20.0)));
+        assert_eq!(union_bounds(&r, &Rect::new((15.0, 15.0), (25.0, 25.0))), Rect::new((10.0, 10.0), (25.0, 25.0)));
+        assert_eq!(union_bounds(&r, &Rect::new((5.0, 5.0), (15.0, 15.0))), Rect::new((5.0, 5.0), (20.0, 20.0)));
+        assert_eq!(union_bounds(&r, &Rect::new((30.0, 0.0), (40.0, 1.0))), Rect::new((10.0, 0.0), (40.0, 20.0)));
+        assert_eq!(union_bounds(&Rect::new((30.0, 0.0), (40.0, 1.0)), &r), Rect::new((10.0, 0.0), (40.0, 20.0)));
+    }
+
+    #[test]
+    fn union_bounds_assign_bounds() {
+        let mut r = Rect::min();
+        union_bounds_assign(&mut r, &Rect::max());
+        assert_eq!(r, Rect::largest());
+
+        let mut r = Rect::new((MIN + 1.0, MIN + 1.0), (MIN + 1.0, MIN + 1.0));
+        union_bounds_assign(&mut r, &Rect::new((MAX - 1.0, MAX - 1.0), (MAX - 1.0, MAX - 1.0)));
+        assert_eq!(r, Rect::new((MIN + 1.0, MIN + 1.0), (MAX - 1.0, MAX - 1.0)));
+    }
+
+    #[test]
+    fn union_bounds_bounds() {
+        assert_eq!(union_bounds(&Rect::min(), &Rect::max()), Rect::largest());
+        assert_eq!(union_bounds(&Rect::largest(), &Rect::min()), Rect::largest());
+        assert_eq!(
+            union_bounds(&Rect::new((MIN + 1.0, MIN + 1.0), (MIN + 1.0, MIN + 1.0)), &Rect::new((MAX - 1.0, MAX - 1.0), (MAX - 1.0, MAX - 1.0))),
+            Rect::new((MIN + 1.0, MIN + 1.0), (MAX - 1.0, MAX - 1.0))
+        );
+    }
+}
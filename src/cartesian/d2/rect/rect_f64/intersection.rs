@@ -0,0 +1,110 @@
+use super::Rect;
+
+pub fn intersects(a: &Rect, b: &Rect) -> bool {
+    a.min.x <= b.max.x && b.min.x <= a.max.x && a.min.y <= b.max.y && b.min.y <= a.max.y
+}
+
+pub fn intersection(a: &Rect, b: &Rect) -> Option<Rect> {
+    if !intersects(a, b) {
+        return None;
+    }
+    Some(Rect::new((a.min.x.max(b.min.x), a.min.y.max(b.min.y)), (a.max.x.min(b.max.x), a.max.y.min(b.max.y))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{intersection, intersects};
+    use crate::cartesian::{
+        d1::point::point_f64::{MAX, MIN},
+        d2::rect::rect_f64::Rect,
+    };
+
+    #[test]
+    fn test_intersects() {
+        let r = Rect::new((10.0, 10.0), (20.0, 20.0));
+        assert!(intersects(&r, &Rect::new((15.0, 15.0), (25.0, 25.0))));
+        assert!(intersects(&r, &Rect::new((5.0, 5.0), (15.0, 15.0))));
+        assert!(intersects(&r, &Rect::new((15.0, 5.0), (25.0, 15.0))));
+        assert!(intersects(&r, &Rect::new((5.0, 15.0), (15.0, 25.0))));
+        assert!(intersects(&r, &Rect::new((12.0, 12.0), (18.0, 18.0))));
+        assert!(intersects(&r, &Rect::new((0.0, 0.0), (30.0, 30.0))));
+        assert!(intersects(&r, &Rect::new((0.0, 12.0), (30.0, 18.0))));
+    }
+
+    #[test]
+    fn intersects_borders() {
+        let r = Rect::new((10.0, 10.0), (20.0, 20.0));
+        assert!(intersects(&r, &r));
+        assert!(intersects(&r, &Rect::new((20.0, 10.0), (30.0, 20.0))));
+        assert!(intersects(&r, &Rect::new((0.0, 10.0), (10.0, 20.0))));
+        assert!(intersects(&r, &Rect::new((10.0, 20.0), (20.0, 30.0))));
+        assert!(intersects(&r, &Rect::new((10.0, 0.0), (20.0, 10.0))));
+        assert!(intersects(&r, &Rect::new((0.0, 0.0), (10.0, 10.0))));
+        assert!(intersects(&r, &Rect::new((20.0, 20.0), (30.0, 30.0))));
+    }
+
+    #[test]
+    fn intersects_outside() {
+        let r = Rect::new((10.0, 10.0), (20.0, 20.0));
+        assert!(!intersects(&r, &Rect::new((21.0, 10.0), (30.0, 20.0))));
+        assert!(!intersects(&r, &Rect::new((0.0, 10.0), (9.0, 20.0))));
+        assert!(!intersects(&r, &Rect::new((10.0, 21.0), (20.0, 30.0))));
+        assert!(!intersects(&r, &Rect::new((10.0, 0.0), (20.0, 9.0))));
+        assert!(!intersects(&r, &Rect::new((21.0, 21.0), (30.0, 30.0))));
+        assert!(!intersects(&r, &Rect::new((0.0, 0.0), (9.0, 9.0))));
+    }
+
+    #[test]
+    fn intersects_bounds() {
+        assert!(intersects(&Rect::largest(), &Rect::min()));
+        assert!(intersects(&Rect::largest(), &Rect::max()));
+        assert!(intersects(&Rect::largest(), &Rect::new((MIN + 1.0, MIN + 1.0), (MAX - 1.0, MAX - 1.0))));
+        assert!(!intersects(&Rect::min(), &Rect::max()));
+        assert!(!intersects(&Rect::new((MIN, MIN), (MIN + 1.0, MIN + 1.0)), &Rect::new((MAX - 1.0, MAX - 1.0), (MAX, MAX))));
+    }
+
+    #[test]
+    fn test_intersection() {
+        let r = Rect::new((10.0, 10.0), (20.0, 20.0));
+        assert_eq!(intersection(&r, &Rect::new((15.0, 15.0), (25.0, 25.0))), Some(Rect::new((15.0, 15.0), (20.0, 20.0))));
+        assert_eq!(intersection(&r, &Rect::new((5.0, 5.0), (15.0, 15.0))), Some(Rect::new((10.0, 10.0), (15.0, 15.0))));
+        assert_eq!(intersection(&r, &Rect::new((15.0, 5.0), (25.0, 15.0))), Some(Rect::new((15.0, 10.0), (20.0, 15.0))));
+        assert_eq!(intersection(&r, &Rect::new((5.0, 15.0), (15.0, 25.0))), Some(Rect::new((10.0, 15.0), (15.0, 20.0))));
+        assert_eq!(intersection(&r, &Rect::new((12.0, 12.0), (18.0, 18.0))), Some(Rect::new((12.0, 12.0), (18.0, 18.0))));
+        assert_eq!(intersection(&r, &Rect::new((0.0, 0.0), (30.0, 30.0))), Some(Rect::new((10.0, 10.0), (20.0, 20.0))));
+        assert_eq!(intersection(&r, &Rect::new((0.0, 12.0), (30.0, 18.0))), Some(Rect::new((10.0, 12.0), (20.0, 18.0))));
+    }
+
+    #[test]
+    fn intersection_borders() {
+        let r = Rect::new((10.0, 10.0), (20.0, 20.0));
+        assert_eq!(intersection(&r, &r), Some(r.clone()));
+        assert_eq!(intersection(&r, &Rect::new((20.0, 10.0), (30.0, 20.0))), Some(Rect::new((20.0, 10.0), (20.0, 20.0))));
+        assert_eq!(intersection(&r, &Rect::new((0.0, 10.0), (10.0, 20.0))), Some(Rect::new((10.0, 10.0), (10.0, 20.0))));
+        assert_eq!(intersection(&r, &Rect::new((10.0, 20.0), (20.0, 30.0))), Some(Rect::new((10.0, 20.0), (20.0, 20.0))));
+        assert_eq!(intersection(&r, &Rect::new((10.0, 0.0), (20.0, 10.0))), Some(Rect::new((10.0, 10.0), (20.0, 10.0))));
+        assert_eq!(intersection(&r, &Rect::new((0.0, 0.0), (10.0, 10.0))), Some(Rect::new((10.0, 10.0), (10.0, 10.0))));
+        assert_eq!(intersection(&r, &Rect::new((20.0, 20.0), (30.0, 30.0))), Some(Rect::new((20.0, 20.0), (20.0, 20.0))));
+    }
+
+    #[test]
+    fn intersection_outside() {
+        let r = Rect::new((10.0, 10.0), (20.0, 20.0));
+        assert_eq!(intersection(&r, &Rect::new((21.0, 10.0), (30.0, 20.0))), None);
+        assert_eq!(intersection(&r, &Rect::new((0.0, 10.0), (9.0, 20.0))), None);
+        assert_eq!(intersection(&r, &Rect::new((10.0, 21.0), (20.0, 30.0))), None);
+        assert_eq!(intersection(&r, &Rect::new((10.0, 0.0), (20.0, 9.0))), None);
+        assert_eq!(intersection(&r, &Rect::new((21.0, 21.0), (30.0, 30.0))), None);
+        assert_eq!(intersection(&r, &Rect::new((0.0, 0.0), (9.0, 9.0))), None);
+    }
+
+    #[test]
+    fn intersection_bounds() {
+        assert_eq!(intersection(&Rect::largest(), &Rect::largest()), Some(Rect::largest()));
+        assert_eq!(intersection(&Rect::largest(), &Rect::min()), Some(Rect::min()));
+        assert_eq!(intersection(&Rect::largest(), &Rect::max()), Some(Rect::max()));
+        assert_eq!(intersection(&Rect::largest(), &Rect::new((MIN + 1.0, MIN + 1.0), (MAX - 1.0, MAX - 1.0))), Some(Rect::new((MIN + 1.0, MIN + 1.0), (MAX - 1.0, MAX - 1.0))));
+        assert_eq!(intersection(&Rect::new((MIN, MIN), (MAX - 1.0, MAX - 1.0)), &Rect::new((MIN + 1.0, MIN + 1.0), (MAX, MAX))), Some(Rect::new((MIN + 1.0, MIN + 1.0), (MAX - 1.0, MAX - 1.0))));
+        assert_eq!(intersection(&Rect::min(), &Rect::max()), None);
+    }
+}
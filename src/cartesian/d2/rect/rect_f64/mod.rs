@@ -3,17 +3,23 @@ use crate::iter::iter_f64;
 
 mod add;
 mod area;
+mod cells;
 mod contains_point;
 mod contains_rect;
 mod deflate;
 mod delta;
 mod inflate;
+mod intersection;
 mod len;
+mod overlapping_pairs;
 mod resize;
+mod sweep;
 mod translate;
+mod union_bounds;
 
 pub use self::add::{checked_add, checked_add_assign, saturating_add, saturating_add_assign, try_checked_add, try_checked_add_assign, wrapping_add, wrapping_add_assign};
 pub use self::area::area;
+pub use self::cells::{Border, Cells, Spiral};
 pub use self::contains_point::contains_point;
 pub use self::contains_rect::contains_rect;
 pub use self::deflate::{deflate, deflate_assign, try_deflate, try_deflate_assign};
@@ -21,13 +27,17 @@ pub use self::delta::{delta_max, delta_min, delta_x, delta_y};
 pub use self::inflate::{
     checked_inflate, checked_inflate_assign, saturating_inflate, saturating_inflate_assign, try_checked_inflate, try_checked_inflate_assign, try_saturating_inflate, try_saturating_inflate_assign,
 };
+pub use self::intersection::{intersection, intersects};
 pub use self::len::{len_max, len_x, len_y};
+pub use self::overlapping_pairs::{overlapping_pairs, overlapping_pairs_incremental};
 pub use self::resize::{
     checked_resize, checked_resize_assign, saturating_resize, saturating_resize_assign, try_checked_resize, try_checked_resize_assign, try_saturating_resize, try_saturating_resize_assign,
 };
+pub use self::sweep::{Sweep, sweep};
 pub use self::translate::{
     checked_translate, checked_translate_assign, saturating_translate, saturating_translate_assign, try_checked_translate, try_checked_translate_assign, wrapping_translate, wrapping_translate_assign,
 };
+pub use self::union_bounds::{union_bounds, union_bounds_assign};
 
 #[derive(PartialEq, Debug, Clone)]
 pub struct Rect {
@@ -71,6 +81,26 @@ impl Rect {
     pub fn iter_y(&self) -> iter_f64::Iter {
         iter_f64::Iter::new(self.min.y, self.max.y)
     }
+
+    pub fn iter_row_major(&self) -> Cells {
+        Cells::new(self, false)
+    }
+
+    pub fn iter_column_major(&self) -> Cells {
+        Cells::new(self, true)
+    }
+
+    pub fn iter_spiral_inward(&self) -> Spiral {
+        Spiral::new(self, false)
+    }
+
+    pub fn iter_spiral_outward(&self) -> Spiral {
+        Spiral::new(self, true)
+    }
+
+    pub fn iter_border(&self) -> Border {
+        Border::new(self)
+    }
 }
 
 impl std::fmt::Display for Rect {
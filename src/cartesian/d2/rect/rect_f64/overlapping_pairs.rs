@@ -0,0 +1,83 @@
+use super::Rect;
+
+fn sweep_pairs(rects: &[Rect], order: &[usize]) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    for (i, &a) in order.iter().enumerate() {
+        for &b in &order[i + 1..] {
+            if rects[b].min.x > rects[a].max.x {
+                break;
+            }
+            if rects[a].min.y <= rects[b].max.y && rects[b].min.y <= rects[a].max.y {
+                pairs.push((a.min(b), a.max(b)));
+            }
+        }
+    }
+    pairs.sort();
+    pairs
+}
+
+pub fn overlapping_pairs(rects: &[Rect]) -> Vec<(usize, usize)> {
+    let mut order: Vec<usize> = (0..rects.len()).collect();
+    order.sort_by(|&a, &b| rects[a].min.x.total_cmp(&rects[b].min.x));
+    sweep_pairs(rects, &order)
+}
+
+pub fn overlapping_pairs_incremental(rects: &[Rect], order: &mut Vec<usize>) -> Vec<(usize, usize)> {
+    if order.len() != rects.len() {
+        *order = (0..rects.len()).collect();
+    }
+    for i in 1..order.len() {
+        let mut j = i;
+        while j > 0 && rects[order[j - 1]].min.x > rects[order[j]].min.x {
+            order.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+    sweep_pairs(rects, order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{overlapping_pairs, overlapping_pairs_incremental};
+    use crate::cartesian::d2::rect::rect_f64::Rect;
+
+    fn rects() -> Vec<Rect> {
+        vec![
+            Rect::new((0.0, 0.0), (10.0, 10.0)),
+            Rect::new((5.0, 5.0), (15.0, 15.0)),
+            Rect::new((20.0, 0.0), (30.0, 10.0)),
+            Rect::new((10.0, 20.0), (20.0, 30.0)),
+            Rect::new((15.0, 0.0), (20.0, 5.0)),
+            Rect::new((25.0, 25.0), (28.0, 28.0)),
+        ]
+    }
+
+    #[test]
+    fn test_overlapping_pairs() {
+        assert_eq!(overlapping_pairs(&rects()), vec![(0, 1), (1, 4), (2, 4)]);
+        assert_eq!(overlapping_pairs(&[Rect::new((0.0, 0.0), (10.0, 10.0)), Rect::new((0.0, 11.0), (10.0, 20.0)), Rect::new((11.0, 0.0), (20.0, 10.0))]), vec![]);
+        assert_eq!(overlapping_pairs(&[Rect::new((0.0, 0.0), (10.0, 10.0)), Rect::new((0.0, 0.0), (10.0, 10.0)), Rect::new((10.0, 10.0), (10.0, 10.0))]), vec![(0, 1), (0, 2), (1, 2)]);
+        assert_eq!(overlapping_pairs(&[]), vec![]);
+    }
+
+    #[test]
+    fn test_overlapping_pairs_incremental() {
+        let mut rects = rects();
+        let mut order = Vec::new();
+        assert_eq!(overlapping_pairs_incremental(&rects, &mut order), vec![(0, 1), (1, 4), (2, 4)]);
+        assert_eq!(order, vec![0, 1, 3, 4, 2, 5]);
+        rects[5] = Rect::new((8.0, 8.0), (9.0, 9.0));
+        assert_eq!(overlapping_pairs_incremental(&rects, &mut order), vec![(0, 1), (0, 5), (1, 4), (1, 5), (2, 4)]);
+        assert_eq!(order, vec![0, 1, 5, 3, 4, 2]);
+        rects.truncate(3);
+        assert_eq!(overlapping_pairs_incremental(&rects, &mut order), vec![(0, 1)]);
+        assert_eq!(order, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn overlapping_pairs_bounds() {
+        let rects = [Rect::largest(), Rect::min(), Rect::max()];
+        assert_eq!(overlapping_pairs(&rects), vec![(0, 1), (0, 2)]);
+        assert_eq!(overlapping_pairs_incremental(&rects, &mut vec![2, 1, 0]), vec![(0, 1), (0, 2)]);
+    }
+}
@@ -0,0 +1,110 @@
+use super::Rect;
+
+pub fn intersects(a: &Rect, b: &Rect) -> bool {
+    a.min.x <= b.max.x && b.min.x <= a.max.x && a.min.y <= b.max.y && b.min.y <= a.max.y
+}
+
+pub fn intersection(a: &Rect, b: &Rect) -> Option<Rect> {
+    if !intersects(a, b) {
+        return None;
+    }
+    Some(Rect::new((a.min.x.max(b.min.x), a.min.y.max(b.min.y)), (a.max.x.min(b.max.x), a.max.y.min(b.max.y))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{intersection, intersects};
+    use crate::cartesian::{
+        d1::point::point_i32::{MAX, MIN},
+        d2::rect::rect_i32::Rect,
+    };
+
+    #[test]
+    fn test_intersects() {
+        let r = Rect::new((10, 10), (20, 20));
+        assert!(intersects(&r, &Rect::new((15, 15), (25, 25))));
+        assert!(intersects(&r, &Rect::new((5, 5), (15, 15))));
+        assert!(intersects(&r, &Rect::new((15, 5), (25, 15))));
+        assert!(intersects(&r, &Rect::new((5, 15), (15, 25))));
+        assert!(intersects(&r, &Rect::new((12, 12), (18, 18))));
+        assert!(intersects(&r, &Rect::new((0, 0), (30, 30))));
+        assert!(intersects(&r, &Rect::new((0, 12), (30, 18))));
+    }
+
+    #[test]
+    fn intersects_borders() {
+        let r = Rect::new((10, 10), (20, 20));
+        assert!(intersects(&r, &r));
+        assert!(intersects(&r, &Rect::new((20, 10), (30, 20))));
+        assert!(intersects(&r, &Rect::new((0, 10), (10, 20))));
+        assert!(intersects(&r, &Rect::new((10, 20), (20, 30))));
+        assert!(intersects(&r, &Rect::new((10, 0), (20, 10))));
+        assert!(intersects(&r, &Rect::new((0, 0), (10, 10))));
+        assert!(intersects(&r, &Rect::new((20, 20), (30, 30))));
+    }
+
+    #[test]
+    fn intersects_outside() {
+        let r = Rect::new((10, 10), (20, 20));
+        assert!(!intersects(&r, &Rect::new((21, 10), (30, 20))));
+        assert!(!intersects(&r, &Rect::new((0, 10), (9, 20))));
+        assert!(!intersects(&r, &Rect::new((10, 21), (20, 30))));
+        assert!(!intersects(&r, &Rect::new((10, 0), (20, 9))));
+        assert!(!intersects(&r, &Rect::new((21, 21), (30, 30))));
+        assert!(!intersects(&r, &Rect::new((0, 0), (9, 9))));
+    }
+
+    #[test]
+    fn intersects_bounds() {
+        assert!(intersects(&Rect::largest(), &Rect::min()));
+        assert!(intersects(&Rect::largest(), &Rect::max()));
+        assert!(intersects(&Rect::largest(), &Rect::new((MIN + 1, MIN + 1), (MAX - 1, MAX - 1))));
+        assert!(!intersects(&Rect::min(), &Rect::max()));
+        assert!(!intersects(&Rect::new((MIN, MIN), (MIN + 1, MIN + 1)), &Rect::new((MAX - 1, MAX - 1), (MAX, MAX))));
+    }
+
+    #[test]
+    fn test_intersection() {
+        let r = Rect::new((10, 10), (20, 20));
+        assert_eq!(intersection(&r, &Rect::new((15, 15), (25, 25))), Some(Rect::new((15, 15), (20, 20))));
+        assert_eq!(intersection(&r, &Rect::new((5, 5), (15, 15))), Some(Rect::new((10, 10), (15, 15))));
+        assert_eq!(intersection(&r, &Rect::new((15, 5), (25, 15))), Some(Rect::new((15, 10), (20, 15))));
+        assert_eq!(intersection(&r, &Rect::new((5, 15), (15, 25))), Some(Rect::new((10, 15), (15, 20))));
+        assert_eq!(intersection(&r, &Rect::new((12, 12), (18, 18))), Some(Rect::new((12, 12), (18, 18))));
+        assert_eq!(intersection(&r, &Rect::new((0, 0), (30, 30))), Some(Rect::new((10, 10), (20, 20))));
+        assert_eq!(intersection(&r, &Rect::new((0, 12), (30, 18))), Some(Rect::new((10, 12), (20, 18))));
+    }
+
+    #[test]
+    fn intersection_borders() {
+        let r = Rect::new((10, 10), (20, 20));
+        assert_eq!(intersection(&r, &r), Some(r.clone()));
+        assert_eq!(intersection(&r, &Rect::new((20, 10), (30, 20))), Some(Rect::new((20, 10), (20, 20))));
+        assert_eq!(intersection(&r, &Rect::new((0, 10), (10, 20))), Some(Rect::new((10, 10), (10, 20))));
+        assert_eq!(intersection(&r, &Rect::new((10, 20), (20, 30))), Some(Rect::new((10, 20), (20, 20))));
+        assert_eq!(intersection(&r, &Rect::new((10, 0), (20, 10))), Some(Rect::new((10, 10), (20, 10))));
+        assert_eq!(intersection(&r, &Rect::new((0, 0), (10, 10))), Some(Rect::new((10, 10), (10, 10))));
+        assert_eq!(intersection(&r, &Rect::new((20, 20), (30, 30))), Some(Rect::new((20, 20), (20, 20))));
+    }
+
+    #[test]
+    fn intersection_outside() {
+        let r = Rect::new((10, 10), (20, 20));
+        assert_eq!(intersection(&r, &Rect::new((21, 10), (30, 20))), None);
+        assert_eq!(intersection(&r, &Rect::new((0, 10), (9, 20))), None);
+        assert_eq!(intersection(&r, &Rect::new((10, 21), (20, 30))), None);
+        assert_eq!(intersection(&r, &Rect::new((10, 0), (20, 9))), None);
+        assert_eq!(intersection(&r, &Rect::new((21, 21), (30, 30))), None);
+        assert_eq!(intersection(&r, &Rect::new((0, 0), (9, 9))), None);
+    }
+
+    #[test]
+    fn intersection_bounds() {
+        assert_eq!(intersection(&Rect::largest(), &Rect::largest()), Some(Rect::largest()));
+        assert_eq!(intersection(&Rect::largest(), &Rect::min()), Some(Rect::min()));
+        assert_eq!(intersection(&Rect::largest(), &Rect::max()), Some(Rect::max()));
+        assert_eq!(intersection(&Rect::largest(), &Rect::new((MIN + 1, MIN + 1), (MAX - 1, MAX - 1))), Some(Rect::new((MIN + 1, MIN + 1), (MAX - 1, MAX - 1))));
+        assert_eq!(intersection(&Rect::new((MIN, MIN), (MAX - 1, MAX - 1)), &Rect::new((MIN + 1, MIN + 1), (MAX, MAX))), Some(Rect::new((MIN + 1, MIN + 1), (MAX - 1, MAX - 1))));
+        assert_eq!(intersection(&Rect::min(), &Rect::max()), None);
+    }
+}
@@ -0,0 +1,63 @@
+use super::Rect;
+
+pub fn union_bounds_assign(r: &mut Rect, other: &Rect) {
+    r.min.x = r.min.x.min(other.min.x);
+    r.min.y = r.min.y.min(other.min.y);
+    r.max.x = r.max.x.max(other.max.x);
+    r.max.y = r.max.y.max(other.max.y);
+}
+
+pub fn union_bounds(a: &Rect, b: &Rect) -> Rect {
+    Rect::new((a.min.x.min(b.min.x), a.min.y.min(b.min.y)), (a.max.x.max(b.max.x), a.max.y.max(b.max.y)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{union_bounds, union_bounds_assign};
+    use crate::cartesian::{
+        d1::point::point_i32::{MAX, MIN},
+        d2::rect::rect_i32::Rect,
+    };
+
+    #[test]
+    fn test_union_bounds_assign() {
+        let mut r = Rect::new((10, 10), (20, 20));
+        union_bounds_assign(&mut r, &Rect::new((12, 12), (18, 18)));
+        assert_eq!(r, Rect::new((10, 10), (20, 20)));
+        union_bounds_assign(&mut r, &Rect::new((15, 15), (25, 25)));
+        assert_eq!(r, Rect::new((10, 10), (25, 25)));
+        union_bounds_assign(&mut r, &Rect::new((5, 5), (15, 15)));
+        assert_eq!(r, Rect::new((5, 5), (25, 25)));
+        union_bounds_assign(&mut r, &Rect::new((30, 0), (40, 1)));
+        assert_eq!(r, Rect::new((5, 0), (40, 25)));
+    }
+
+    #[test]
+    fn test_union_bounds() {
+        let r = Rect::new((10, 10), (20, 20));
+        assert_eq!(union_bounds(&r, &r), r);
+        assert_eq!(union_bounds(&r, &Rect::new((12, 12), (18, 18))), Rect::new((10, 10), (20, 20)));
+        assert_eq!(union_bounds(&r, &Rect::new((15, 15), (25, 25))), Rect::new((10, 10), (25, 25)));
+        assert_eq!(union_bounds(&r, &Rect::new((5, 5), (15, 15))), Rect::new((5, 5), (20, 20)));
+        assert_eq!(union_bounds(&r, &Rect::new((30, 0), (40, 1))), Rect::new((10, 0), (40, 20)));
+        assert_eq!(union_bounds(&Rect::new((30, 0), (40, 1)), &r), Rect::new((10, 0), (40, 20)));
+    }
+
+    #[test]
+    fn union_bounds_assign_bounds() {
+        let mut r = Rect::min();
+        union_bounds_assign(&mut r, &Rect::max());
+        assert_eq!(r, Rect::largest());
+
+        let mut r = Rect::new((MIN + 1, MIN + 1), (MIN + 1, MIN + 1));
+        union_bounds_assign(&mut r, &Rect::new((MAX - 1, MAX - 1), (MAX - 1, MAX - 1)));
+        assert_eq!(r, Rect::new((MIN + 1, MIN + 1), (MAX - 1, MAX - 1)));
+    }
+
+    #[test]
+    fn union_bounds_bounds() {
+        assert_eq!(union_bounds(&Rect::min(), &Rect::max()), Rect::largest());
+        assert_eq!(union_bounds(&Rect::largest(), &Rect::min()), Rect::largest());
+        assert_eq!(union_bounds(&Rect::new((MIN + 1, MIN + 1), (MIN + 1, MIN + 1)), &Rect::new((MAX - 1, MAX - 1), (MAX - 1, MAX - 1))), Rect::new((MIN + 1, MIN + 1), (MAX - 1, MAX - 1)));
+    }
+}
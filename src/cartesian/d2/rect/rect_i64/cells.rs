@@ -0,0 +1,405 @@
+use super::{Rect, delta_x, delta_y};
+use crate::cartesian::d2::point::point_i64::Point;
+
+fn at(min: &Point, a: u128, b: u128) -> Point {
+    Point::new(min.x.wrapping_add(a as i64), min.y.wrapping_add(b as i64))
+}
+
+fn ring(w: u128, h: u128, i: u128) -> (u128, u128) {
+    if h == 1 {
+        (i, 0)
+    } else if w == 1 {
+        (0, i)
+    } else if i < w {
+        (i, 0)
+    } else if i < w + h - 1 {
+        (w - 1, i + 1 - w)
+    } else if i < 2 * w + h - 2 {
+        (2 * w + h - 3 - i, h - 1)
+    } else {
+        (0, 2 * w + 2 * h - 4 - i)
+    }
+}
+
+fn perimeter(w: u128, h: u128) -> u128 {
+    if w == 1 || h == 1 { w * h } else { 2 * (w + h) - 4 }
+}
+
+fn lengths(r: &Rect) -> Option<(u128, u128)> {
+    (r.min.x <= r.max.x && r.min.y <= r.max.y).then(|| (u128::from(delta_x(r)) + 1, u128::from(delta_y(r)) + 1))
+}
+
+fn exact(remaining: Option<u128>) -> (usize, Option<usize>) {
+    match remaining.and_then(|remaining| usize::try_from(remaining).ok()) {
+        Some(remaining) => (remaining, Some(remaining)),
+        None => (usize::MAX, None),
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Cells {
+    min: Point,
+    len: (u128, u128),
+    column_major: bool,
+    front: (u128, u128),
+    back: (u128, u128),
+    done: bool,
+}
+
+impl Cells {
+    pub fn new(r: &Rect, column_major: bool) -> Self {
+        let Some((len_a, len_b)) = lengths(r) else {
+            return Cells { min: r.min.clone(), len: (0, 0), column_major, front: (0, 0), back: (0, 0), done: true };
+        };
+        let len = if column_major { (len_a, len_b) } else { (len_b, len_a) };
+        Cells { min: r.min.clone(), len, column_major, front: (0, 0), back: (len.0 - 1, len.1 - 1), done: false }
+    }
+
+    fn at(&self, (outer, inner): (u128, u128)) -> Point {
+        if self.column_major { at(&self.min, outer, inner) } else { at(&self.min, inner, outer) }
+    }
+}
+
+impl Iterator for Cells {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let p = self.at(self.front);
+        if self.front == self.back {
+            self.done = true;
+        } else if self.front.1 + 1 == self.len.1 {
+            self.front = (self.front.0 + 1, 0);
+        } else {
+            self.front.1 += 1;
+        }
+        Some(p)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        exact((self.back.0 - self.front.0).checked_mul(self.len.1).and_then(|rows| rows.checked_add(self.back.1 + 1)).map(|remaining| remaining - self.front.1))
+    }
+}
+
+impl DoubleEndedIterator for Cells {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let p = self.at(self.back);
+        if self.front == self.back {
+            self.done = true;
+        } else if self.back.1 == 0 {
+            self.back = (self.back.0 - 1, self.len.1 - 1);
+        } else {
+            self.back.1 -= 1;
+        }
+        Some(p)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Border {
+    min: Point,
+    len: (u128, u128),
+    front: u128,
+    back: u128,
+    done: bool,
+}
+
+impl Border {
+    pub fn new(r: &Rect) -> Self {
+        let Some(len) = lengths(r) else {
+            return Border { min: r.min.clone(), len: (0, 0), front: 0, back: 0, done: true };
+        };
+        Border { min: r.min.clone(), len, front: 0, back: perimeter(len.0, len.1) - 1, done: false }
+    }
+}
+
+impl Iterator for Border {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let (a, b) = ring(self.len.0, self.len.1, self.front);
+        if self.front == self.back {
+            self.done = true;
+        } else {
+            self.front += 1;
+        }
+        Some(at(&self.min, a, b))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        exact(Some(self.back - self.front + 1))
+    }
+}
+
+impl DoubleEndedIterator for Border {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let (a, b) = ring(self.len.0, self.len.1, self.back);
+        if self.front == self.back {
+            self.done = true;
+        } else {
+            self.back -= 1;
+        }
+        Some(at(&self.min, a, b))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Spiral {
+    min: Point,
+    len: (u128, u128),
+    outward: bool,
+    layer: u128,
+    layers: u128,
+    index: u128,
+    done: bool,
+}
+
+impl Spiral {
+    pub fn new(r: &Rect, outward: bool) -> Self {
+        let Some(len) = lengths(r) else {
+            return Spiral { min: r.min.clone(), len: (0, 0), outward, layer: 0, layers: 0, index: 0, done: true };
+        };
+        let layers = len.0.min(len.1).div_ceil(2);
+        let mut spiral = Spiral { min: r.min.clone(), len, outward, layer: 0, layers, index: 0, done: false };
+        if outward {
+            spiral.layer = layers - 1;
+            spiral.index = spiral.perimeter() - 1;
+        }
+        spiral
+    }
+
+    fn size(&self) -> (u128, u128) {
+        (self.len.0 - 2 * self.layer, self.len.1 - 2 * self.layer)
+    }
+
+    fn perimeter(&self) -> u128 {
+        let (w, h) = self.size();
+        perimeter(w, h)
+    }
+}
+
+impl Iterator for Spiral {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let (w, h) = self.size();
+        let (a, b) = ring(w, h, self.index);
+        let p = at(&self.min, a + self.layer, b + self.layer);
+        if self.outward {
+            if self.index > 0 {
+                self.index -= 1;
+            } else if self.layer > 0 {
+                self.layer -= 1;
+                self.index = self.perimeter() - 1;
+            } else {
+                self.done = true;
+            }
+        } else if self.index + 1 < perimeter(w, h) {
+            self.index += 1;
+        } else if self.layer + 1 < self.layers {
+            self.layer += 1;
+            self.index = 0;
+        } else {
+            self.done = true;
+        }
+        Some(p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cartesian::d1::point::point_i64::{MAX, MIN},
+        cartesian::d2::point::point_i64::Point,
+        cartesian::d2::rect::rect_i64::Rect,
+    };
+
+    #[test]
+    fn iter_row_major() {
+        assert_eq!(
+            Rect::new((1, 2), (3, 4)).iter_row_major().collect::<Vec<Point>>(),
+            [Point::new(1, 2), Point::new(2, 2), Point::new(3, 2), Point::new(1, 3), Point::new(2, 3), Point::new(3, 3), Point::new(1, 4), Point::new(2, 4), Point::new(3, 4)]
+        );
+        assert_eq!(
+            Rect::new((1, 2), (3, 4)).iter_row_major().rev().collect::<Vec<Point>>(),
+            [Point::new(3, 4), Point::new(2, 4), Point::new(1, 4), Point::new(3, 3), Point::new(2, 3), Point::new(1, 3), Point::new(3, 2), Point::new(2, 2), Point::new(1, 2)]
+        );
+        assert_eq!(Rect::new((5, 5), (5, 5)).iter_row_major().collect::<Vec<Point>>(), [Point::new(5, 5)]);
+        assert_eq!(Rect::new((0, 0), (1, 0)).iter_row_major().collect::<Vec<Point>>(), [Point::new(0, 0), Point::new(1, 0)]);
+    }
+
+    #[test]
+    fn iter_column_major() {
+        assert_eq!(
+            Rect::new((1, 2), (3, 4)).iter_column_major().collect::<Vec<Point>>(),
+            [Point::new(1, 2), Point::new(1, 3), Point::new(1, 4), Point::new(2, 2), Point::new(2, 3), Point::new(2, 4), Point::new(3, 2), Point::new(3, 3), Point::new(3, 4)]
+        );
+        assert_eq!(
+            Rect::new((1, 2), (3, 4)).iter_column_major().rev().collect::<Vec<Point>>(),
+            [Point::new(3, 4), Point::new(3, 3), Point::new(3, 2), Point::new(2, 4), Point::new(2, 3), Point::new(2, 2), Point::new(1, 4), Point::new(1, 3), Point::new(1, 2)]
+        );
+        assert_eq!(Rect::new((5, 5), (5, 5)).iter_column_major().collect::<Vec<Point>>(), [Point::new(5, 5)]);
+        assert_eq!(Rect::new((0, 0), (1, 0)).iter_column_major().collect::<Vec<Point>>(), [Point::new(0, 0), Point::new(1, 0)]);
+    }
+
+    #[test]
+    fn iter_cells_double_ended() {
+        let mut it = Rect::new((1, 2), (3, 4)).iter_row_major();
+        assert_eq!(it.size_hint(), (9, Some(9)));
+        assert_eq!(it.next(), Some(Point::new(1, 2)));
+        assert_eq!(it.next_back(), Some(Point::new(3, 4)));
+        assert_eq!(it.next_back(), Some(Point::new(2, 4)));
+        assert_eq!(it.size_hint(), (6, Some(6)));
+        assert_eq!(it.collect::<Vec<Point>>(), [Point::new(2, 2), Point::new(3, 2), Point::new(1, 3), Point::new(2, 3), Point::new(3, 3), Point::new(1, 4)]);
+        let mut it = Rect::new((0, 0), (1, 0)).iter_column_major();
+        assert_eq!(it.next_back(), Some(Point::new(1, 0)));
+        assert_eq!(it.next(), Some(Point::new(0, 0)));
+        assert_eq!(it.size_hint(), (0, Some(0)));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next_back(), None);
+    }
+
+    #[test]
+    fn iter_spiral() {
+        assert_eq!(
+            Rect::new((1, 2), (3, 4)).iter_spiral_inward().collect::<Vec<Point>>(),
+            [Point::new(1, 2), Point::new(2, 2), Point::new(3, 2), Point::new(3, 3), Point::new(3, 4), Point::new(2, 4), Point::new(1, 4), Point::new(1, 3), Point::new(2, 3)]
+        );
+        assert_eq!(
+            Rect::new((1, 2), (3, 4)).iter_spiral_outward().collect::<Vec<Point>>(),
+            [Point::new(2, 3), Point::new(1, 3), Point::new(1, 4), Point::new(2, 4), Point::new(3, 4), Point::new(3, 3), Point::new(3, 2), Point::new(2, 2), Point::new(1, 2)]
+        );
+        assert_eq!(
+            Rect::new((0, 0), (3, 2)).iter_spiral_inward().collect::<Vec<Point>>(),
+            [
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(2, 0),
+                Point::new(3, 0),
+                Point::new(3, 1),
+                Point::new(3, 2),
+                Point::new(2, 2),
+                Point::new(1, 2),
+                Point::new(0, 2),
+                Point::new(0, 1),
+                Point::new(1, 1),
+                Point::new(2, 1)
+            ]
+        );
+        assert_eq!(
+            Rect::new((0, 0), (2, 3)).iter_spiral_inward().collect::<Vec<Point>>(),
+            [
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(2, 0),
+                Point::new(2, 1),
+                Point::new(2, 2),
+                Point::new(2, 3),
+                Point::new(1, 3),
+                Point::new(0, 3),
+                Point::new(0, 2),
+                Point::new(0, 1),
+                Point::new(1, 1),
+                Point::new(1, 2)
+            ]
+        );
+        assert_eq!(
+            Rect::new((0, 0), (3, 3)).iter_spiral_outward().collect::<Vec<Point>>(),
+            [
+                Point::new(1, 2),
+                Point::new(2, 2),
+                Point::new(2, 1),
+                Point::new(1, 1),
+                Point::new(0, 1),
+                Point::new(0, 2),
+                Point::new(0, 3),
+                Point::new(1, 3),
+                Point::new(2, 3),
+                Point::new(3, 3),
+                Point::new(3, 2),
+                Point::new(3, 1),
+                Point::new(3, 0),
+                Point::new(2, 0),
+                Point::new(1, 0),
+                Point::new(0, 0)
+            ]
+        );
+        assert_eq!(Rect::new((0, 0), (0, 3)).iter_spiral_inward().collect::<Vec<Point>>(), [Point::new(0, 0), Point::new(0, 1), Point::new(0, 2), Point::new(0, 3)]);
+        assert_eq!(Rect::new((0, 0), (3, 0)).iter_spiral_outward().collect::<Vec<Point>>(), [Point::new(3, 0), Point::new(2, 0), Point::new(1, 0), Point::new(0, 0)]);
+        assert_eq!(Rect::new((5, 5), (5, 5)).iter_spiral_outward().collect::<Vec<Point>>(), [Point::new(5, 5)]);
+        assert_eq!(Rect::new((0, 0), (6, 4)).iter_spiral_inward().count(), 35);
+    }
+
+    #[test]
+    fn iter_border() {
+        assert_eq!(
+            Rect::new((1, 2), (3, 4)).iter_border().collect::<Vec<Point>>(),
+            [Point::new(1, 2), Point::new(2, 2), Point::new(3, 2), Point::new(3, 3), Point::new(3, 4), Point::new(2, 4), Point::new(1, 4), Point::new(1, 3)]
+        );
+        assert_eq!(
+            Rect::new((1, 2), (3, 4)).iter_border().rev().collect::<Vec<Point>>(),
+            [Point::new(1, 3), Point::new(1, 4), Point::new(2, 4), Point::new(3, 4), Point::new(3, 3), Point::new(3, 2), Point::new(2, 2), Point::new(1, 2)]
+        );
+        assert_eq!(
+            Rect::new((0, 0), (3, 2)).iter_border().collect::<Vec<Point>>(),
+            [Point::new(0, 0), Point::new(1, 0), Point::new(2, 0), Point::new(3, 0), Point::new(3, 1), Point::new(3, 2), Point::new(2, 2), Point::new(1, 2), Point::new(0, 2), Point::new(0, 1)]
+        );
+        assert_eq!(Rect::new((0, 0), (0, 3)).iter_border().collect::<Vec<Point>>(), [Point::new(0, 0), Point::new(0, 1), Point::new(0, 2), Point::new(0, 3)]);
+        assert_eq!(Rect::new((5, 5), (5, 5)).iter_border().collect::<Vec<Point>>(), [Point::new(5, 5)]);
+        let mut it = Rect::new((0, 0), (9, 9)).iter_border();
+        assert_eq!(it.size_hint(), (36, Some(36)));
+        assert_eq!(it.next_back(), Some(Point::new(0, 1)));
+        assert_eq!(it.size_hint(), (35, Some(35)));
+    }
+
+    #[test]
+    fn iter_cells_inverted() {
+        for r in [Rect::new((5, 0), (3, 0)), Rect::new((0, 5), (0, 3)), Rect::new((5, 5), (3, 3))] {
+            assert_eq!(r.iter_row_major().collect::<Vec<Point>>(), []);
+            assert_eq!(r.iter_row_major().rev().collect::<Vec<Point>>(), []);
+            assert_eq!(r.iter_column_major().collect::<Vec<Point>>(), []);
+            assert_eq!(r.iter_column_major().rev().collect::<Vec<Point>>(), []);
+            assert_eq!(r.iter_border().collect::<Vec<Point>>(), []);
+            assert_eq!(r.iter_border().rev().collect::<Vec<Point>>(), []);
+            assert_eq!(r.iter_spiral_inward().collect::<Vec<Point>>(), []);
+            assert_eq!(r.iter_spiral_outward().collect::<Vec<Point>>(), []);
+            assert_eq!(r.iter_row_major().size_hint(), (0, Some(0)));
+            assert_eq!(r.iter_border().size_hint(), (0, Some(0)));
+        }
+    }
+
+    #[test]
+    fn iter_cells_bounds() {
+        assert_eq!(Rect::largest().iter_row_major().take(2).collect::<Vec<Point>>(), [Point::new(MIN, MIN), Point::new(MIN + 1, MIN)]);
+        assert_eq!(Rect::largest().iter_row_major().rev().take(2).collect::<Vec<Point>>(), [Point::new(MAX, MAX), Point::new(MAX - 1, MAX)]);
+        assert_eq!(Rect::largest().iter_column_major().take(2).collect::<Vec<Point>>(), [Point::new(MIN, MIN), Point::new(MIN, MIN + 1)]);
+        assert_eq!(Rect::largest().iter_column_major().rev().take(2).collect::<Vec<Point>>(), [Point::new(MAX, MAX), Point::new(MAX, MAX - 1)]);
+        assert_eq!(Rect::largest().iter_border().take(2).collect::<Vec<Point>>(), [Point::new(MIN, MIN), Point::new(MIN + 1, MIN)]);
+        assert_eq!(Rect::largest().iter_border().rev().take(2).collect::<Vec<Point>>(), [Point::new(MIN, MIN + 1), Point::new(MIN, MIN + 2)]);
+        assert_eq!(Rect::largest().iter_spiral_inward().take(2).collect::<Vec<Point>>(), [Point::new(MIN, MIN), Point::new(MIN + 1, MIN)]);
+        assert_eq!(Rect::largest().iter_spiral_outward().take(2).collect::<Vec<Point>>(), [Point::new(-1, 0), Point::new(0, 0)]);
+        assert_eq!(Rect::largest().iter_row_major().size_hint(), (usize::MAX, None));
+        assert_eq!(Rect::largest().iter_border().size_hint(), (usize::MAX, None));
+        assert_eq!(Rect::max().iter_spiral_inward().collect::<Vec<Point>>(), [Point::new(MAX, MAX)]);
+    }
+}
@@ -0,0 +1,117 @@
+use super::{Rect, saturating_translate};
+use crate::cartesian::d2::point::point_i8::Point;
+
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct Sweep {
+    pub time: (u8, u8),
+    pub normal: Point,
+    pub position: Rect,
+}
+
+type Fraction = (i32, i32);
+
+fn axis(min: i8, max: i8, velocity: i8, target_min: i8, target_max: i8) -> Option<(Fraction, Fraction)> {
+    let speed = i32::from(velocity.unsigned_abs());
+    match velocity.signum() {
+        1 => Some(((i32::from(target_min) - i32::from(max), speed), (i32::from(target_max) - i32::from(min), speed))),
+        -1 => Some(((i32::from(min) - i32::from(target_max), speed), (i32::from(max) - i32::from(target_min), speed))),
+        _ => {
+            if max > target_min && min < target_max {
+                Some(((-1, 1), (2, 1)))
+            } else {
+                None
+            }
+        }
+    }
+}
+
+fn less(a: Fraction, b: Fraction) -> bool {
+    a.0 * b.1 < b.0 * a.1
+}
+
+pub fn sweep(moving: &Rect, velocity: &Point, target: &Rect) -> Option<Sweep> {
+    let (entry_x, exit_x) = axis(moving.min.x, moving.max.x, velocity.x, target.min.x, target.max.x)?;
+    let (entry_y, exit_y) = axis(moving.min.y, moving.max.y, velocity.y, target.min.y, target.max.y)?;
+    let blocked_x = !less(entry_x, entry_y);
+    let entry = if blocked_x { entry_x } else { entry_y };
+    let exit = if less(exit_y, exit_x) { exit_y } else { exit_x };
+    if entry.0 < 0 || entry.0 > entry.1 || !less(entry, exit) {
+        return None;
+    }
+    let time = (entry.0 as u8, entry.1 as u8);
+    if blocked_x {
+        let offset = (i32::from(velocity.x.signum()) * entry.0) as i8;
+        let position = saturating_translate(moving, &Point::new(offset, velocity.y));
+        Some(Sweep { time, normal: Point::new(-velocity.x.signum(), 0), position })
+    } else {
+        let offset = (i32::from(velocity.y.signum()) * entry.0) as i8;
+        let position = saturating_translate(moving, &Point::new(velocity.x, offset));
+        Some(Sweep { time, normal: Point::new(0, -velocity.y.signum()), position })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Sweep, sweep};
+    use crate::cartesian::{
+        d1::point::point_i8::{MAX, MIN},
+        d2::{point::point_i8::Point, rect::rect_i8::Rect},
+    };
+
+    #[test]
+    fn test_sweep() {
+        let a = Rect::new((0, 0), (2, 2));
+        let b = Rect::new((5, 0), (7, 2));
+        assert_eq!(sweep(&a, &Point::new(5, 0), &b), Some(Sweep { time: (3, 5), normal: Point::new(-1, 0), position: Rect::new((3, 0), (5, 2)) }));
+        assert_eq!(sweep(&a, &Point::new(3, 0), &b), Some(Sweep { time: (3, 3), normal: Point::new(-1, 0), position: Rect::new((3, 0), (5, 2)) }));
+        assert_eq!(sweep(&b, &Point::new(-6, 0), &a), Some(Sweep { time: (3, 6), normal: Point::new(1, 0), position: Rect::new((2, 0), (4, 2)) }));
+        assert_eq!(sweep(&Rect::new((0, 4), (2, 6)), &Point::new(0, -4), &Rect::new((0, 0), (2, 2))), Some(Sweep { time: (2, 4), normal: Point::new(0, 1), position: Rect::new((0, 2), (2, 4)) }));
+        assert_eq!(sweep(&Rect::new((0, 0), (2, 2)), &Point::new(0, 4), &Rect::new((0, 4), (2, 6))), Some(Sweep { time: (2, 4), normal: Point::new(0, -1), position: Rect::new((0, 2), (2, 4)) }));
+    }
+
+    #[test]
+    fn sweep_slide() {
+        let a = Rect::new((0, 0), (2, 2));
+        assert_eq!(sweep(&a, &Point::new(6, 1), &Rect::new((5, 0), (7, 2))), Some(Sweep { time: (3, 6), normal: Point::new(-1, 0), position: Rect::new((3, 1), (5, 3)) }));
+        assert_eq!(sweep(&Rect::new((0, 4), (2, 6)), &Point::new(1, -4), &Rect::new((-10, 0), (10, 2))), Some(Sweep { time: (2, 4), normal: Point::new(0, 1), position: Rect::new((1, 2), (3, 4)) }));
+        assert_eq!(sweep(&Rect::new((0, 2), (2, 4)), &Point::new(3, -1), &Rect::new((-10, 0), (10, 2))), Some(Sweep { time: (0, 1), normal: Point::new(0, 1), position: Rect::new((3, 2), (5, 4)) }));
+    }
+
+    #[test]
+    fn sweep_corner() {
+        assert_eq!(sweep(&Rect::new((0, 0), (2, 2)), &Point::new(2, 2), &Rect::new((4, 4), (6, 6))), Some(Sweep { time: (2, 2), normal: Point::new(-1, 0), position: Rect::new((2, 2), (4, 4)) }));
+        assert_eq!(sweep(&Rect::new((0, 0), (2, 2)), &Point::new(2, 4), &Rect::new((4, 4), (6, 6))), Some(Sweep { time: (2, 2), normal: Point::new(-1, 0), position: Rect::new((2, 4), (4, 6)) }));
+        assert_eq!(sweep(&Rect::new((0, 0), (2, 2)), &Point::new(6, 2), &Rect::new((4, 4), (6, 6))), None);
+    }
+
+    #[test]
+    fn sweep_miss() {
+        let a = Rect::new((0, 0), (2, 2));
+        let b = Rect::new((5, 0), (7, 2));
+        assert_eq!(sweep(&a, &Point::new(2, 0), &b), None);
+        assert_eq!(sweep(&a, &Point::new(-5, 0), &b), None);
+        assert_eq!(sweep(&a, &Point::new(5, 4), &b), None);
+        assert_eq!(sweep(&a, &Point::new(0, 5), &b), None);
+        assert_eq!(sweep(&a, &Point::new(0, 0), &b), None);
+        assert_eq!(sweep(&Rect::new((0, 2), (2, 4)), &Point::new(3, 0), &Rect::new((-10, 0), (10, 2))), None);
+        assert_eq!(sweep(&Rect::new((0, 2), (2, 4)), &Point::new(3, 1), &Rect::new((-10, 0), (10, 2))), None);
+    }
+
+    #[test]
+    fn sweep_overlapping() {
+        assert_eq!(sweep(&Rect::new((0, 0), (2, 2)), &Point::new(1, 0), &Rect::new((1, 1), (3, 3))), None);
+        assert_eq!(sweep(&Rect::new((0, 0), (2, 2)), &Point::new(0, 0), &Rect::new((1, 1), (3, 3))), None);
+    }
+
+    #[test]
+    fn sweep_bounds() {
+        let left = Rect::new((MIN, 0), (-1, 1));
+        let right = Rect::new((1, 0), (MAX, 1));
+        assert_eq!(sweep(&left, &Point::new(MAX, 0), &right), Some(Sweep { time: (2, MAX as u8), normal: Point::new(-1, 0), position: Rect::new((MIN + 2, 0), (1, 1)) }));
+        assert_eq!(sweep(&right, &Point::new(MIN, 0), &left), Some(Sweep { time: (2, MIN.unsigned_abs()), normal: Point::new(1, 0), position: Rect::new((-1, 0), (MAX - 2, 1)) }));
+        assert_eq!(
+            sweep(&Rect::new((0, MAX - 3), (2, MAX - 2)), &Point::new(30, 10), &Rect::new((5, MAX - 10), (7, MAX))),
+            Some(Sweep { time: (3, 30), normal: Point::new(-1, 0), position: Rect::new((3, MAX - 1), (5, MAX)) })
+        );
+    }
+}
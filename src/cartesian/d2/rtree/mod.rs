@@ -0,0 +1,12 @@
+pub mod rtree_u16;
+pub mod rtree_u32;
+pub mod rtree_u64;
+pub mod rtree_u8;
+
+pub mod rtree_i16;
+pub mod rtree_i32;
+pub mod rtree_i64;
+pub mod rtree_i8;
+
+pub mod rtree_f32;
+pub mod rtree_f64;
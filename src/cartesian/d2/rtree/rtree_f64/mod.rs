@@ -0,0 +1,128 @@
+use crate::cartesian::d2::rect::rect_f64::{Rect, union_bounds};
+
+mod nearest;
+mod query;
+
+pub use self::nearest::nearest;
+pub use self::query::{query_point, query_rect};
+
+const MAX_ENTRIES: usize = 16;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum Node<P> {
+    Leaf(Vec<(Rect, P)>),
+    Branch(Vec<(Rect, Node<P>)>),
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct RTree<P> {
+    pub root: Node<P>,
+}
+
+fn center_x(r: &Rect) -> f64 {
+    r.min.x + r.max.x
+}
+
+fn center_y(r: &Rect) -> f64 {
+    r.min.y + r.max.y
+}
+
+fn bounds<T>(group: &[(Rect, T)]) -> Rect {
+    group[1..].iter().fold(group[0].0.clone(), |acc, (r, _)| union_bounds(&acc, r))
+}
+
+fn pack<T>(mut items: Vec<(Rect, T)>) -> Vec<(Rect, Vec<(Rect, T)>)> {
+    let leaves = items.len().div_ceil(MAX_ENTRIES);
+    let slice_len = (leaves as f64).sqrt().ceil() as usize * MAX_ENTRIES;
+    items.sort_by(|a, b| center_x(&a.0).total_cmp(&center_x(&b.0)));
+    let mut groups = Vec::new();
+    let mut items = items.into_iter();
+    loop {
+        let mut slice: Vec<(Rect, T)> = items.by_ref().take(slice_len).collect();
+        if slice.is_empty() {
+            return groups;
+        }
+        slice.sort_by(|a, b| center_y(&a.0).total_cmp(&center_y(&b.0)));
+        let mut slice = slice.into_iter();
+        loop {
+            let group: Vec<(Rect, T)> = slice.by_ref().take(MAX_ENTRIES).collect();
+            if group.is_empty() {
+                break;
+            }
+            groups.push((bounds(&group), group));
+        }
+    }
+}
+
+pub fn bulk_load<P>(entries: Vec<(Rect, P)>) -> RTree<P> {
+    if entries.len() <= MAX_ENTRIES {
+        return RTree { root: Node::Leaf(entries) };
+    }
+    let mut nodes: Vec<(Rect, Node<P>)> = pack(entries).into_iter().map(|(r, group)| (r, Node::Leaf(group))).collect();
+    while nodes.len() > MAX_ENTRIES {
+        nodes = pack(nodes).into_iter().map(|(r, group)| (r, Node::Branch(group))).collect();
+    }
+    RTree { root: Node::Branch(nodes) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Node, RTree, bulk_load};
+    use crate::cartesian::d2::rect::rect_f64::Rect;
+
+    fn grid() -> RTree<u8> {
+        let mut entries = Vec::new();
+        for i in 0..5u8 {
+            for j in 0..4u8 {
+                let (x, y) = (10.0 * i as f64, 10.0 * j as f64);
+                entries.push((Rect::new((x, y), (x + 5.0, y + 5.0)), i * 4 + j));
+            }
+        }
+        bulk_load(entries)
+    }
+
+    #[test]
+    fn test_bulk_load() {
+        assert_eq!(bulk_load::<u8>(Vec::new()), RTree { root: Node::Leaf(Vec::new()) });
+        assert_eq!(
+            bulk_load(vec![(Rect::new((40.0, 40.0), (50.0, 50.0)), 1), (Rect::new((0.0, 0.0), (10.0, 10.0)), 2), (Rect::new((20.0, 0.0), (30.0, 10.0)), 3)]),
+            RTree { root: Node::Leaf(vec![(Rect::new((40.0, 40.0), (50.0, 50.0)), 1), (Rect::new((0.0, 0.0), (10.0, 10.0)), 2), (Rect::new((20.0, 0.0), (30.0, 10.0)), 3)]) }
+        );
+    }
+
+    #[test]
+    fn bulk_load_branch() {
+        let Node::Branch(children) = grid().root else { panic!() };
+        assert_eq!(children.len(), 2);
+        assert_eq!(children[0].0, Rect::new((0.0, 0.0), (45.0, 35.0)));
+        assert_eq!(children[1].0, Rect::new((10.0, 30.0), (45.0, 35.0)));
+        let Node::Leaf(first) = &children[0].1 else { panic!() };
+        let Node::Leaf(second) = &children[1].1 else { panic!() };
+        assert_eq!(first.iter().map(|e| e.1).collect::<Vec<_>>(), vec![0, 4, 8, 12, 16, 1, 5, 9, 13, 17, 2, 6, 10, 14, 18, 3]);
+        assert_eq!(second.iter().map(|e| e.1).collect::<Vec<_>>(), vec![7, 11, 15, 19]);
+    }
+
+    #[test]
+    fn bulk_load_levels() {
+        let mut entries = Vec::new();
+        for i in 0..20u8 {
+            for j in 0..20u8 {
+                entries.push((Rect::new((i as f64, j as f64), (i as f64, j as f64)), (i, j)));
+            }
+        }
+        let Node::Branch(children) = bulk_load(entries).root else { panic!() };
+        assert_eq!(children.len(), 2);
+        assert_eq!(children[0].0, Rect::new((0.0, 0.0), (19.0, 15.0)));
+        assert_eq!(children[1].0, Rect::new((0.0, 12.0), (19.0, 19.0)));
+        assert!(children.iter().all(|(_, child)| matches!(child, Node::Branch(_))));
+    }
+
+    #[test]
+    fn bulk_load_bounds() {
+        let mut entries = vec![(Rect::largest(), 0u8); 16];
+        entries.push((Rect::min(), 1));
+        entries.push((Rect::max(), 2));
+        let Node::Branch(children) = bulk_load(entries).root else { panic!() };
+        assert_eq!(children.iter().map(|(r, _)| r.clone()).collect::<Vec<_>>(), vec![Rect::largest(), Rect::largest()]);
+    }
+}
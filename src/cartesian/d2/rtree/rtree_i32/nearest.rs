@@ -0,0 +1,113 @@
+use super::{Node, RTree};
+use crate::cartesian::d2::{
+    point::point_i32::{Point, distance},
+    rect::rect_i32::Rect,
+};
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+enum Item<'a, P> {
+    Node(&'a Node<P>),
+    Entry(&'a Rect, &'a P),
+}
+
+struct Candidate<'a, P> {
+    distance: f64,
+    order: usize,
+    item: Item<'a, P>,
+}
+
+impl<P> PartialEq for Candidate<'_, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<P> Eq for Candidate<'_, P> {}
+
+impl<P> PartialOrd for Candidate<'_, P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P> Ord for Candidate<'_, P> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.distance.total_cmp(&self.distance).then_with(|| other.order.cmp(&self.order))
+    }
+}
+
+fn distance_to_rect(p: &Point, r: &Rect) -> f64 {
+    distance(p, &Point::new(p.x.clamp(r.min.x, r.max.x), p.y.clamp(r.min.y, r.max.y)))
+}
+
+pub fn nearest<'a, P>(t: &'a RTree<P>, p: &Point, k: usize) -> Vec<(&'a Rect, &'a P)> {
+    let mut result = Vec::new();
+    let mut heap = BinaryHeap::from([Candidate { distance: 0.0, order: 0, item: Item::Node(&t.root) }]);
+    let mut order = 1;
+    while result.len() < k {
+        let Some(candidate) = heap.pop() else { break };
+        match candidate.item {
+            Item::Entry(r, payload) => result.push((r, payload)),
+            Item::Node(Node::Leaf(entries)) => {
+                for (r, payload) in entries {
+                    heap.push(Candidate { distance: distance_to_rect(p, r), order, item: Item::Entry(r, payload) });
+                    order += 1;
+                }
+            }
+            Item::Node(Node::Branch(children)) => {
+                for (r, child) in children {
+                    heap.push(Candidate { distance: distance_to_rect(p, r), order, item: Item::Node(child) });
+                    order += 1;
+                }
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::nearest;
+    use crate::cartesian::d2::{
+        point::point_i32::Point,
+        rect::rect_i32::Rect,
+        rtree::rtree_i32::{RTree, bulk_load},
+    };
+
+    fn payloads(result: Vec<(&Rect, &u8)>) -> Vec<u8> {
+        result.into_iter().map(|(_, payload)| *payload).collect()
+    }
+
+    fn grid() -> RTree<u8> {
+        let mut entries = Vec::new();
+        for i in 0..5u8 {
+            for j in 0..4u8 {
+                let (x, y) = (10 * i as i32, 10 * j as i32);
+                entries.push((Rect::new((x, y), (x + 5, y + 5)), i * 4 + j));
+            }
+        }
+        bulk_load(entries)
+    }
+
+    #[test]
+    fn test_nearest() {
+        let t = grid();
+        assert_eq!(nearest(&t, &Point::new(0, 2), 1), vec![(&Rect::new((0, 0), (5, 5)), &0)]);
+        assert_eq!(payloads(nearest(&t, &Point::new(0, 2), 3)), vec![0, 1, 4]);
+        assert_eq!(payloads(nearest(&t, &Point::new(100, 2), 2)), vec![16, 17]);
+        assert_eq!(payloads(nearest(&t, &Point::new(23, 12), 2)), vec![9, 8]);
+        assert_eq!(payloads(nearest(&t, &Point::new(0, 2), 0)), vec![]);
+        assert_eq!(nearest(&t, &Point::new(0, 2), 100).len(), 20);
+        assert_eq!(payloads(nearest(&bulk_load(Vec::new()), &Point::new(0, 2), 1)), vec![]);
+    }
+
+    #[test]
+    fn nearest_bounds() {
+        let mut entries = vec![(Rect::max(), 0u8); 16];
+        entries.push((Rect::min(), 1));
+        let t = bulk_load(entries);
+        assert_eq!(payloads(nearest(&t, &Point::min(), 2)), vec![1, 0]);
+        assert_eq!(payloads(nearest(&t, &Point::max(), 1)), vec![0]);
+        assert_eq!(payloads(nearest(&t, &Point::new(Point::min().x, Point::max().y / 2), 1)), vec![1]);
+    }
+}
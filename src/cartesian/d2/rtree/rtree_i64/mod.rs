@@ -0,0 +1,128 @@
+use crate::cartesian::d2::rect::rect_i64::{Rect, union_bounds};
+
+mod nearest;
+mod query;
+
+pub use self::nearest::nearest;
+pub use self::query::{query_point, query_rect};
+
+const MAX_ENTRIES: usize = 16;
+
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub enum Node<P> {
+    Leaf(Vec<(Rect, P)>),
+    Branch(Vec<(Rect, Node<P>)>),
+}
+
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct RTree<P> {
+    pub root: Node<P>,
+}
+
+fn center_x(r: &Rect) -> i128 {
+    i128::from(r.min.x) + i128::from(r.max.x)
+}
+
+fn center_y(r: &Rect) -> i128 {
+    i128::from(r.min.y) + i128::from(r.max.y)
+}
+
+fn bounds<T>(group: &[(Rect, T)]) -> Rect {
+    group[1..].iter().fold(group[0].0.clone(), |acc, (r, _)| union_bounds(&acc, r))
+}
+
+fn pack<T>(mut items: Vec<(Rect, T)>) -> Vec<(Rect, Vec<(Rect, T)>)> {
+    let leaves = items.len().div_ceil(MAX_ENTRIES);
+    let slice_len = (leaves as f64).sqrt().ceil() as usize * MAX_ENTRIES;
+    items.sort_by_key(|item| center_x(&item.0));
+    let mut groups = Vec::new();
+    let mut items = items.into_iter();
+    loop {
+        let mut slice: Vec<(Rect, T)> = items.by_ref().take(slice_len).collect();
+        if slice.is_empty() {
+            return groups;
+        }
+        slice.sort_by_key(|item| center_y(&item.0));
+        let mut slice = slice.into_iter();
+        loop {
+            let group: Vec<(Rect, T)> = slice.by_ref().take(MAX_ENTRIES).collect();
+            if group.is_empty() {
+                break;
+            }
+            groups.push((bounds(&group), group));
+        }
+    }
+}
+
+pub fn bulk_load<P>(entries: Vec<(Rect, P)>) -> RTree<P> {
+    if entries.len() <= MAX_ENTRIES {
+        return RTree { root: Node::Leaf(entries) };
+    }
+    let mut nodes: Vec<(Rect, Node<P>)> = pack(entries).into_iter().map(|(r, group)| (r, Node::Leaf(group))).collect();
+    while nodes.len() > MAX_ENTRIES {
+        nodes = pack(nodes).into_iter().map(|(r, group)| (r, Node::Branch(group))).collect();
+    }
+    RTree { root: Node::Branch(nodes) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Node, RTree, bulk_load};
+    use crate::cartesian::d2::rect::rect_i64::Rect;
+
+    fn grid() -> RTree<u8> {
+        let mut entries = Vec::new();
+        for i in 0..5u8 {
+            for j in 0..4u8 {
+                let (x, y) = (10 * i as i64, 10 * j as i64);
+                entries.push((Rect::new((x, y), (x + 5, y + 5)), i * 4 + j));
+            }
+        }
+        bulk_load(entries)
+    }
+
+    #[test]
+    fn test_bulk_load() {
+        assert_eq!(bulk_load::<u8>(Vec::new()), RTree { root: Node::Leaf(Vec::new()) });
+        assert_eq!(
+            bulk_load(vec![(Rect::new((40, 40), (50, 50)), 1), (Rect::new((0, 0), (10, 10)), 2), (Rect::new((20, 0), (30, 10)), 3)]),
+            RTree { root: Node::Leaf(vec![(Rect::new((40, 40), (50, 50)), 1), (Rect::new((0, 0), (10, 10)), 2), (Rect::new((20, 0), (30, 10)), 3)]) }
+        );
+    }
+
+    #[test]
+    fn bulk_load_branch() {
+        let Node::Branch(children) = grid().root else { panic!() };
+        assert_eq!(children.len(), 2);
+        assert_eq!(children[0].0, Rect::new((0, 0), (45, 35)));
+        assert_eq!(children[1].0, Rect::new((10, 30), (45, 35)));
+        let Node::Leaf(first) = &children[0].1 else { panic!() };
+        let Node::Leaf(second) = &children[1].1 else { panic!() };
+        assert_eq!(first.iter().map(|e| e.1).collect::<Vec<_>>(), vec![0, 4, 8, 12, 16, 1, 5, 9, 13, 17, 2, 6, 10, 14, 18, 3]);
+        assert_eq!(second.iter().map(|e| e.1).collect::<Vec<_>>(), vec![7, 11, 15, 19]);
+    }
+
+    #[test]
+    fn bulk_load_levels() {
+        let mut entries = Vec::new();
+        for i in 0..20u8 {
+            for j in 0..20u8 {
+                entries.push((Rect::new((i as i64, j as i64), (i as i64, j as i64)), (i, j)));
+            }
+        }
+        let Node::Branch(children) = bulk_load(entries).root else { panic!() };
+        assert_eq!(children.len(), 2);
+        assert_eq!(children[0].0, Rect::new((0, 0), (19, 15)));
+        assert_eq!(children[1].0, Rect::new((0, 12), (19, 19)));
+        assert!(children.iter().all(|(_, child)| matches!(child, Node::Branch(_))));
+    }
+
+    #[test]
+    fn bulk_load_bounds() {
+        let mut entries = vec![(Rect::largest(), 0u8); 16];
+        entries.push((Rect::min(), 1));
+        entries.push((Rect::max(), 2));
+        let Node::Branch(children) = bulk_load(entries).root else { panic!() };
+        assert_eq!(children.iter().map(|(r, _)| r.clone()).collect::<Vec<_>>(), vec![Rect::largest(), Rect::largest()]);
+    }
+}
@@ -0,0 +1,84 @@
+use super::{Node, RTree};
+use crate::cartesian::d2::{
+    point::point_i64::Point,
+    rect::rect_i64::{Rect, contains_point, intersects},
+};
+
+fn query<P>(t: &RTree<P>, entry_matches: impl Fn(&Rect) -> bool) -> Vec<(&Rect, &P)> {
+    let mut result = Vec::new();
+    let mut stack = vec![&t.root];
+    while let Some(node) = stack.pop() {
+        match node {
+            Node::Leaf(entries) => result.extend(entries.iter().filter(|(r, _)| entry_matches(r)).map(|(r, payload)| (r, payload))),
+            Node::Branch(children) => stack.extend(children.iter().rev().filter(|(r, _)| entry_matches(r)).map(|(_, child)| child)),
+        }
+    }
+    result
+}
+
+pub fn query_rect<'a, P>(t: &'a RTree<P>, r: &Rect) -> Vec<(&'a Rect, &'a P)> {
+    query(t, |entry| intersects(entry, r))
+}
+
+pub fn query_point<'a, P>(t: &'a RTree<P>, p: &Point) -> Vec<(&'a Rect, &'a P)> {
+    query(t, |entry| contains_point(entry, p))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{query_point, query_rect};
+    use crate::cartesian::d2::{
+        point::point_i64::Point,
+        rect::rect_i64::Rect,
+        rtree::rtree_i64::{RTree, bulk_load},
+    };
+
+    fn payloads(result: Vec<(&Rect, &u8)>) -> Vec<u8> {
+        let mut payloads: Vec<u8> = result.into_iter().map(|(_, payload)| *payload).collect();
+        payloads.sort();
+        payloads
+    }
+
+    fn grid() -> RTree<u8> {
+        let mut entries = Vec::new();
+        for i in 0..5u8 {
+            for j in 0..4u8 {
+                let (x, y) = (10 * i as i64, 10 * j as i64);
+                entries.push((Rect::new((x, y), (x + 5, y + 5)), i * 4 + j));
+            }
+        }
+        bulk_load(entries)
+    }
+
+    #[test]
+    fn test_query_rect() {
+        let t = grid();
+        assert_eq!(payloads(query_rect(&t, &Rect::new((0, 0), (100, 100)))), (0..20).collect::<Vec<_>>());
+        assert_eq!(payloads(query_rect(&t, &Rect::new((0, 0), (12, 12)))), vec![0, 1, 4, 5]);
+        assert_eq!(payloads(query_rect(&t, &Rect::new((15, 15), (20, 20)))), vec![5, 6, 9, 10]);
+        assert_eq!(payloads(query_rect(&t, &Rect::new((41, 31), (42, 32)))), vec![19]);
+        assert_eq!(payloads(query_rect(&t, &Rect::new((6, 0), (9, 100)))), vec![]);
+        assert_eq!(payloads(query_rect(&bulk_load(Vec::new()), &Rect::new((0, 0), (100, 100)))), vec![]);
+    }
+
+    #[test]
+    fn test_query_point() {
+        let t = grid();
+        assert_eq!(payloads(query_point(&t, &Point::new(0, 0))), vec![0]);
+        assert_eq!(payloads(query_point(&t, &Point::new(45, 35))), vec![19]);
+        assert_eq!(payloads(query_point(&t, &Point::new(22, 13))), vec![9]);
+        assert_eq!(payloads(query_point(&t, &Point::new(7, 7))), vec![]);
+        assert_eq!(payloads(query_point(&t, &Point::new(50, 0))), vec![]);
+    }
+
+    #[test]
+    fn query_bounds() {
+        let mut entries = vec![(Rect::min(), 0u8); 16];
+        entries.push((Rect::max(), 1));
+        entries.push((Rect::largest(), 2));
+        let t = bulk_load(entries);
+        assert_eq!(payloads(query_point(&t, &Point::max())), vec![1, 2]);
+        assert_eq!(payloads(query_rect(&t, &Rect::max())), vec![1, 2]);
+        assert_eq!(payloads(query_rect(&t, &Rect::largest())).len(), 18);
+    }
+}
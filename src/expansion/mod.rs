@@ -0,0 +1,153 @@
+use std::cmp::Ordering;
+
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x = a + b;
+    let b_virtual = x - a;
+    let a_virtual = x - b_virtual;
+    (x, (a - a_virtual) + (b - b_virtual))
+}
+
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let x = a * b;
+    (x, a.mul_add(b, -x))
+}
+
+pub fn from_f64(a: f64) -> Vec<f64> {
+    vec![a]
+}
+
+pub fn from_i128(a: i128) -> Vec<f64> {
+    let mut result = vec![((a >> 96) as f64) * 2.0_f64.powi(96)];
+    for shift in [64, 32, 0] {
+        result = grow(&result, ((a >> shift) & 0xFFFF_FFFF) as f64 * 2.0_f64.powi(shift));
+    }
+    result
+}
+
+pub fn diff(a: f64, b: f64) -> Vec<f64> {
+    let (x, y) = two_sum(a, -b);
+    grow(&[y], x)
+}
+
+pub fn grow(e: &[f64], b: f64) -> Vec<f64> {
+    let mut h = Vec::with_capacity(e.len() + 1);
+    let mut q = b;
+    for &component in e {
+        let (sum, err) = two_sum(q, component);
+        q = sum;
+        if err != 0.0 {
+            h.push(err);
+        }
+    }
+    if q != 0.0 || h.is_empty() {
+        h.push(q);
+    }
+    h
+}
+
+pub fn sum(e: &[f64], f: &[f64]) -> Vec<f64> {
+    f.iter().fold(e.to_vec(), |acc, &component| grow(&acc, component))
+}
+
+pub fn negate(e: &[f64]) -> Vec<f64> {
+    e.iter().map(|component| -component).collect()
+}
+
+pub fn product(e: &[f64], f: &[f64]) -> Vec<f64> {
+    let mut result = vec![0.0];
+    for &a in e {
+        for &b in f {
+            let (x, y) = two_product(a, b);
+            result = grow(&grow(&result, y), x);
+        }
+    }
+    result
+}
+
+pub fn sign(e: &[f64]) -> Ordering {
+    match e.iter().rev().find(|component| **component != 0.0) {
+        Some(component) if *component > 0.0 => Ordering::Greater,
+        Some(_) => Ordering::Less,
+        None => Ordering::Equal,
+    }
+}
+
+pub fn orient2d(acx: &[f64], acy: &[f64], bcx: &[f64], bcy: &[f64]) -> Ordering {
+    sign(&sum(&product(acx, bcy), &negate(&product(acy, bcx))))
+}
+
+pub fn incircle(adx: &[f64], ady: &[f64], bdx: &[f64], bdy: &[f64], cdx: &[f64], cdy: &[f64]) -> Ordering {
+    let a_lift = sum(&product(adx, adx), &product(ady, ady));
+    let b_lift = sum(&product(bdx, bdx), &product(bdy, bdy));
+    let c_lift = sum(&product(cdx, cdx), &product(cdy, cdy));
+    let bc = sum(&product(bdx, cdy), &negate(&product(cdx, bdy)));
+    let ca = sum(&product(cdx, ady), &negate(&product(adx, cdy)));
+    let ab = sum(&product(adx, bdy), &negate(&product(bdx, ady)));
+    sign(&sum(&sum(&product(&a_lift, &bc), &product(&b_lift, &ca)), &product(&c_lift, &ab)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diff, from_f64, from_i128, grow, incircle, negate, orient2d, product, sign, sum};
+    use std::cmp::Ordering;
+
+    #[test]
+    fn test_from_i128() {
+        assert_eq!(from_i128(0), [0.0]);
+        assert_eq!(from_i128(5), [5.0]);
+        assert_eq!(from_i128(-5), [-5.0]);
+        assert_eq!(sign(&sum(&from_i128(i128::from(u64::MAX) + 1), &from_f64(-(2.0_f64.powi(64))))), Ordering::Equal);
+        assert_eq!(sign(&sum(&from_i128(i128::from(u64::MAX)), &from_f64(-(2.0_f64.powi(64))))), Ordering::Less);
+        assert_eq!(sign(&sum(&from_i128(i128::from(i64::MIN) * 2 - 1), &from_f64(2.0_f64.powi(64)))), Ordering::Less);
+        assert_eq!(sign(&sum(&from_i128(i128::from(i64::MIN) * 2 + 1), &from_f64(2.0_f64.powi(64)))), Ordering::Greater);
+        assert_eq!(sign(&sum(&from_i128(i128::MAX), &from_f64(-(2.0_f64.powi(127))))), Ordering::Less);
+        assert_eq!(sign(&sum(&from_i128(i128::MIN), &from_f64(2.0_f64.powi(127)))), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(diff(5.0, 3.0), [2.0]);
+        assert_eq!(diff(1.0, 1.0), [0.0]);
+        assert_eq!(sign(&sum(&diff(1.0, f64::EPSILON / 4.0), &from_f64(-1.0))), Ordering::Less);
+    }
+
+    #[test]
+    fn test_grow() {
+        assert_eq!(grow(&[0.0], 1.0), [1.0]);
+        assert_eq!(grow(&[1.0], f64::EPSILON / 4.0), [f64::EPSILON / 4.0, 1.0]);
+        assert_eq!(grow(&[1.0], -1.0), [0.0]);
+    }
+
+    #[test]
+    fn test_sign() {
+        assert_eq!(sign(&[]), Ordering::Equal);
+        assert_eq!(sign(&[0.0]), Ordering::Equal);
+        assert_eq!(sign(&[-1.0, 2.0]), Ordering::Greater);
+        assert_eq!(sign(&[1.0, -2.0]), Ordering::Less);
+        assert_eq!(sign(&negate(&[1.0, -2.0])), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_product() {
+        assert_eq!(product(&[3.0], &[4.0]), [12.0]);
+        let one_plus_epsilon = grow(&[f64::EPSILON], 1.0);
+        let square = product(&one_plus_epsilon, &one_plus_epsilon);
+        assert_eq!(sign(&sum(&square, &[-1.0, -2.0 * f64::EPSILON])), Ordering::Greater);
+        assert_eq!(sign(&sum(&square, &[-1.0, -2.0 * f64::EPSILON, -f64::EPSILON * f64::EPSILON])), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_orient2d() {
+        assert_eq!(orient2d(&[1.0], &[0.0], &[0.0], &[1.0]), Ordering::Greater);
+        assert_eq!(orient2d(&[0.0], &[1.0], &[1.0], &[0.0]), Ordering::Less);
+        assert_eq!(orient2d(&[1.0], &[1.0], &[2.0], &[2.0]), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_incircle() {
+        assert_eq!(incircle(&[1.0], &[0.0], &[0.0], &[1.0], &[-1.0], &[0.0]), Ordering::Greater);
+        assert_eq!(incircle(&[2.0], &[0.0], &[0.0], &[2.0], &[-2.0], &[0.0]), Ordering::Greater);
+        assert_eq!(incircle(&[-1.0], &[-1.0], &[-1.0], &[1.0], &[-2.0], &[0.0]), Ordering::Equal);
+        assert_eq!(incircle(&[6.0], &[0.0], &[5.0], &[1.0], &[4.0], &[0.0]), Ordering::Less);
+    }
+}
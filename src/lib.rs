@@ -1,4 +1,6 @@
+mod bentley_ottmann;
 pub mod cartesian;
+mod expansion;
 mod iter;
 pub mod matrix;
 pub mod transform;
@@ -0,0 +1,134 @@
+use super::{Row, scan, transform};
+use crate::matrix::d2::{
+    point::point_u16::Point,
+    rect::rect_u16::{Rect, contains_point},
+};
+use std::collections::HashSet;
+
+pub fn field_of_view(bounds: &Rect, origin: &Point, radius: u16, opaque: impl Fn(&Point) -> bool) -> Vec<Point> {
+    if !contains_point(bounds, origin) {
+        return Vec::new();
+    }
+    let radius_squared = u128::from(radius) * u128::from(radius);
+    let mut visible = vec![origin.clone()];
+    let mut seen = HashSet::from([origin.clone()]);
+    for quadrant in 0..4 {
+        let cell = |depth, col| transform(origin, quadrant, depth, col).filter(|p| contains_point(bounds, p));
+        let is_wall = |depth, col| cell(depth, col).is_none_or(|p| opaque(&p));
+        scan(Row::first(), i128::from(radius), &is_wall, &mut |depth: i128, col: i128| {
+            let distance_squared = depth.unsigned_abs().pow(2).checked_add(col.unsigned_abs().pow(2));
+            if distance_squared.is_some_and(|distance_squared| distance_squared <= radius_squared)
+                && let Some(p) = cell(depth, col)
+                && seen.insert(p.clone())
+            {
+                visible.push(p);
+            }
+        });
+    }
+    visible
+}
+
+#[cfg(test)]
+mod tests {
+    use super::field_of_view;
+    use crate::matrix::d1::point::point_u16::MAX;
+    use crate::matrix::d2::{fov::fov_u16::has_line_of_sight, point::point_u16::Point, rect::rect_u16::Rect};
+
+    const GRID: [&str; 7] = [".......", "..#....", ".....#.", "...#...", "#......", "....##.", "......."];
+
+    fn opaque(p: &Point) -> bool {
+        p.row > 6 || p.col > 6 || GRID[p.row as usize].as_bytes()[p.col as usize] == b'#'
+    }
+
+    fn sorted(mut points: Vec<Point>) -> Vec<Point> {
+        points.sort_by_key(|p| (p.row, p.col));
+        points
+    }
+
+    #[test]
+    fn test_field_of_view() {
+        let bounds = Rect::new((0, 0), (10, 10));
+        let open = |_: &Point| false;
+        assert_eq!(field_of_view(&bounds, &Point::new(5, 5), 0, open), vec![Point::new(5, 5)]);
+        assert_eq!(sorted(field_of_view(&bounds, &Point::new(5, 5), 1, open)), vec![Point::new(4, 5), Point::new(5, 4), Point::new(5, 5), Point::new(5, 6), Point::new(6, 5)]);
+        assert_eq!(
+            sorted(field_of_view(&bounds, &Point::new(5, 5), 2, open)),
+            vec![
+                Point::new(3, 5),
+                Point::new(4, 4),
+                Point::new(4, 5),
+                Point::new(4, 6),
+                Point::new(5, 3),
+                Point::new(5, 4),
+                Point::new(5, 5),
+                Point::new(5, 6),
+                Point::new(5, 7),
+                Point::new(6, 4),
+                Point::new(6, 5),
+                Point::new(6, 6),
+                Point::new(7, 5),
+            ]
+        );
+        assert_eq!(field_of_view(&bounds, &Point::new(5, 5), 4, open).len(), 49);
+    }
+
+    #[test]
+    fn field_of_view_pillar() {
+        let bounds = Rect::new((0, 0), (10, 10));
+        let visible = field_of_view(&bounds, &Point::new(5, 5), 4, |p: &Point| *p == Point::new(5, 7));
+        assert_eq!(visible.len(), 47);
+        assert!(visible.contains(&Point::new(5, 7)));
+        assert!(visible.contains(&Point::new(6, 8)));
+        assert!(visible.contains(&Point::new(4, 8)));
+        assert!(!visible.contains(&Point::new(5, 8)));
+        assert!(!visible.contains(&Point::new(5, 9)));
+    }
+
+    #[test]
+    fn field_of_view_line_of_sight() {
+        let bounds = Rect::new((0, 0), (6, 6));
+        for origin_row in 0..7 {
+            for origin_col in 0..7 {
+                let origin = Point::new(origin_row, origin_col);
+                if opaque(&origin) {
+                    continue;
+                }
+                let mut expected = Vec::new();
+                for row in 0..7 {
+                    for col in 0..7 {
+                        if has_line_of_sight(&origin, &Point::new(row, col), opaque) {
+                            expected.push(Point::new(row, col));
+                        }
+                    }
+                }
+                assert_eq!(sorted(field_of_view(&bounds, &origin, 10, opaque)), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn field_of_view_clipped() {
+        let open = |_: &Point| false;
+        assert_eq!(sorted(field_of_view(&Rect::new((5, 5), (6, 5)), &Point::new(5, 5), 3, open)), vec![Point::new(5, 5), Point::new(6, 5)]);
+        assert_eq!(field_of_view(&Rect::new((0, 0), (4, 4)), &Point::new(5, 5), 3, open), vec![]);
+        assert_eq!(field_of_view(&Rect::new((0, 0), (10, 10)), &Point::new(0, 0), 10, open).len(), 90);
+    }
+
+    #[test]
+    fn field_of_view_bounds() {
+        let open = |_: &Point| false;
+        assert_eq!(
+            sorted(field_of_view(&Rect::new((MAX - 2, MAX - 2), (MAX, MAX)), &Point::max(), 2, open)),
+            vec![Point::new(MAX - 2, MAX), Point::new(MAX - 1, MAX - 1), Point::new(MAX - 1, MAX), Point::new(MAX, MAX - 2), Point::new(MAX, MAX - 1), Point::max()]
+        );
+        assert_eq!(sorted(field_of_view(&Rect::largest(), &Point::min(), 2, open)), vec![Point::min(), Point::new(0, 1), Point::new(0, 2), Point::new(1, 0), Point::new(1, 1), Point::new(2, 0)]);
+    }
+
+    #[test]
+    fn field_of_view_far() {
+        let open = |_: &Point| false;
+        let radius = MAX;
+        assert_eq!(field_of_view(&Rect::new((0, 0), (0, MAX)), &Point::new(0, 0), radius, open).len(), usize::from(radius) + 1);
+        assert_eq!(field_of_view(&Rect::new((0, 0), (1, MAX)), &Point::new(0, 0), radius, open).len(), 2 * usize::from(radius) + 1);
+    }
+}
@@ -0,0 +1,91 @@
+use super::{Row, scan, transform};
+use crate::matrix::d2::point::point_u16::Point;
+
+pub fn has_line_of_sight(origin: &Point, target: &Point, opaque: impl Fn(&Point) -> bool) -> bool {
+    if origin == target {
+        return true;
+    }
+    let delta_row = i128::from(target.row) - i128::from(origin.row);
+    let delta_col = i128::from(target.col) - i128::from(origin.col);
+    for quadrant in 0..4 {
+        let (depth, col) = match quadrant {
+            0 => (-delta_row, delta_col),
+            1 => (delta_col, delta_row),
+            2 => (delta_row, delta_col),
+            _ => (-delta_col, delta_row),
+        };
+        if depth <= 0 || col.abs() > depth {
+            continue;
+        }
+        let mut visible = false;
+        let is_wall = |depth, col| transform(origin, quadrant, depth, col).is_none_or(|p| opaque(&p));
+        scan(Row::toward(depth, col), depth, &is_wall, &mut |revealed_depth, revealed_col| visible |= revealed_depth == depth && revealed_col == col);
+        if visible {
+            return true;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::has_line_of_sight;
+    use crate::matrix::d1::point::point_u16::MAX;
+    use crate::matrix::d2::point::point_u16::Point;
+
+    const GRID: [&str; 7] = [".......", "..#....", ".....#.", "...#...", "#......", "....##.", "......."];
+
+    fn opaque(p: &Point) -> bool {
+        p.row > 6 || p.col > 6 || GRID[p.row as usize].as_bytes()[p.col as usize] == b'#'
+    }
+
+    #[test]
+    fn test_has_line_of_sight() {
+        let open = |_: &Point| false;
+        assert!(has_line_of_sight(&Point::new(5, 5), &Point::new(5, 5), open));
+        assert!(has_line_of_sight(&Point::new(5, 5), &Point::new(9, 7), open));
+        assert!(has_line_of_sight(&Point::new(9, 7), &Point::new(5, 5), open));
+        let pillar = |p: &Point| *p == Point::new(5, 7);
+        assert!(has_line_of_sight(&Point::new(5, 5), &Point::new(5, 7), pillar));
+        assert!(has_line_of_sight(&Point::new(5, 5), &Point::new(6, 8), pillar));
+        assert!(!has_line_of_sight(&Point::new(5, 5), &Point::new(5, 8), pillar));
+        assert!(!has_line_of_sight(&Point::new(5, 9), &Point::new(5, 5), pillar));
+        assert!(!has_line_of_sight(&Point::new(5, 5), &Point::new(5, 20), pillar));
+    }
+
+    #[test]
+    fn has_line_of_sight_symmetric() {
+        for a in 0..49 {
+            for b in 0..49 {
+                let (a, b) = (Point::new(a / 7, a % 7), Point::new(b / 7, b % 7));
+                if !opaque(&a) && !opaque(&b) {
+                    assert_eq!(has_line_of_sight(&a, &b, opaque), has_line_of_sight(&b, &a, opaque));
+                }
+            }
+        }
+        assert!(has_line_of_sight(&Point::new(3, 1), &Point::new(3, 2), opaque));
+        assert!(!has_line_of_sight(&Point::new(3, 1), &Point::new(3, 5), opaque));
+        assert!(has_line_of_sight(&Point::new(3, 1), &Point::new(3, 3), opaque));
+    }
+
+    #[test]
+    fn has_line_of_sight_bounds() {
+        let open = |_: &Point| false;
+        assert!(has_line_of_sight(&Point::max(), &Point::new(MAX - 3, MAX - 5), open));
+        assert!(has_line_of_sight(&Point::min(), &Point::new(5, 3), open));
+        let wall = |p: &Point| *p == Point::new(MAX - 1, MAX - 1);
+        assert!(!has_line_of_sight(&Point::max(), &Point::new(MAX - 2, MAX - 2), wall));
+        assert!(!has_line_of_sight(&Point::new(MAX - 2, MAX - 2), &Point::max(), wall));
+    }
+
+    #[test]
+    fn has_line_of_sight_far() {
+        let open = |_: &Point| false;
+        assert!(has_line_of_sight(&Point::new(0, 0), &Point::new(0, MAX), open));
+        assert!(has_line_of_sight(&Point::new(MAX, MAX), &Point::new(0, 0), open));
+        assert!(has_line_of_sight(&Point::new(0, 0), &Point::new(MAX / 2, MAX), open));
+        let wall = |p: &Point| *p == Point::new(0, MAX / 2);
+        assert!(!has_line_of_sight(&Point::new(0, 0), &Point::new(0, MAX), wall));
+        assert!(has_line_of_sight(&Point::new(0, 0), &Point::new(2, MAX), wall));
+    }
+}
@@ -0,0 +1,109 @@
+use crate::matrix::d2::point::point_u16::Point;
+
+mod field_of_view;
+mod has_line_of_sight;
+
+pub use self::field_of_view::field_of_view;
+pub use self::has_line_of_sight::has_line_of_sight;
+
+type Slope = (i128, i128);
+
+struct Row {
+    depth: i128,
+    start: Slope,
+    end: Slope,
+}
+
+impl Row {
+    fn first() -> Self {
+        Row { depth: 1, start: (-1, 1), end: (1, 1) }
+    }
+
+    fn toward(depth: i128, col: i128) -> Self {
+        let start = if 2 * col - 1 < -2 * depth { (-1, 1) } else { (2 * col - 1, 2 * depth) };
+        let end = if 2 * col + 1 > 2 * depth { (1, 1) } else { (2 * col + 1, 2 * depth) };
+        Row { depth: 1, start, end }
+    }
+}
+
+fn transform(origin: &Point, quadrant: usize, depth: i128, col: i128) -> Option<Point> {
+    let (delta_row, delta_col) = match quadrant {
+        0 => (-depth, col),
+        1 => (col, depth),
+        2 => (depth, col),
+        _ => (col, -depth),
+    };
+    let row = u16::try_from(i128::from(origin.row) + delta_row).ok()?;
+    let col = u16::try_from(i128::from(origin.col) + delta_col).ok()?;
+    Some(Point::new(row, col))
+}
+
+fn scan(first: Row, max_depth: i128, is_wall: &impl Fn(i128, i128) -> bool, reveal: &mut impl FnMut(i128, i128)) {
+    let mut rows = vec![first];
+    while let Some(mut row) = rows.pop() {
+        if row.depth > max_depth {
+            continue;
+        }
+        let pending = rows.len();
+        let min_col = (2 * row.depth * row.start.0 + row.start.1).div_euclid(2 * row.start.1);
+        let max_col = -(row.end.1 - 2 * row.depth * row.end.0).div_euclid(2 * row.end.1);
+        let mut previous = None;
+        for col in min_col..=max_col {
+            let wall = is_wall(row.depth, col);
+            if wall || (col * row.start.1 >= row.depth * row.start.0 && col * row.end.1 <= row.depth * row.end.0) {
+                reveal(row.depth, col);
+            }
+            if previous == Some(true) && !wall {
+                row.start = (2 * col - 1, 2 * row.depth);
+            }
+            if previous == Some(false) && wall {
+                rows.push(Row { depth: row.depth + 1, start: row.start, end: (2 * col - 1, 2 * row.depth) });
+            }
+            previous = Some(wall);
+        }
+        if previous == Some(false) {
+            rows.push(Row { depth: row.depth + 1, start: row.start, end: row.end });
+        }
+        rows[pending..].reverse();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Row, scan, transform};
+    use crate::matrix::d1::point::point_u16::MAX;
+    use crate::matrix::d2::point::point_u16::Point;
+
+    #[test]
+    fn test_transform() {
+        let origin = Point::new(10, 20);
+        assert_eq!(transform(&origin, 0, 2, 1), Some(Point::new(8, 21)));
+        assert_eq!(transform(&origin, 1, 2, 1), Some(Point::new(11, 22)));
+        assert_eq!(transform(&origin, 2, 2, 1), Some(Point::new(12, 21)));
+        assert_eq!(transform(&origin, 3, 2, 1), Some(Point::new(11, 18)));
+        assert_eq!(transform(&Point::min(), 0, 1, 0), None);
+        assert_eq!(transform(&Point::min(), 2, 1, -1), None);
+        assert_eq!(transform(&Point::max(), 1, 1, 0), None);
+        assert_eq!(transform(&Point::new(1, MAX - 1), 1, 1, -1), Some(Point::new(0, MAX)));
+    }
+
+    #[test]
+    fn test_scan() {
+        let mut revealed = Vec::new();
+        scan(Row::first(), 2, &|_, _| false, &mut |depth, col| revealed.push((depth, col)));
+        assert_eq!(revealed, vec![(1, -1), (1, 0), (1, 1), (2, -2), (2, -1), (2, 0), (2, 1), (2, 2)]);
+        let mut revealed = Vec::new();
+        scan(Row::first(), 3, &|depth, col| depth == 2 && col == 0, &mut |depth, col| revealed.push((depth, col)));
+        assert_eq!(revealed, vec![(1, -1), (1, 0), (1, 1), (2, -2), (2, -1), (2, 0), (2, 1), (2, 2), (3, -3), (3, -2), (3, -1), (3, 1), (3, 2), (3, 3)]);
+    }
+
+    #[test]
+    fn scan_toward() {
+        let mut revealed = Vec::new();
+        scan(Row::toward(4, 1), 4, &|_, _| false, &mut |depth, col| revealed.push((depth, col)));
+        assert_eq!(revealed, vec![(3, 1), (4, 1)]);
+        let mut revealed = Vec::new();
+        scan(Row::toward(3, -3), 3, &|_, _| false, &mut |depth, col| revealed.push((depth, col)));
+        assert_eq!(revealed, vec![(1, -1), (2, -2), (3, -3)]);
+    }
+}
@@ -0,0 +1,9 @@
+pub mod fov_u16;
+pub mod fov_u32;
+pub mod fov_u64;
+pub mod fov_u8;
+
+pub mod fov_i16;
+pub mod fov_i32;
+pub mod fov_i64;
+pub mod fov_i8;
@@ -0,0 +1,92 @@
+use super::Line;
+use crate::matrix::d2::point::point_i32::Point;
+
+#[derive(Debug, PartialEq)]
+pub struct Bresenham {
+    row: i64,
+    col: i64,
+    end_row: i64,
+    end_col: i64,
+    d_row: i64,
+    d_col: i64,
+    step_row: i64,
+    step_col: i64,
+    err: i64,
+    done: bool,
+}
+
+impl Bresenham {
+    pub fn new(l: &Line) -> Self {
+        let d_row = -(i64::from(l.max.row) - i64::from(l.min.row)).abs();
+        let d_col = (i64::from(l.max.col) - i64::from(l.min.col)).abs();
+        Bresenham {
+            row: i64::from(l.min.row),
+            col: i64::from(l.min.col),
+            end_row: i64::from(l.max.row),
+            end_col: i64::from(l.max.col),
+            d_row,
+            d_col,
+            step_row: if l.min.row < l.max.row { 1 } else { -1 },
+            step_col: if l.min.col < l.max.col { 1 } else { -1 },
+            err: d_col + d_row,
+            done: false,
+        }
+    }
+}
+
+impl Iterator for Bresenham {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let p = Point::new(self.row as i32, self.col as i32);
+        if self.row == self.end_row && self.col == self.end_col {
+            self.done = true;
+            return Some(p);
+        }
+        let e2 = 2 * self.err;
+        if e2 >= self.d_row {
+            self.err += self.d_row;
+            self.col += self.step_col;
+        }
+        if e2 <= self.d_col {
+            self.err += self.d_col;
+            self.row += self.step_row;
+        }
+        Some(p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::{
+        d1::point::point_i32::{MAX, MIN},
+        d2::{line::line_i32::Line, point::point_i32::Point},
+    };
+
+    #[test]
+    fn bresenham() {
+        assert_eq!(Line::new((0, 0), (0, 4)).iter_bresenham().collect::<Vec<Point>>(), [Point::new(0, 0), Point::new(0, 1), Point::new(0, 2), Point::new(0, 3), Point::new(0, 4)]);
+        assert_eq!(Line::new((4, 0), (0, 0)).iter_bresenham().collect::<Vec<Point>>(), [Point::new(4, 0), Point::new(3, 0), Point::new(2, 0), Point::new(1, 0), Point::new(0, 0)]);
+        assert_eq!(Line::new((0, 0), (3, 3)).iter_bresenham().collect::<Vec<Point>>(), [Point::new(0, 0), Point::new(1, 1), Point::new(2, 2), Point::new(3, 3)]);
+        assert_eq!(Line::new((0, 0), (2, 5)).iter_bresenham().collect::<Vec<Point>>(), [Point::new(0, 0), Point::new(0, 1), Point::new(1, 2), Point::new(1, 3), Point::new(2, 4), Point::new(2, 5)]);
+        assert_eq!(Line::new((0, 0), (5, 2)).iter_bresenham().collect::<Vec<Point>>(), [Point::new(0, 0), Point::new(1, 0), Point::new(2, 1), Point::new(3, 1), Point::new(4, 2), Point::new(5, 2)]);
+        assert_eq!(Line::new((2, 5), (0, 0)).iter_bresenham().collect::<Vec<Point>>(), [Point::new(2, 5), Point::new(2, 4), Point::new(1, 3), Point::new(1, 2), Point::new(0, 1), Point::new(0, 0)]);
+        assert_eq!(Line::new((0, 0), (2, 4)).iter_bresenham().collect::<Vec<Point>>(), [Point::new(0, 0), Point::new(1, 1), Point::new(1, 2), Point::new(2, 3), Point::new(2, 4)]);
+        assert_eq!(Line::new((0, 0), (1, 3)).iter_bresenham().collect::<Vec<Point>>(), [Point::new(0, 0), Point::new(0, 1), Point::new(1, 2), Point::new(1, 3)]);
+        assert_eq!(Line::new((3, 1), (0, 4)).iter_bresenham().collect::<Vec<Point>>(), [Point::new(3, 1), Point::new(2, 2), Point::new(1, 3), Point::new(0, 4)]);
+    }
+
+    #[test]
+    fn bounds() {
+        assert_eq!(Line::new((MIN, MIN), (MIN + 2, MIN + 2)).iter_bresenham().collect::<Vec<Point>>(), [Point::new(MIN, MIN), Point::new(MIN + 1, MIN + 1), Point::new(MIN + 2, MIN + 2)]);
+        assert_eq!(Line::new((MAX, MAX), (MAX - 2, MAX - 2)).iter_bresenham().collect::<Vec<Point>>(), [Point::new(MAX, MAX), Point::new(MAX - 1, MAX - 1), Point::new(MAX - 2, MAX - 2)]);
+        assert_eq!(Line::new((MAX - 2, MIN), (MAX, MIN)).iter_bresenham().collect::<Vec<Point>>(), [Point::new(MAX - 2, MIN), Point::new(MAX - 1, MIN), Point::new(MAX, MIN)]);
+        assert_eq!(Line::largest().iter_bresenham().take(3).collect::<Vec<Point>>(), [Point::new(MIN, MIN), Point::new(MIN + 1, MIN + 1), Point::new(MIN + 2, MIN + 2)]);
+        assert_eq!(Line::new((MIN, MAX), (MAX, MIN)).iter_bresenham().take(3).collect::<Vec<Point>>(), [Point::new(MIN, MAX), Point::new(MIN + 1, MAX - 1), Point::new(MIN + 2, MAX - 2)]);
+        assert_eq!(Line::min().iter_bresenham().collect::<Vec<Point>>(), [Point::min()]);
+        assert_eq!(Line::max().iter_bresenham().collect::<Vec<Point>>(), [Point::max()]);
+    }
+}
@@ -0,0 +1,140 @@
+use super::Line;
+use crate::matrix::d2::point::point_i32::Point;
+
+#[derive(Debug, PartialEq)]
+pub struct Supercover {
+    row: i64,
+    col: i64,
+    len_row: i64,
+    len_col: i64,
+    i_row: i64,
+    i_col: i64,
+    step_row: i64,
+    step_col: i64,
+    decision: i64,
+    corner: Option<Point>,
+    done: bool,
+}
+
+impl Supercover {
+    pub fn new(l: &Line) -> Self {
+        let len_row = (i64::from(l.max.row) - i64::from(l.min.row)).abs();
+        let len_col = (i64::from(l.max.col) - i64::from(l.min.col)).abs();
+        Supercover {
+            row: i64::from(l.min.row),
+            col: i64::from(l.min.col),
+            len_row,
+            len_col,
+            i_row: 0,
+            i_col: 0,
+            step_row: if l.min.row < l.max.row { 1 } else { -1 },
+            step_col: if l.min.col < l.max.col { 1 } else { -1 },
+            decision: len_row - len_col,
+            corner: None,
+            done: false,
+        }
+    }
+}
+
+impl Iterator for Supercover {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(p) = self.corner.take() {
+            return Some(p);
+        }
+        if self.done {
+            return None;
+        }
+        let p = Point::new(self.row as i32, self.col as i32);
+        if self.i_row == self.len_row && self.i_col == self.len_col {
+            self.done = true;
+            return Some(p);
+        }
+        if self.decision == 0 {
+            self.corner = Some(Point::new((self.row + self.step_row) as i32, self.col as i32));
+        }
+        if self.decision <= 0 {
+            self.col += self.step_col;
+            self.i_col += 1;
+            self.decision += 2 * self.len_row;
+        } else {
+            self.row += self.step_row;
+            self.i_row += 1;
+            self.decision -= 2 * self.len_col;
+        }
+        Some(p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::{
+        d1::point::point_i32::{MAX, MIN},
+        d2::{line::line_i32::Line, point::point_i32::Point},
+    };
+
+    #[test]
+    fn supercover() {
+        assert_eq!(Line::new((0, 0), (0, 4)).iter_supercover().collect::<Vec<Point>>(), [Point::new(0, 0), Point::new(0, 1), Point::new(0, 2), Point::new(0, 3), Point::new(0, 4)]);
+        assert_eq!(Line::new((4, 0), (0, 0)).iter_supercover().collect::<Vec<Point>>(), [Point::new(4, 0), Point::new(3, 0), Point::new(2, 0), Point::new(1, 0), Point::new(0, 0)]);
+        assert_eq!(
+            Line::new((0, 0), (3, 3)).iter_supercover().collect::<Vec<Point>>(),
+            [Point::new(0, 0), Point::new(1, 0), Point::new(0, 1), Point::new(1, 1), Point::new(2, 1), Point::new(1, 2), Point::new(2, 2), Point::new(3, 2), Point::new(2, 3), Point::new(3, 3)]
+        );
+        assert_eq!(
+            Line::new((0, 0), (2, 5)).iter_supercover().collect::<Vec<Point>>(),
+            [Point::new(0, 0), Point::new(0, 1), Point::new(1, 1), Point::new(1, 2), Point::new(1, 3), Point::new(1, 4), Point::new(2, 4), Point::new(2, 5)]
+        );
+        assert_eq!(
+            Line::new((0, 0), (5, 2)).iter_supercover().collect::<Vec<Point>>(),
+            [Point::new(0, 0), Point::new(1, 0), Point::new(1, 1), Point::new(2, 1), Point::new(3, 1), Point::new(4, 1), Point::new(4, 2), Point::new(5, 2)]
+        );
+        assert_eq!(
+            Line::new((2, 5), (0, 0)).iter_supercover().collect::<Vec<Point>>(),
+            [Point::new(2, 5), Point::new(2, 4), Point::new(1, 4), Point::new(1, 3), Point::new(1, 2), Point::new(1, 1), Point::new(0, 1), Point::new(0, 0)]
+        );
+        assert_eq!(
+            Line::new((0, 0), (2, 4)).iter_supercover().collect::<Vec<Point>>(),
+            [Point::new(0, 0), Point::new(0, 1), Point::new(1, 1), Point::new(1, 2), Point::new(1, 3), Point::new(2, 3), Point::new(2, 4)]
+        );
+        assert_eq!(Line::new((0, 0), (1, 3)).iter_supercover().collect::<Vec<Point>>(), [Point::new(0, 0), Point::new(0, 1), Point::new(1, 1), Point::new(0, 2), Point::new(1, 2), Point::new(1, 3)]);
+        assert_eq!(
+            Line::new((3, 1), (0, 4)).iter_supercover().collect::<Vec<Point>>(),
+            [Point::new(3, 1), Point::new(2, 1), Point::new(3, 2), Point::new(2, 2), Point::new(1, 2), Point::new(2, 3), Point::new(1, 3), Point::new(0, 3), Point::new(1, 4), Point::new(0, 4)]
+        );
+    }
+
+    #[test]
+    fn bounds() {
+        assert_eq!(
+            Line::new((MIN, MIN), (MIN + 2, MIN + 2)).iter_supercover().collect::<Vec<Point>>(),
+            [
+                Point::new(MIN, MIN),
+                Point::new(MIN + 1, MIN),
+                Point::new(MIN, MIN + 1),
+                Point::new(MIN + 1, MIN + 1),
+                Point::new(MIN + 2, MIN + 1),
+                Point::new(MIN + 1, MIN + 2),
+                Point::new(MIN + 2, MIN + 2)
+            ]
+        );
+        assert_eq!(
+            Line::new((MAX, MAX), (MAX - 2, MAX - 2)).iter_supercover().collect::<Vec<Point>>(),
+            [
+                Point::new(MAX, MAX),
+                Point::new(MAX - 1, MAX),
+                Point::new(MAX, MAX - 1),
+                Point::new(MAX - 1, MAX - 1),
+                Point::new(MAX - 2, MAX - 1),
+                Point::new(MAX - 1, MAX - 2),
+                Point::new(MAX - 2, MAX - 2)
+            ]
+        );
+        assert_eq!(Line::new((MAX - 2, MIN), (MAX, MIN)).iter_supercover().collect::<Vec<Point>>(), [Point::new(MAX - 2, MIN), Point::new(MAX - 1, MIN), Point::new(MAX, MIN)]);
+        assert_eq!(Line::largest().iter_supercover().take(3).collect::<Vec<Point>>(), [Point::new(MIN, MIN), Point::new(MIN + 1, MIN), Point::new(MIN, MIN + 1)]);
+        assert_eq!(Line::new((MIN, MAX), (MAX, MIN)).iter_supercover().take(3).collect::<Vec<Point>>(), [Point::new(MIN, MAX), Point::new(MIN + 1, MAX), Point::new(MIN, MAX - 1)]);
+        assert_eq!(Line::min().iter_supercover().collect::<Vec<Point>>(), [Point::min()]);
+        assert_eq!(Line::max().iter_supercover().collect::<Vec<Point>>(), [Point::max()]);
+    }
+}
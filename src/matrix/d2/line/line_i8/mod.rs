@@ -1,5 +1,11 @@
 use crate::matrix::d2::point::point_i8::Point;
 
+mod bresenham;
+mod supercover;
+
+pub use self::bresenham::Bresenham;
+pub use self::supercover::Supercover;
+
 #[derive(Eq, PartialEq, Debug, Clone)]
 pub struct Line {
     pub min: Point,
@@ -26,6 +32,14 @@ impl Line {
     pub fn zero() -> Self {
         Line { min: Point::zero(), max: Point::zero() }
     }
+
+    pub fn iter_bresenham(&self) -> Bresenham {
+        Bresenham::new(self)
+    }
+
+    pub fn iter_supercover(&self) -> Supercover {
+        Supercover::new(self)
+    }
 }
 
 impl std::fmt::Display for Line {
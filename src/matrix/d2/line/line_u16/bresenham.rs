@@ -0,0 +1,92 @@
+use super::Line;
+use crate::matrix::d2::point::point_u16::Point;
+
+#[derive(Debug, PartialEq)]
+pub struct Bresenham {
+    row: i32,
+    col: i32,
+    end_row: i32,
+    end_col: i32,
+    d_row: i32,
+    d_col: i32,
+    step_row: i32,
+    step_col: i32,
+    err: i32,
+    done: bool,
+}
+
+impl Bresenham {
+    pub fn new(l: &Line) -> Self {
+        let d_row = -(i32::from(l.max.row) - i32::from(l.min.row)).abs();
+        let d_col = (i32::from(l.max.col) - i32::from(l.min.col)).abs();
+        Bresenham {
+            row: i32::from(l.min.row),
+            col: i32::from(l.min.col),
+            end_row: i32::from(l.max.row),
+            end_col: i32::from(l.max.col),
+            d_row,
+            d_col,
+            step_row: if l.min.row < l.max.row { 1 } else { -1 },
+            step_col: if l.min.col < l.max.col { 1 } else { -1 },
+            err: d_col + d_row,
+            done: false,
+        }
+    }
+}
+
+impl Iterator for Bresenham {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let p = Point::new(self.row as u16, self.col as u16);
+        if self.row == self.end_row && self.col == self.end_col {
+            self.done = true;
+            return Some(p);
+        }
+        let e2 = 2 * self.err;
+        if e2 >= self.d_row {
+            self.err += self.d_row;
+            self.col += self.step_col;
+        }
+        if e2 <= self.d_col {
+            self.err += self.d_col;
+            self.row += self.step_row;
+        }
+        Some(p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::{
+        d1::point::point_u16::MAX,
+        d2::{line::line_u16::Line, point::point_u16::Point},
+    };
+
+    #[test]
+    fn bresenham() {
+        assert_eq!(Line::new((0, 0), (0, 4)).iter_bresenham().collect::<Vec<Point>>(), [Point::new(0, 0), Point::new(0, 1), Point::new(0, 2), Point::new(0, 3), Point::new(0, 4)]);
+        assert_eq!(Line::new((4, 0), (0, 0)).iter_bresenham().collect::<Vec<Point>>(), [Point::new(4, 0), Point::new(3, 0), Point::new(2, 0), Point::new(1, 0), Point::new(0, 0)]);
+        assert_eq!(Line::new((0, 0), (3, 3)).iter_bresenham().collect::<Vec<Point>>(), [Point::new(0, 0), Point::new(1, 1), Point::new(2, 2), Point::new(3, 3)]);
+        assert_eq!(Line::new((0, 0), (2, 5)).iter_bresenham().collect::<Vec<Point>>(), [Point::new(0, 0), Point::new(0, 1), Point::new(1, 2), Point::new(1, 3), Point::new(2, 4), Point::new(2, 5)]);
+        assert_eq!(Line::new((0, 0), (5, 2)).iter_bresenham().collect::<Vec<Point>>(), [Point::new(0, 0), Point::new(1, 0), Point::new(2, 1), Point::new(3, 1), Point::new(4, 2), Point::new(5, 2)]);
+        assert_eq!(Line::new((2, 5), (0, 0)).iter_bresenham().collect::<Vec<Point>>(), [Point::new(2, 5), Point::new(2, 4), Point::new(1, 3), Point::new(1, 2), Point::new(0, 1), Point::new(0, 0)]);
+        assert_eq!(Line::new((0, 0), (2, 4)).iter_bresenham().collect::<Vec<Point>>(), [Point::new(0, 0), Point::new(1, 1), Point::new(1, 2), Point::new(2, 3), Point::new(2, 4)]);
+        assert_eq!(Line::new((0, 0), (1, 3)).iter_bresenham().collect::<Vec<Point>>(), [Point::new(0, 0), Point::new(0, 1), Point::new(1, 2), Point::new(1, 3)]);
+        assert_eq!(Line::new((3, 1), (0, 4)).iter_bresenham().collect::<Vec<Point>>(), [Point::new(3, 1), Point::new(2, 2), Point::new(1, 3), Point::new(0, 4)]);
+    }
+
+    #[test]
+    fn bounds() {
+        assert_eq!(Line::new((0, 0), (2, 2)).iter_bresenham().collect::<Vec<Point>>(), [Point::new(0, 0), Point::new(1, 1), Point::new(2, 2)]);
+        assert_eq!(Line::new((MAX, MAX), (MAX - 2, MAX - 2)).iter_bresenham().collect::<Vec<Point>>(), [Point::new(MAX, MAX), Point::new(MAX - 1, MAX - 1), Point::new(MAX - 2, MAX - 2)]);
+        assert_eq!(Line::new((MAX - 2, 0), (MAX, 0)).iter_bresenham().collect::<Vec<Point>>(), [Point::new(MAX - 2, 0), Point::new(MAX - 1, 0), Point::new(MAX, 0)]);
+        assert_eq!(Line::largest().iter_bresenham().take(3).collect::<Vec<Point>>(), [Point::new(0, 0), Point::new(1, 1), Point::new(2, 2)]);
+        assert_eq!(Line::new((0, MAX), (MAX, 0)).iter_bresenham().take(3).collect::<Vec<Point>>(), [Point::new(0, MAX), Point::new(1, MAX - 1), Point::new(2, MAX - 2)]);
+        assert_eq!(Line::min().iter_bresenham().collect::<Vec<Point>>(), [Point::min()]);
+        assert_eq!(Line::max().iter_bresenham().collect::<Vec<Point>>(), [Point::max()]);
+    }
+}
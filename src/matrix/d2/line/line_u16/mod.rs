@@ -1,5 +1,11 @@
 use crate::matrix::d2::{line::line_u8, point::point_u16::Point};
 
+mod bresenham;
+mod supercover;
+
+pub use self::bresenham::Bresenham;
+pub use self::supercover::Supercover;
+
 #[derive(Eq, PartialEq, Debug, Clone)]
 pub struct Line {
     pub min: Point,
@@ -22,6 +28,14 @@ impl Line {
     pub fn max() -> Self {
         Line { min: Point::max(), max: Point::max() }
     }
+
+    pub fn iter_bresenham(&self) -> Bresenham {
+        Bresenham::new(self)
+    }
+
+    pub fn iter_supercover(&self) -> Supercover {
+        Supercover::new(self)
+    }
 }
 
 impl std::fmt::Display for Line {
@@ -1,3 +1,6 @@
+pub mod fov;
 pub mod line;
+pub mod path;
 pub mod point;
 pub mod rect;
+pub mod region;
@@ -0,0 +1,22 @@
+pub mod path_u16;
+pub mod path_u32;
+pub mod path_u64;
+pub mod path_u8;
+
+pub mod path_i16;
+pub mod path_i32;
+pub mod path_i64;
+pub mod path_i8;
+
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub enum Connectivity {
+    Four,
+    Eight,
+}
+
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub enum Heuristic {
+    Manhattan,
+    Chebyshev,
+    Octile,
+}
@@ -0,0 +1,118 @@
+use super::{Candidate, estimate, neighbors, reconstruct};
+use crate::matrix::d2::{
+    path::{Connectivity, Heuristic},
+    point::point_i32::Point,
+    rect::rect_i32::{Rect, contains_point},
+};
+use std::collections::{BinaryHeap, HashMap};
+
+pub fn a_star(bounds: &Rect, start: &Point, goal: &Point, connectivity: &Connectivity, heuristic: &Heuristic, cost: impl Fn(&Point) -> Option<f64>) -> Option<(Vec<Point>, f64)> {
+    if !contains_point(bounds, start) || !contains_point(bounds, goal) || cost(start).is_none() || cost(goal).is_none() {
+        return None;
+    }
+    let mut costs = HashMap::from([(start.clone(), 0.0)]);
+    let mut came_from = HashMap::new();
+    let mut heap = BinaryHeap::from([Candidate { priority: estimate(heuristic, start, goal), cost: 0.0, order: 0, p: start.clone() }]);
+    let mut order = 1;
+    while let Some(candidate) = heap.pop() {
+        if candidate.p == *goal {
+            return Some((reconstruct(&came_from, goal), candidate.cost));
+        }
+        if costs.get(&candidate.p).is_some_and(|known| *known < candidate.cost) {
+            continue;
+        }
+        for (neighbor, distance) in neighbors(bounds, &candidate.p, connectivity) {
+            let Some(step) = cost(&neighbor) else { continue };
+            let next = step.mul_add(distance, candidate.cost);
+            if costs.get(&neighbor).is_none_or(|known| next < *known) {
+                costs.insert(neighbor.clone(), next);
+                came_from.insert(neighbor.clone(), candidate.p.clone());
+                heap.push(Candidate { priority: next + estimate(heuristic, &neighbor, goal), cost: next, order, p: neighbor });
+                order += 1;
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::a_star;
+    use crate::matrix::d1::point::point_i32::{MAX, MIN};
+    use crate::matrix::d2::{
+        path::{Connectivity, Heuristic},
+        point::point_i32::Point,
+        rect::rect_i32::Rect,
+    };
+    use std::f64::consts::SQRT_2;
+
+    fn wall(p: &Point) -> Option<f64> {
+        if p.col == 2 && p.row <= 3 { None } else { Some(1.0) }
+    }
+
+    fn valid(path: &[Point], diagonal: bool) -> bool {
+        path.windows(2).all(|w| {
+            let (row, col) = (w[0].row.abs_diff(w[1].row), w[0].col.abs_diff(w[1].col));
+            row <= 1 && col <= 1 && row + col >= 1 && (diagonal || row + col == 1)
+        }) && path.iter().all(|p| wall(p).is_some())
+    }
+
+    #[test]
+    fn test_a_star() {
+        let bounds = Rect::new((0, 0), (4, 4));
+        let (path, cost) = a_star(&bounds, &Point::new(0, 0), &Point::new(0, 4), &Connectivity::Four, &Heuristic::Manhattan, wall).unwrap();
+        assert_eq!(cost, 12.0);
+        assert_eq!(path.len(), 13);
+        assert_eq!((path.first(), path.last()), (Some(&Point::new(0, 0)), Some(&Point::new(0, 4))));
+        assert!(valid(&path, false));
+        let (path, cost) = a_star(&bounds, &Point::new(0, 0), &Point::new(0, 4), &Connectivity::Eight, &Heuristic::Octile, wall).unwrap();
+        assert_eq!(path.len(), 9);
+        assert!(valid(&path, true));
+        assert_eq!(cost, path.windows(2).fold(0.0, |acc, w| if w[0].row != w[1].row && w[0].col != w[1].col { SQRT_2 + acc } else { 1.0 + acc }));
+        assert!(cost > 9.65 && cost < 9.66);
+        let (path, cost) = a_star(&bounds, &Point::new(0, 0), &Point::new(0, 4), &Connectivity::Eight, &Heuristic::Chebyshev, wall).unwrap();
+        assert_eq!(path.len(), 9);
+        assert!(cost > 9.65 && cost < 9.66);
+    }
+
+    #[test]
+    fn a_star_cost() {
+        let bounds = Rect::new((0, 0), (0, 4));
+        let swamp = |p: &Point| Some(if p.col == 2 { 5.0 } else { 1.0 });
+        assert_eq!(
+            a_star(&bounds, &Point::new(0, 0), &Point::new(0, 4), &Connectivity::Four, &Heuristic::Manhattan, swamp),
+            Some((vec![Point::new(0, 0), Point::new(0, 1), Point::new(0, 2), Point::new(0, 3), Point::new(0, 4)], 8.0))
+        );
+        let bounds = Rect::new((0, 0), (2, 4));
+        let swamp = |p: &Point| Some(if p.col == 2 && p.row < 2 { 6.0 } else { 1.0 });
+        let (path, cost) = a_star(&bounds, &Point::new(0, 0), &Point::new(0, 4), &Connectivity::Four, &Heuristic::Manhattan, swamp).unwrap();
+        assert_eq!(cost, 8.0);
+        assert!(path.contains(&Point::new(2, 2)));
+    }
+
+    #[test]
+    fn a_star_unreachable() {
+        let bounds = Rect::new((0, 0), (4, 4));
+        let closed = |p: &Point| if p.col == 2 { None } else { Some(1.0) };
+        assert_eq!(a_star(&bounds, &Point::new(0, 0), &Point::new(0, 4), &Connectivity::Eight, &Heuristic::Octile, closed), None);
+        assert_eq!(a_star(&bounds, &Point::new(0, 0), &Point::new(0, 2), &Connectivity::Four, &Heuristic::Manhattan, wall), None);
+        assert_eq!(a_star(&bounds, &Point::new(0, 0), &Point::new(5, 5), &Connectivity::Four, &Heuristic::Manhattan, wall), None);
+        assert_eq!(a_star(&bounds, &Point::new(5, 5), &Point::new(0, 0), &Connectivity::Four, &Heuristic::Manhattan, wall), None);
+        assert_eq!(a_star(&bounds, &Point::new(0, 2), &Point::new(0, 4), &Connectivity::Four, &Heuristic::Manhattan, wall), None);
+        assert_eq!(a_star(&bounds, &Point::new(0, 2), &Point::new(0, 2), &Connectivity::Four, &Heuristic::Manhattan, wall), None);
+        assert_eq!(a_star(&bounds, &Point::new(1, 1), &Point::new(1, 1), &Connectivity::Four, &Heuristic::Manhattan, wall), Some((vec![Point::new(1, 1)], 0.0)));
+    }
+
+    #[test]
+    fn a_star_bounds() {
+        let open = |_: &Point| Some(1.0);
+        assert_eq!(
+            a_star(&Rect::new((MAX - 2, MAX - 2), (MAX, MAX)), &Point::new(MAX - 2, MAX - 2), &Point::max(), &Connectivity::Eight, &Heuristic::Octile, open),
+            Some((vec![Point::new(MAX - 2, MAX - 2), Point::new(MAX - 1, MAX - 1), Point::max()], 2.0 * SQRT_2))
+        );
+        assert_eq!(
+            a_star(&Rect::new((MIN, MIN), (MIN + 2, MIN + 2)), &Point::new(MIN + 2, MIN + 2), &Point::min(), &Connectivity::Eight, &Heuristic::Octile, open),
+            Some((vec![Point::new(MIN + 2, MIN + 2), Point::new(MIN + 1, MIN + 1), Point::min()], 2.0 * SQRT_2))
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use super::{neighbors, reconstruct};
+use crate::matrix::d2::{
+    path::Connectivity,
+    point::point_i64::Point,
+    rect::rect_i64::{Rect, contains_point},
+};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+pub fn bfs(bounds: &Rect, start: &Point, goal: &Point, connectivity: &Connectivity, passable: impl Fn(&Point) -> bool) -> Option<Vec<Point>> {
+    if !contains_point(bounds, start) || !contains_point(bounds, goal) || !passable(start) || !passable(goal) {
+        return None;
+    }
+    let mut visited = HashSet::from([start.clone()]);
+    let mut came_from = HashMap::new();
+    let mut queue = VecDeque::from([start.clone()]);
+    while let Some(p) = queue.pop_front() {
+        if p == *goal {
+            return Some(reconstruct(&came_from, goal));
+        }
+        for (neighbor, _) in neighbors(bounds, &p, connectivity) {
+            if passable(&neighbor) && visited.insert(neighbor.clone()) {
+                came_from.insert(neighbor.clone(), p.clone());
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::bfs;
+    use crate::matrix::d1::point::point_i64::{MAX, MIN};
+    use crate::matrix::d2::{path::Connectivity, point::point_i64::Point, rect::rect_i64::Rect};
+
+    fn wall(p: &Point) -> Option<f64> {
+        if p.col == 2 && p.row <= 3 { None } else { Some(1.0) }
+    }
+
+    fn valid(path: &[Point], diagonal: bool) -> bool {
+        path.windows(2).all(|w| {
+            let (row, col) = (w[0].row.abs_diff(w[1].row), w[0].col.abs_diff(w[1].col));
+            row <= 1 && col <= 1 && row + col >= 1 && (diagonal || row + col == 1)
+        }) && path.iter().all(|p| wall(p).is_some())
+    }
+
+    #[test]
+    fn test_bfs() {
+        let bounds = Rect::new((0, 0), (4, 4));
+        let passable = |p: &Point| wall(p).is_some();
+        let path = bfs(&bounds, &Point::new(0, 0), &Point::new(0, 4), &Connectivity::Four, passable).unwrap();
+        assert_eq!(path.len(), 13);
+        assert_eq!((path.first(), path.last()), (Some(&Point::new(0, 0)), Some(&Point::new(0, 4))));
+        assert!(valid(&path, false));
+        let path = bfs(&bounds, &Point::new(0, 0), &Point::new(0, 4), &Connectivity::Eight, passable).unwrap();
+        assert_eq!(path.len(), 9);
+        assert!(valid(&path, true));
+        assert_eq!(bfs(&bounds, &Point::new(0, 0), &Point::new(0, 1), &Connectivity::Four, passable), Some(vec![Point::new(0, 0), Point::new(0, 1)]));
+        assert_eq!(bfs(&bounds, &Point::new(3, 3), &Point::new(3, 3), &Connectivity::Four, passable), Some(vec![Point::new(3, 3)]));
+    }
+
+    #[test]
+    fn bfs_unreachable() {
+        let bounds = Rect::new((0, 0), (4, 4));
+        let passable = |p: &Point| p.col != 2;
+        assert_eq!(bfs(&bounds, &Point::new(0, 0), &Point::new(0, 4), &Connectivity::Eight, passable), None);
+        assert_eq!(bfs(&bounds, &Point::new(0, 0), &Point::new(0, 2), &Connectivity::Eight, passable), None);
+        assert_eq!(bfs(&bounds, &Point::new(0, 0), &Point::new(5, 0), &Connectivity::Eight, passable), None);
+        assert_eq!(bfs(&bounds, &Point::new(0, 2), &Point::new(0, 3), &Connectivity::Eight, passable), None);
+        assert_eq!(bfs(&bounds, &Point::new(1, 2), &Point::new(1, 2), &Connectivity::Eight, passable), None);
+    }
+
+    #[test]
+    fn bfs_bounds() {
+        let open = |_: &Point| true;
+        assert_eq!(bfs(&Rect::new((MAX - 1, MAX - 1), (MAX, MAX)), &Point::new(MAX - 1, MAX - 1), &Point::max(), &Connectivity::Eight, open), Some(vec![Point::new(MAX - 1, MAX - 1), Point::max()]));
+        assert_eq!(bfs(&Rect::new((MIN, MIN), (MIN + 1, MIN + 1)), &Point::new(MIN + 1, MIN + 1), &Point::min(), &Connectivity::Four, open).map(|path| path.len()), Some(3));
+    }
+}
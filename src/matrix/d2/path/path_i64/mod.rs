@@ -0,0 +1,114 @@
+use crate::matrix::d2::{
+    path::{Connectivity, Heuristic},
+    point::point_i64::{Point, delta_col, delta_max, delta_min, delta_row},
+    rect::rect_i64::{Rect, contains_point},
+};
+use std::{cmp::Ordering, collections::HashMap, f64::consts::SQRT_2};
+
+mod a_star;
+mod bfs;
+mod dijkstra_map;
+
+pub use self::a_star::a_star;
+pub use self::bfs::bfs;
+pub use self::dijkstra_map::dijkstra_map;
+
+struct Candidate {
+    priority: f64,
+    cost: f64,
+    order: usize,
+    p: Point,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.total_cmp(&self.priority).then_with(|| other.order.cmp(&self.order))
+    }
+}
+
+fn neighbors(bounds: &Rect, p: &Point, connectivity: &Connectivity) -> Vec<(Point, f64)> {
+    let rows = [p.row.checked_sub(1), Some(p.row), p.row.checked_add(1)];
+    let cols = [p.col.checked_sub(1), Some(p.col), p.col.checked_add(1)];
+    let mut result = Vec::new();
+    for (i, row) in rows.iter().enumerate() {
+        for (j, col) in cols.iter().enumerate() {
+            let diagonal = i != 1 && j != 1;
+            if (i == 1 && j == 1) || (diagonal && *connectivity == Connectivity::Four) {
+                continue;
+            }
+            if let (Some(row), Some(col)) = (row, col) {
+                let neighbor = Point::new(*row, *col);
+                if contains_point(bounds, &neighbor) {
+                    result.push((neighbor, if diagonal { SQRT_2 } else { 1.0 }));
+                }
+            }
+        }
+    }
+    result
+}
+
+fn estimate(heuristic: &Heuristic, a: &Point, b: &Point) -> f64 {
+    let min = Point::new(a.row.min(b.row), a.col.min(b.col));
+    let max = Point::new(a.row.max(b.row), a.col.max(b.col));
+    match heuristic {
+        Heuristic::Manhattan => delta_row(&min, &max) as f64 + delta_col(&min, &max) as f64,
+        Heuristic::Chebyshev => delta_max(&min, &max) as f64,
+        Heuristic::Octile => (SQRT_2 - 1.0).mul_add(delta_min(&min, &max) as f64, delta_max(&min, &max) as f64),
+    }
+}
+
+fn reconstruct(came_from: &HashMap<Point, Point>, goal: &Point) -> Vec<Point> {
+    let mut path = vec![goal.clone()];
+    while let Some(previous) = came_from.get(&path[path.len() - 1]) {
+        path.push(previous.clone());
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{estimate, neighbors};
+    use crate::matrix::d1::point::point_i64::{MAX, MIN};
+    use crate::matrix::d2::{
+        path::{Connectivity, Heuristic},
+        point::point_i64::Point,
+        rect::rect_i64::Rect,
+    };
+    use std::f64::consts::SQRT_2;
+
+    #[test]
+    fn test_neighbors() {
+        let bounds = Rect::new((0, 0), (4, 4));
+        assert_eq!(neighbors(&bounds, &Point::new(2, 2), &Connectivity::Four), vec![(Point::new(1, 2), 1.0), (Point::new(2, 1), 1.0), (Point::new(2, 3), 1.0), (Point::new(3, 2), 1.0)]);
+        assert_eq!(neighbors(&bounds, &Point::new(0, 0), &Connectivity::Four), vec![(Point::new(0, 1), 1.0), (Point::new(1, 0), 1.0)]);
+        assert_eq!(neighbors(&bounds, &Point::new(4, 4), &Connectivity::Eight), vec![(Point::new(3, 3), SQRT_2), (Point::new(3, 4), 1.0), (Point::new(4, 3), 1.0)]);
+        assert_eq!(neighbors(&bounds, &Point::new(2, 2), &Connectivity::Eight).len(), 8);
+        assert_eq!(neighbors(&Rect::new((0, 0), (0, 0)), &Point::new(0, 0), &Connectivity::Eight), vec![]);
+        assert_eq!(neighbors(&Rect::largest(), &Point::min(), &Connectivity::Eight), vec![(Point::new(MIN, MIN + 1), 1.0), (Point::new(MIN + 1, MIN), 1.0), (Point::new(MIN + 1, MIN + 1), SQRT_2)]);
+        assert_eq!(neighbors(&Rect::largest(), &Point::max(), &Connectivity::Eight), vec![(Point::new(MAX - 1, MAX - 1), SQRT_2), (Point::new(MAX - 1, MAX), 1.0), (Point::new(MAX, MAX - 1), 1.0)]);
+    }
+
+    #[test]
+    fn test_estimate() {
+        let (a, b) = (Point::new(1, 2), Point::new(4, 8));
+        assert_eq!(estimate(&Heuristic::Manhattan, &a, &b), 9.0);
+        assert_eq!(estimate(&Heuristic::Chebyshev, &a, &b), 6.0);
+        assert_eq!(estimate(&Heuristic::Octile, &a, &b), (SQRT_2 - 1.0).mul_add(3.0, 6.0));
+        assert_eq!(estimate(&Heuristic::Octile, &a, &a), 0.0);
+    }
+}
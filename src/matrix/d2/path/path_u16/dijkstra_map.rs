@@ -0,0 +1,107 @@
+use super::{Candidate, neighbors};
+use crate::matrix::d2::{
+    path::Connectivity,
+    point::point_u16::{Point, delta_col, delta_row},
+    rect::rect_u16::{self, Rect, contains_point},
+};
+use std::collections::BinaryHeap;
+
+pub fn dijkstra_map(bounds: &Rect, goals: &[Point], connectivity: &Connectivity, cost: impl Fn(&Point) -> Option<f64>) -> Vec<Vec<Option<f64>>> {
+    let mut map = vec![vec![None; rect_u16::delta_col(bounds) as usize + 1]; rect_u16::delta_row(bounds) as usize + 1];
+    let mut heap = BinaryHeap::new();
+    for (order, goal) in goals.iter().filter(|goal| contains_point(bounds, goal)).enumerate() {
+        map[delta_row(&bounds.min, goal) as usize][delta_col(&bounds.min, goal) as usize] = Some(0.0);
+        heap.push(Candidate { priority: 0.0, cost: 0.0, order, p: goal.clone() });
+    }
+    let mut order = heap.len();
+    while let Some(candidate) = heap.pop() {
+        if map[delta_row(&bounds.min, &candidate.p) as usize][delta_col(&bounds.min, &candidate.p) as usize].is_some_and(|known| known < candidate.cost) {
+            continue;
+        }
+        let Some(step) = cost(&candidate.p) else { continue };
+        for (neighbor, distance) in neighbors(bounds, &candidate.p, connectivity) {
+            if cost(&neighbor).is_none() {
+                continue;
+            }
+            let next = step.mul_add(distance, candidate.cost);
+            let cell = &mut map[delta_row(&bounds.min, &neighbor) as usize][delta_col(&bounds.min, &neighbor) as usize];
+            if cell.is_none_or(|known| next < known) {
+                *cell = Some(next);
+                heap.push(Candidate { priority: next, cost: next, order, p: neighbor });
+                order += 1;
+            }
+        }
+    }
+    map
+}
+
+#[cfg(test)]
+mod tests {
+    use super::dijkstra_map;
+    use crate::matrix::d1::point::point_u16::MAX;
+    use crate::matrix::d2::{
+        path::{Connectivity, Heuristic, path_u16::a_star},
+        point::point_u16::Point,
+        rect::rect_u16::Rect,
+    };
+    use std::f64::consts::SQRT_2;
+
+    fn wall(p: &Point) -> Option<f64> {
+        if p.col == 2 && p.row <= 3 { None } else { Some(1.0) }
+    }
+
+    #[test]
+    fn test_dijkstra_map() {
+        let bounds = Rect::new((0, 0), (4, 4));
+        assert_eq!(
+            dijkstra_map(&bounds, &[Point::new(0, 4)], &Connectivity::Four, wall),
+            vec![
+                vec![Some(12.0), Some(11.0), None, Some(1.0), Some(0.0)],
+                vec![Some(11.0), Some(10.0), None, Some(2.0), Some(1.0)],
+                vec![Some(10.0), Some(9.0), None, Some(3.0), Some(2.0)],
+                vec![Some(9.0), Some(8.0), None, Some(4.0), Some(3.0)],
+                vec![Some(8.0), Some(7.0), Some(6.0), Some(5.0), Some(4.0)],
+            ]
+        );
+        assert_eq!(
+            dijkstra_map(&bounds, &[Point::new(0, 0), Point::new(0, 4)], &Connectivity::Four, wall),
+            vec![
+                vec![Some(0.0), Some(1.0), None, Some(1.0), Some(0.0)],
+                vec![Some(1.0), Some(2.0), None, Some(2.0), Some(1.0)],
+                vec![Some(2.0), Some(3.0), None, Some(3.0), Some(2.0)],
+                vec![Some(3.0), Some(4.0), None, Some(4.0), Some(3.0)],
+                vec![Some(4.0), Some(5.0), Some(6.0), Some(5.0), Some(4.0)],
+            ]
+        );
+    }
+
+    #[test]
+    fn dijkstra_map_cost() {
+        let bounds = Rect::new((0, 0), (4, 4));
+        let swamp = |p: &Point| if p.col == 2 && p.row <= 3 { None } else { Some(if p.row == 4 { 3.0 } else { 1.0 }) };
+        let map = dijkstra_map(&bounds, &[Point::new(0, 4)], &Connectivity::Eight, swamp);
+        for row in 0..5 {
+            for col in 0..5 {
+                let expected = a_star(&bounds, &Point::new(row, col), &Point::new(0, 4), &Connectivity::Eight, &Heuristic::Octile, swamp).map(|(_, cost)| cost);
+                match (map[row as usize][col as usize], expected) {
+                    (Some(value), Some(expected)) => assert!((value - expected).abs() < 1e-9),
+                    (value, expected) => assert_eq!(value, expected),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn dijkstra_map_empty() {
+        let bounds = Rect::new((0, 0), (1, 2));
+        assert_eq!(dijkstra_map(&bounds, &[], &Connectivity::Four, wall), vec![vec![None, None, None], vec![None, None, None]]);
+        assert_eq!(dijkstra_map(&bounds, &[Point::new(3, 3)], &Connectivity::Four, wall), vec![vec![None, None, None], vec![None, None, None]]);
+    }
+
+    #[test]
+    fn dijkstra_map_bounds() {
+        let open = |_: &Point| Some(1.0);
+        assert_eq!(dijkstra_map(&Rect::new((MAX - 1, MAX - 1), (MAX, MAX)), &[Point::max()], &Connectivity::Eight, open), vec![vec![Some(SQRT_2), Some(1.0)], vec![Some(1.0), Some(0.0)]]);
+        assert_eq!(dijkstra_map(&Rect::new((0, 0), (1, 1)), &[Point::min()], &Connectivity::Four, open), vec![vec![Some(0.0), Some(1.0)], vec![Some(1.0), Some(2.0)]]);
+    }
+}
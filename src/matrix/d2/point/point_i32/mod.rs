@@ -1,11 +1,16 @@
 use super::{point_i8, point_i16};
-use crate::matrix::d1::point::point_i32::{MAX, MIN};
+use crate::matrix::{
+    d1::point::point_i32::{MAX, MIN},
+    d2::rect::rect_i32::Rect,
+};
 
 mod add;
 mod delta;
+mod neighborhood;
 
 pub use self::add::{checked_add, checked_add_assign, saturating_add, saturating_add_assign, try_checked_add, try_checked_add_assign, wrapping_add, wrapping_add_assign};
 pub use self::delta::{delta, delta_col, delta_max, delta_min, delta_row};
+pub use self::neighborhood::Neighborhood;
 
 #[derive(Eq, PartialEq, Debug, Clone, Hash)]
 pub struct Point {
@@ -26,6 +31,22 @@ impl Point {
         Point { row: MAX, col: MAX }
     }
 
+    pub fn iter_von_neumann(&self, radius: u32, bounds: &Rect) -> Neighborhood {
+        neighborhood::neighborhood(self, radius, bounds, true, false)
+    }
+
+    pub fn iter_moore(&self, radius: u32, bounds: &Rect) -> Neighborhood {
+        neighborhood::neighborhood(self, radius, bounds, false, false)
+    }
+
+    pub fn iter_von_neumann_toroidal(&self, radius: u32, bounds: &Rect) -> Neighborhood {
+        neighborhood::neighborhood(self, radius, bounds, true, true)
+    }
+
+    pub fn iter_moore_toroidal(&self, radius: u32, bounds: &Rect) -> Neighborhood {
+        neighborhood::neighborhood(self, radius, bounds, false, true)
+    }
+
     pub fn zero() -> Self {
         Point { row: 0, col: 0 }
     }
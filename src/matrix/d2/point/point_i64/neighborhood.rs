@@ -0,0 +1,186 @@
+use super::Point;
+use crate::matrix::d2::rect::rect_i64::Rect;
+
+#[derive(Debug, PartialEq)]
+pub struct Neighborhood {
+    center: (i128, i128),
+    radius: i128,
+    von_neumann: bool,
+    toroidal: bool,
+    min: (i128, i128),
+    max: (i128, i128),
+    row: i128,
+    row_end: i128,
+    col: i128,
+    col_end: i128,
+}
+
+fn wrapped(center: i128, radius: i128, min: i128, max: i128) -> (i128, i128) {
+    let size = max - min + 1;
+    (center - radius.min((size - 1) / 2), center + radius.min(size / 2))
+}
+
+pub fn neighborhood(center: &Point, radius: u64, bounds: &Rect, von_neumann: bool, toroidal: bool) -> Neighborhood {
+    let center = (i128::from(center.row), i128::from(center.col));
+    let radius = i128::from(radius);
+    let min = (i128::from(bounds.min.row), i128::from(bounds.min.col));
+    let max = (i128::from(bounds.max.row), i128::from(bounds.max.col));
+    let (row, row_end) = if toroidal { wrapped(center.0, radius, min.0, max.0) } else { ((center.0 - radius).max(min.0), (center.0 + radius).min(max.0)) };
+    let mut neighborhood = Neighborhood { center, radius, von_neumann, toroidal, min, max, row, row_end, col: 0, col_end: 0 };
+    (neighborhood.col, neighborhood.col_end) = neighborhood.span();
+    neighborhood
+}
+
+impl Neighborhood {
+    fn span(&self) -> (i128, i128) {
+        let span = if self.von_neumann { self.radius - (self.row - self.center.0).abs() } else { self.radius };
+        if self.toroidal { wrapped(self.center.1, span, self.min.1, self.max.1) } else { ((self.center.1 - span).max(self.min.1), (self.center.1 + span).min(self.max.1)) }
+    }
+}
+
+impl Iterator for Neighborhood {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.row > self.row_end {
+                return None;
+            }
+            if self.col > self.col_end {
+                self.row += 1;
+                (self.col, self.col_end) = self.span();
+                continue;
+            }
+            let (mut row, mut col) = (self.row, self.col);
+            self.col += 1;
+            if (row, col) == self.center {
+                continue;
+            }
+            if self.toroidal {
+                row = self.min.0 + (row - self.min.0).rem_euclid(self.max.0 - self.min.0 + 1);
+                col = self.min.1 + (col - self.min.1).rem_euclid(self.max.1 - self.min.1 + 1);
+            }
+            return Some(Point::new(row as i64, col as i64));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::{
+        d1::point::point_i64::{MAX, MIN},
+        d2::{
+            point::point_i64::{Point, wrapping_add},
+            rect::rect_i64::Rect,
+        },
+    };
+    use std::collections::HashSet;
+
+    #[test]
+    fn iter_von_neumann() {
+        let bounds = Rect::new((0, 0), (10, 10));
+        assert_eq!(Point::new(5, 5).iter_von_neumann(0, &bounds).collect::<Vec<Point>>(), []);
+        assert_eq!(Point::new(5, 5).iter_von_neumann(1, &bounds).collect::<Vec<Point>>(), [Point::new(4, 5), Point::new(5, 4), Point::new(5, 6), Point::new(6, 5)]);
+        assert_eq!(
+            Point::new(5, 5).iter_von_neumann(2, &bounds).collect::<Vec<Point>>(),
+            [
+                Point::new(3, 5),
+                Point::new(4, 4),
+                Point::new(4, 5),
+                Point::new(4, 6),
+                Point::new(5, 3),
+                Point::new(5, 4),
+                Point::new(5, 6),
+                Point::new(5, 7),
+                Point::new(6, 4),
+                Point::new(6, 5),
+                Point::new(6, 6),
+                Point::new(7, 5),
+            ]
+        );
+        assert_eq!(Point::new(5, 5).iter_von_neumann(5, &bounds).count(), 60);
+    }
+
+    #[test]
+    fn iter_von_neumann_clipped() {
+        let bounds = Rect::new((0, 0), (10, 10));
+        assert_eq!(Point::new(0, 0).iter_von_neumann(2, &bounds).collect::<Vec<Point>>(), [Point::new(0, 1), Point::new(0, 2), Point::new(1, 0), Point::new(1, 1), Point::new(2, 0)]);
+        assert_eq!(Point::new(10, 5).iter_von_neumann(1, &bounds).collect::<Vec<Point>>(), [Point::new(9, 5), Point::new(10, 4), Point::new(10, 6)]);
+        assert_eq!(Point::new(5, 5).iter_von_neumann(1, &Rect::new((5, 5), (5, 5))).collect::<Vec<Point>>(), []);
+        assert_eq!(Point::new(12, 5).iter_von_neumann(2, &bounds).collect::<Vec<Point>>(), [Point::new(10, 5)]);
+    }
+
+    #[test]
+    fn iter_von_neumann_bounds() {
+        assert_eq!(Point::min().iter_von_neumann(1, &Rect::largest()).collect::<Vec<Point>>(), [Point::new(MIN, MIN + 1), Point::new(MIN + 1, MIN)]);
+        assert_eq!(Point::max().iter_von_neumann(1, &Rect::largest()).collect::<Vec<Point>>(), [Point::new(MAX - 1, MAX), Point::new(MAX, MAX - 1)]);
+        assert_eq!(Point::max().iter_von_neumann(u64::MAX, &Rect::new((MAX - 1, MAX - 1), (MAX, MAX))).count(), 3);
+    }
+
+    #[test]
+    fn iter_moore() {
+        let bounds = Rect::new((0, 0), (10, 10));
+        assert_eq!(Point::new(5, 5).iter_moore(0, &bounds).collect::<Vec<Point>>(), []);
+        assert_eq!(
+            Point::new(5, 5).iter_moore(1, &bounds).collect::<Vec<Point>>(),
+            [Point::new(4, 4), Point::new(4, 5), Point::new(4, 6), Point::new(5, 4), Point::new(5, 6), Point::new(6, 4), Point::new(6, 5), Point::new(6, 6)]
+        );
+        assert_eq!(Point::new(5, 5).iter_moore(2, &bounds).count(), 24);
+        assert_eq!(Point::new(5, 5).iter_moore(5, &bounds).count(), 120);
+    }
+
+    #[test]
+    fn iter_moore_clipped() {
+        let bounds = Rect::new((0, 0), (10, 10));
+        assert_eq!(Point::new(0, 0).iter_moore(1, &bounds).collect::<Vec<Point>>(), [Point::new(0, 1), Point::new(1, 0), Point::new(1, 1)]);
+        assert_eq!(Point::new(10, 10).iter_moore(2, &bounds).count(), 8);
+        assert_eq!(Point::new(5, 5).iter_moore(3, &Rect::new((4, 5), (5, 6))).collect::<Vec<Point>>(), [Point::new(4, 5), Point::new(4, 6), Point::new(5, 6)]);
+    }
+
+    #[test]
+    fn iter_moore_bounds() {
+        assert_eq!(Point::min().iter_moore(1, &Rect::largest()).collect::<Vec<Point>>(), [Point::new(MIN, MIN + 1), Point::new(MIN + 1, MIN), Point::new(MIN + 1, MIN + 1)]);
+        assert_eq!(Point::max().iter_moore(1, &Rect::largest()).collect::<Vec<Point>>(), [Point::new(MAX - 1, MAX - 1), Point::new(MAX - 1, MAX), Point::new(MAX, MAX - 1)]);
+        assert_eq!(Point::max().iter_moore(u64::MAX, &Rect::new((MAX - 2, MAX - 2), (MAX, MAX))).count(), 8);
+    }
+
+    #[test]
+    fn iter_von_neumann_toroidal() {
+        let bounds = Rect::new((0, 0), (4, 4));
+        assert_eq!(Point::new(0, 0).iter_von_neumann_toroidal(1, &bounds).collect::<Vec<Point>>(), [Point::new(4, 0), Point::new(0, 4), Point::new(0, 1), Point::new(1, 0)]);
+        assert_eq!(Point::new(2, 2).iter_von_neumann_toroidal(2, &bounds).collect::<Vec<Point>>(), Point::new(2, 2).iter_von_neumann(2, &bounds).collect::<Vec<Point>>());
+        assert_eq!(
+            Point::new(12, 11).iter_von_neumann_toroidal(1, &Rect::new((10, 10), (12, 12))).collect::<Vec<Point>>(),
+            [Point::new(11, 11), Point::new(12, 10), Point::new(12, 12), Point::new(10, 11)]
+        );
+        assert_eq!(Point::new(0, 0).iter_von_neumann_toroidal(3, &bounds).count(), 20);
+        assert_eq!(Point::new(0, 0).iter_von_neumann_toroidal(4, &bounds).count(), 24);
+        assert_eq!(Point::new(5, 5).iter_von_neumann_toroidal(2, &Rect::new((5, 5), (5, 5))).collect::<Vec<Point>>(), []);
+    }
+
+    #[test]
+    fn iter_moore_toroidal() {
+        let bounds = Rect::new((0, 0), (4, 4));
+        assert_eq!(
+            Point::new(0, 0).iter_moore_toroidal(1, &bounds).collect::<Vec<Point>>(),
+            [Point::new(4, 4), Point::new(4, 0), Point::new(4, 1), Point::new(0, 4), Point::new(0, 1), Point::new(1, 4), Point::new(1, 0), Point::new(1, 1)]
+        );
+        assert_eq!(Point::new(0, 0).iter_moore_toroidal(1, &Rect::new((0, 0), (1, 1))).collect::<Vec<Point>>(), [Point::new(0, 1), Point::new(1, 0), Point::new(1, 1)]);
+        let cells: Vec<Point> = Point::new(1, 1).iter_moore_toroidal(10, &Rect::new((0, 0), (3, 3))).collect();
+        assert_eq!(cells.len(), 15);
+        assert_eq!(cells.iter().collect::<HashSet<&Point>>().len(), 15);
+        assert!(!cells.contains(&Point::new(1, 1)));
+        assert_eq!(Point::new(5, 5).iter_moore_toroidal(2, &Rect::new((5, 5), (5, 5))).collect::<Vec<Point>>(), []);
+    }
+
+    #[test]
+    fn iter_toroidal_bounds() {
+        let deltas = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+        for p in [Point::min(), Point::max(), Point::new(MIN, MAX), Point::new(MAX, MIN)] {
+            let expected: Vec<Point> = deltas.iter().map(|(row, col)| wrapping_add(&p, &Point::new(*row, *col))).collect();
+            assert_eq!(p.iter_moore_toroidal(1, &Rect::largest()).collect::<Vec<Point>>(), expected);
+            let expected: Vec<Point> = [1, 3, 4, 6].iter().map(|i| expected[*i].clone()).collect();
+            assert_eq!(p.iter_von_neumann_toroidal(1, &Rect::largest()).collect::<Vec<Point>>(), expected);
+        }
+    }
+}
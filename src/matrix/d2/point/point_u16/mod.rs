@@ -1,11 +1,13 @@
 use super::point_u8;
-use crate::matrix::d1::point::point_u16::MAX;
+use crate::matrix::{d1::point::point_u16::MAX, d2::rect::rect_u16::Rect};
 
 mod add;
 mod delta;
+mod neighborhood;
 
 pub use self::add::{checked_add, checked_add_assign, saturating_add, saturating_add_assign, try_checked_add, try_checked_add_assign, wrapping_add, wrapping_add_assign};
 pub use self::delta::{delta, delta_col, delta_max, delta_min, delta_row};
+pub use self::neighborhood::Neighborhood;
 
 #[derive(Eq, PartialEq, Debug, Clone, Hash)]
 pub struct Point {
@@ -25,6 +27,22 @@ impl Point {
     pub fn max() -> Self {
         Point { row: MAX, col: MAX }
     }
+
+    pub fn iter_von_neumann(&self, radius: u16, bounds: &Rect) -> Neighborhood {
+        neighborhood::neighborhood(self, radius, bounds, true, false)
+    }
+
+    pub fn iter_moore(&self, radius: u16, bounds: &Rect) -> Neighborhood {
+        neighborhood::neighborhood(self, radius, bounds, false, false)
+    }
+
+    pub fn iter_von_neumann_toroidal(&self, radius: u16, bounds: &Rect) -> Neighborhood {
+        neighborhood::neighborhood(self, radius, bounds, true, true)
+    }
+
+    pub fn iter_moore_toroidal(&self, radius: u16, bounds: &Rect) -> Neighborhood {
+        neighborhood::neighborhood(self, radius, bounds, false, true)
+    }
 }
 
 impl std::fmt::Display for Point {
@@ -0,0 +1,85 @@
+use super::{Rect, intersection};
+
+pub fn difference(a: &Rect, b: &Rect) -> Vec<Rect> {
+    let Some(i) = intersection(a, b) else {
+        return vec![a.clone()];
+    };
+    let mut result = Vec::with_capacity(4);
+    if i.min.row > a.min.row {
+        result.push(Rect::new((a.min.row, a.min.col), (i.min.row - 1, a.max.col)));
+    }
+    if i.max.row < a.max.row {
+        result.push(Rect::new((i.max.row + 1, a.min.col), (a.max.row, a.max.col)));
+    }
+    if i.min.col > a.min.col {
+        result.push(Rect::new((i.min.row, a.min.col), (i.max.row, i.min.col - 1)));
+    }
+    if i.max.col < a.max.col {
+        result.push(Rect::new((i.min.row, i.max.col + 1), (i.max.row, a.max.col)));
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::difference;
+    use crate::matrix::{
+        d1::point::point_i64::{MAX, MIN},
+        d2::rect::rect_i64::Rect,
+    };
+
+    #[test]
+    fn outside() {
+        let r = Rect::new((10, 10), (20, 20));
+        assert_eq!(difference(&r, &Rect::new((21, 10), (30, 20))), [Rect::new((10, 10), (20, 20))]);
+        assert_eq!(difference(&r, &Rect::new((0, 10), (9, 20))), [Rect::new((10, 10), (20, 20))]);
+        assert_eq!(difference(&r, &Rect::new((10, 21), (20, 30))), [Rect::new((10, 10), (20, 20))]);
+        assert_eq!(difference(&r, &Rect::new((10, 0), (20, 9))), [Rect::new((10, 10), (20, 20))]);
+    }
+
+    #[test]
+    fn covered() {
+        let r = Rect::new((10, 10), (20, 20));
+        assert_eq!(difference(&r, &r), []);
+        assert_eq!(difference(&r, &Rect::new((0, 0), (30, 30))), []);
+        assert_eq!(difference(&r, &Rect::new((10, 10), (30, 30))), []);
+        assert_eq!(difference(&r, &Rect::new((0, 0), (20, 20))), []);
+    }
+
+    #[test]
+    fn inside() {
+        let r = Rect::new((10, 10), (20, 20));
+        assert_eq!(difference(&r, &Rect::new((12, 13), (18, 17))), [Rect::new((10, 10), (11, 20)), Rect::new((19, 10), (20, 20)), Rect::new((12, 10), (18, 12)), Rect::new((12, 18), (18, 20))]);
+        assert_eq!(difference(&r, &Rect::new((15, 15), (15, 15))), [Rect::new((10, 10), (14, 20)), Rect::new((16, 10), (20, 20)), Rect::new((15, 10), (15, 14)), Rect::new((15, 16), (15, 20))]);
+    }
+
+    #[test]
+    fn corners() {
+        let r = Rect::new((10, 10), (20, 20));
+        assert_eq!(difference(&r, &Rect::new((0, 0), (15, 15))), [Rect::new((16, 10), (20, 20)), Rect::new((10, 16), (15, 20))]);
+        assert_eq!(difference(&r, &Rect::new((0, 15), (15, 30))), [Rect::new((16, 10), (20, 20)), Rect::new((10, 10), (15, 14))]);
+        assert_eq!(difference(&r, &Rect::new((15, 0), (30, 15))), [Rect::new((10, 10), (14, 20)), Rect::new((15, 16), (20, 20))]);
+        assert_eq!(difference(&r, &Rect::new((15, 15), (30, 30))), [Rect::new((10, 10), (14, 20)), Rect::new((15, 10), (20, 14))]);
+    }
+
+    #[test]
+    fn edges() {
+        let r = Rect::new((10, 10), (20, 20));
+        assert_eq!(difference(&r, &Rect::new((0, 0), (12, 30))), [Rect::new((13, 10), (20, 20))]);
+        assert_eq!(difference(&r, &Rect::new((18, 0), (30, 30))), [Rect::new((10, 10), (17, 20))]);
+        assert_eq!(difference(&r, &Rect::new((0, 0), (30, 12))), [Rect::new((10, 13), (20, 20))]);
+        assert_eq!(difference(&r, &Rect::new((0, 18), (30, 30))), [Rect::new((10, 10), (20, 17))]);
+        assert_eq!(difference(&r, &Rect::new((0, 14), (30, 16))), [Rect::new((10, 10), (20, 13)), Rect::new((10, 17), (20, 20))]);
+        assert_eq!(difference(&r, &Rect::new((14, 0), (16, 30))), [Rect::new((10, 10), (13, 20)), Rect::new((17, 10), (20, 20))]);
+    }
+
+    #[test]
+    fn bounds() {
+        assert_eq!(difference(&Rect::largest(), &Rect::largest()), []);
+        assert_eq!(difference(&Rect::min(), &Rect::max()), [Rect::min()]);
+        assert_eq!(
+            difference(&Rect::largest(), &Rect::new((MIN + 1, MIN + 1), (MAX - 1, MAX - 1))),
+            [Rect::new((MIN, MIN), (MIN, MAX)), Rect::new((MAX, MIN), (MAX, MAX)), Rect::new((MIN + 1, MIN), (MAX - 1, MIN)), Rect::new((MIN + 1, MAX), (MAX - 1, MAX))]
+        );
+    }
+}
@@ -0,0 +1,9 @@
+pub mod region_u16;
+pub mod region_u32;
+pub mod region_u64;
+pub mod region_u8;
+
+pub mod region_i16;
+pub mod region_i32;
+pub mod region_i64;
+pub mod region_i8;
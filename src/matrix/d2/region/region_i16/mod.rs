@@ -0,0 +1,13 @@
+use crate::matrix::d2::rect::rect_i16::Rect;
+
+mod flood_fill;
+mod label_components;
+
+pub use self::flood_fill::flood_fill;
+pub use self::label_components::label_components;
+
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct Component {
+    pub bounds: Rect,
+    pub count: usize,
+}
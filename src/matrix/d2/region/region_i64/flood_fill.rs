@@ -0,0 +1,110 @@
+use crate::matrix::d2::{
+    path::Connectivity,
+    point::point_i64::Point,
+    rect::rect_i64::{Rect, contains_point},
+};
+use std::collections::HashSet;
+
+pub fn flood_fill(bounds: &Rect, seed: &Point, connectivity: &Connectivity, predicate: impl Fn(&Point) -> bool) -> Vec<Point> {
+    let fillable = |p: &Point, visited: &HashSet<Point>| contains_point(bounds, p) && !visited.contains(p) && predicate(p);
+    let mut filled = Vec::new();
+    let mut visited = HashSet::new();
+    let mut seeds = vec![seed.clone()];
+    while let Some(p) = seeds.pop() {
+        if !fillable(&p, &visited) {
+            continue;
+        }
+        let mut min = p.col;
+        while min > bounds.min.col && fillable(&Point::new(p.row, min - 1), &visited) {
+            min -= 1;
+        }
+        let mut max = p.col;
+        while max < bounds.max.col && fillable(&Point::new(p.row, max + 1), &visited) {
+            max += 1;
+        }
+        for col in min..=max {
+            let cell = Point::new(p.row, col);
+            visited.insert(cell.clone());
+            filled.push(cell);
+        }
+        let (from, to) = match connectivity {
+            Connectivity::Four => (min, max),
+            Connectivity::Eight => (if min > bounds.min.col { min - 1 } else { min }, if max < bounds.max.col { max + 1 } else { max }),
+        };
+        for row in [p.row.checked_sub(1), p.row.checked_add(1)].into_iter().flatten() {
+            let mut inside = false;
+            for col in from..=to {
+                let cell = Point::new(row, col);
+                let fill = fillable(&cell, &visited);
+                if fill && !inside {
+                    seeds.push(cell);
+                }
+                inside = fill;
+            }
+        }
+    }
+    filled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::flood_fill;
+    use crate::matrix::d1::point::point_i64::{MAX, MIN};
+    use crate::matrix::d2::{path::Connectivity, point::point_i64::Point, rect::rect_i64::Rect};
+
+    const GRID: [&str; 5] = ["..#..", "..#..", "##.##", "...#.", ".#.#."];
+
+    fn open(p: &Point) -> bool {
+        GRID[p.row as usize].as_bytes()[p.col as usize] == b'.'
+    }
+
+    fn sorted(mut points: Vec<Point>) -> Vec<Point> {
+        points.sort_by_key(|p| (p.row, p.col));
+        points
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let bounds = Rect::new((0, 0), (4, 4));
+        assert_eq!(flood_fill(&bounds, &Point::new(0, 0), &Connectivity::Four, open), vec![Point::new(0, 0), Point::new(0, 1), Point::new(1, 0), Point::new(1, 1)]);
+        assert_eq!(
+            sorted(flood_fill(&bounds, &Point::new(4, 2), &Connectivity::Four, open)),
+            vec![Point::new(2, 2), Point::new(3, 0), Point::new(3, 1), Point::new(3, 2), Point::new(4, 0), Point::new(4, 2)]
+        );
+        assert_eq!(sorted(flood_fill(&bounds, &Point::new(4, 4), &Connectivity::Eight, open)), vec![Point::new(3, 4), Point::new(4, 4)]);
+        let filled = sorted(flood_fill(&bounds, &Point::new(0, 0), &Connectivity::Eight, open));
+        assert_eq!(filled.len(), 14);
+        assert!(filled.iter().all(open));
+        assert!(!filled.contains(&Point::new(3, 4)));
+    }
+
+    #[test]
+    fn flood_fill_clipped() {
+        let bounds = Rect::new((0, 0), (1, 4));
+        assert_eq!(flood_fill(&bounds, &Point::new(1, 1), &Connectivity::Eight, open), vec![Point::new(1, 0), Point::new(1, 1), Point::new(0, 0), Point::new(0, 1)]);
+        assert_eq!(
+            sorted(flood_fill(&Rect::new((1, 1), (3, 3)), &Point::new(2, 2), &Connectivity::Eight, open)),
+            vec![Point::new(1, 1), Point::new(1, 3), Point::new(2, 2), Point::new(3, 1), Point::new(3, 2)]
+        );
+    }
+
+    #[test]
+    fn flood_fill_empty() {
+        let bounds = Rect::new((0, 0), (4, 4));
+        assert_eq!(flood_fill(&bounds, &Point::new(0, 2), &Connectivity::Four, open), vec![]);
+        assert_eq!(flood_fill(&Rect::new((0, 0), (1, 1)), &Point::new(0, 3), &Connectivity::Four, open), vec![]);
+    }
+
+    #[test]
+    fn flood_fill_bounds() {
+        let all = |_: &Point| true;
+        assert_eq!(
+            flood_fill(&Rect::new((MAX - 1, MAX - 1), (MAX, MAX)), &Point::max(), &Connectivity::Four, all),
+            vec![Point::new(MAX, MAX - 1), Point::max(), Point::new(MAX - 1, MAX - 1), Point::new(MAX - 1, MAX)]
+        );
+        assert_eq!(
+            flood_fill(&Rect::new((MIN, MIN), (MIN + 1, MIN + 1)), &Point::min(), &Connectivity::Four, all),
+            vec![Point::min(), Point::new(MIN, MIN + 1), Point::new(MIN + 1, MIN), Point::new(MIN + 1, MIN + 1)]
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use super::Component;
+use crate::matrix::d2::{
+    path::Connectivity,
+    point::point_i64::Point,
+    rect::rect_i64::{Rect, delta_col, delta_row},
+};
+
+fn find(parent: &mut [usize], mut label: usize) -> usize {
+    while parent[label] != label {
+        parent[label] = parent[parent[label]];
+        label = parent[label];
+    }
+    label
+}
+
+fn union(parent: &mut [usize], a: usize, b: usize) {
+    let (a, b) = (find(parent, a), find(parent, b));
+    parent[a.max(b)] = a.min(b);
+}
+
+pub fn label_components(bounds: &Rect, connectivity: &Connectivity, predicate: impl Fn(&Point) -> bool) -> (Vec<Vec<Option<usize>>>, Vec<Component>) {
+    let mut labels = vec![vec![None; delta_col(bounds) as usize + 1]; delta_row(bounds) as usize + 1];
+    let mut parent = Vec::new();
+    for (i, row) in (bounds.min.row..=bounds.max.row).enumerate() {
+        for (j, col) in (bounds.min.col..=bounds.max.col).enumerate() {
+            if !predicate(&Point::new(row, col)) {
+                continue;
+            }
+            let mut neighbors = Vec::new();
+            if j > 0 {
+                neighbors.push(labels[i][j - 1]);
+            }
+            if i > 0 {
+                neighbors.push(labels[i - 1][j]);
+                if *connectivity == Connectivity::Eight {
+                    if j > 0 {
+                        neighbors.push(labels[i - 1][j - 1]);
+                    }
+                    if j + 1 < labels[i].len() {
+                        neighbors.push(labels[i - 1][j + 1]);
+                    }
+                }
+            }
+            let neighbors: Vec<usize> = neighbors.into_iter().flatten().collect();
+            let label = match neighbors.iter().min() {
+                Some(label) => *label,
+                None => {
+                    parent.push(parent.len());
+                    parent.len() - 1
+                }
+            };
+            for neighbor in neighbors {
+                union(&mut parent, label, neighbor);
+            }
+            labels[i][j] = Some(label);
+        }
+    }
+    let mut ids = vec![None; parent.len()];
+    let mut components: Vec<Component> = Vec::new();
+    for (i, row) in (bounds.min.row..=bounds.max.row).enumerate() {
+        for (j, col) in (bounds.min.col..=bounds.max.col).enumerate() {
+            let Some(label) = labels[i][j] else { continue };
+            let root = find(&mut parent, label);
+            let id = *ids[root].get_or_insert_with(|| {
+                components.push(Component { bounds: Rect::new((row, col), (row, col)), count: 0 });
+                components.len() - 1
+            });
+            let component = &mut components[id];
+            component.bounds.min.col = component.bounds.min.col.min(col);
+            component.bounds.max.row = row;
+            component.bounds.max.col = component.bounds.max.col.max(col);
+            component.count += 1;
+            labels[i][j] = Some(id);
+        }
+    }
+    (labels, components)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::label_components;
+    use crate::matrix::d1::point::point_i64::{MAX, MIN};
+    use crate::matrix::d2::{path::Connectivity, point::point_i64::Point, rect::rect_i64::Rect, region::region_i64::Component};
+
+    const GRID: [&str; 5] = ["..#..", "..#..", "##.##", "...#.", ".#.#."];
+
+    fn open(p: &Point) -> bool {
+        GRID[p.row as usize].as_bytes()[p.col as usize] == b'.'
+    }
+
+    #[test]
+    fn test_label_components() {
+        let bounds = Rect::new((0, 0), (4, 4));
+        assert_eq!(
+            label_components(&bounds, &Connectivity::Four, open),
+            (
+                vec![
+                    vec![Some(0), Some(0), None, Some(1), Some(1)],
+                    vec![Some(0), Some(0), None, Some(1), Some(1)],
+                    vec![None, None, Some(2), None, None],
+                    vec![Some(2), Some(2), Some(2), None, Some(3)],
+                    vec![Some(2), None, Some(2), None, Some(3)],
+                ],
+                vec![
+                    Component { bounds: Rect::new((0, 0), (1, 1)), count: 4 },
+                    Component { bounds: Rect::new((0, 3), (1, 4)), count: 4 },
+                    Component { bounds: Rect::new((2, 0), (4, 2)), count: 6 },
+                    Component { bounds: Rect::new((3, 4), (4, 4)), count: 2 },
+                ]
+            )
+        );
+        assert_eq!(
+            label_components(&bounds, &Connectivity::Eight, open),
+            (
+                vec![
+                    vec![Some(0), Some(0), None, Some(0), Some(0)],
+                    vec![Some(0), Some(0), None, Some(0), Some(0)],
+                    vec![None, None, Some(0), None, None],
+                    vec![Some(0), Some(0), Some(0), None, Some(1)],
+                    vec![Some(0), None, Some(0), None, Some(1)],
+                ],
+                vec![Component { bounds: Rect::new((0, 0), (4, 4)), count: 14 }, Component { bounds: Rect::new((3, 4), (4, 4)), count: 2 }]
+            )
+        );
+    }
+
+    #[test]
+    fn label_components_merge() {
+        let u = |p: &Point| p.col == 0 || p.col == 4 || p.row == 3;
+        assert_eq!(
+            label_components(&Rect::new((0, 0), (3, 4)), &Connectivity::Four, u),
+            (
+                vec![vec![Some(0), None, None, None, Some(0)], vec![Some(0), None, None, None, Some(0)], vec![Some(0), None, None, None, Some(0)], vec![Some(0), Some(0), Some(0), Some(0), Some(0)],],
+                vec![Component { bounds: Rect::new((0, 0), (3, 4)), count: 11 }]
+            )
+        );
+    }
+
+    #[test]
+    fn label_components_clipped() {
+        assert_eq!(
+            label_components(&Rect::new((0, 0), (1, 4)), &Connectivity::Eight, open),
+            (
+                vec![vec![Some(0), Some(0), None, Some(1), Some(1)], vec![Some(0), Some(0), None, Some(1), Some(1)]],
+                vec![Component { bounds: Rect::new((0, 0), (1, 1)), count: 4 }, Component { bounds: Rect::new((0, 3), (1, 4)), count: 4 }]
+            )
+        );
+        assert_eq!(label_components(&Rect::new((0, 0), (1, 1)), &Connectivity::Four, |_: &Point| false), (vec![vec![None, None], vec![None, None]], vec![]));
+    }
+
+    #[test]
+    fn label_components_bounds() {
+        let diagonal = |p: &Point| p.row == p.col;
+        assert_eq!(
+            label_components(&Rect::new((MAX - 1, MAX - 1), (MAX, MAX)), &Connectivity::Four, diagonal),
+            (vec![vec![Some(0), None], vec![None, Some(1)]], vec![Component { bounds: Rect::new((MAX - 1, MAX - 1), (MAX - 1, MAX - 1)), count: 1 }, Component { bounds: Rect::max(), count: 1 }])
+        );
+        assert_eq!(
+            label_components(&Rect::new((MIN, MIN), (MIN + 1, MIN + 1)), &Connectivity::Eight, diagonal),
+            (vec![vec![Some(0), None], vec![None, Some(0)]], vec![Component { bounds: Rect::new((MIN, MIN), (MIN + 1, MIN + 1)), count: 2 }])
+        );
+    }
+}
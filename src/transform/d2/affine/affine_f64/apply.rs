@@ -0,0 +1,67 @@
+use super::Affine;
+use crate::cartesian::d2::{line::line_f64::Line, point::point_f64::Point, rect::rect_f64::Rect};
+
+pub fn apply_point(t: &Affine, p: &Point) -> Point {
+    Point::new(t.xx * p.x + t.xy * p.y + t.tx, t.yx * p.x + t.yy * p.y + t.ty)
+}
+
+pub fn apply_line(t: &Affine, l: &Line) -> Line {
+    Line { min: apply_point(t, &l.min), max: apply_point(t, &l.max) }
+}
+
+pub fn apply_rect(t: &Affine, r: &Rect) -> Rect {
+    let corners = [apply_point(t, &r.min), apply_point(t, &Point::new(r.max.x, r.min.y)), apply_point(t, &r.max), apply_point(t, &Point::new(r.min.x, r.max.y))];
+    let min_x = corners.iter().map(|p| p.x).fold(f64::INFINITY, f64::min);
+    let min_y = corners.iter().map(|p| p.y).fold(f64::INFINITY, f64::min);
+    let max_x = corners.iter().map(|p| p.x).fold(f64::NEG_INFINITY, f64::max);
+    let max_y = corners.iter().map(|p| p.y).fold(f64::NEG_INFINITY, f64::max);
+    Rect::new((min_x, min_y), (max_x, max_y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_line, apply_point, apply_rect};
+    use crate::{
+        cartesian::d2::{line::line_f64::Line, point::point_f64::Point, rect::rect_f64::Rect},
+        transform::d2::affine::affine_f64::{Affine, compose},
+    };
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    #[test]
+    fn test_apply_point() {
+        assert_eq!(apply_point(&Affine::identity(), &Point::new(3.0, -4.0)), Point::new(3.0, -4.0));
+        assert_eq!(apply_point(&Affine::translate(2.0, -1.0), &Point::new(3.0, -4.0)), Point::new(5.0, -5.0));
+        assert_eq!(apply_point(&Affine::scale(2.0, 0.5), &Point::new(3.0, -4.0)), Point::new(6.0, -2.0));
+        assert_eq!(apply_point(&Affine::shear(1.0, 0.0), &Point::new(3.0, -4.0)), Point::new(-1.0, -4.0));
+        assert_eq!(apply_point(&Affine::shear(0.0, 1.0), &Point::new(3.0, -4.0)), Point::new(3.0, -1.0));
+        assert_eq!(apply_point(&Affine::rotate(FRAC_PI_2), &Point::new(1.0, 0.0)), Point::new(FRAC_PI_2.cos(), 1.0));
+        assert_eq!(apply_point(&Affine::rotate(FRAC_PI_2), &Point::new(0.0, 1.0)), Point::new(-1.0, FRAC_PI_2.cos()));
+    }
+
+    #[test]
+    fn apply_point_composed() {
+        let zoom_at = compose(&compose(&Affine::translate(-10.0, -20.0), &Affine::scale(4.0, 4.0)), &Affine::translate(10.0, 20.0));
+        assert_eq!(apply_point(&zoom_at, &Point::new(10.0, 20.0)), Point::new(10.0, 20.0));
+        assert_eq!(apply_point(&zoom_at, &Point::new(11.0, 19.0)), Point::new(14.0, 16.0));
+    }
+
+    #[test]
+    fn test_apply_line() {
+        assert_eq!(apply_line(&Affine::translate(1.0, 2.0), &Line::new((-1.0, -1.0), (3.0, 4.0))), Line::new((0.0, 1.0), (4.0, 6.0)));
+        assert_eq!(apply_line(&Affine::scale(-1.0, 1.0), &Line::new((-1.0, -1.0), (3.0, 4.0))), Line::new((1.0, -1.0), (-3.0, 4.0)));
+    }
+
+    #[test]
+    fn test_apply_rect() {
+        assert_eq!(apply_rect(&Affine::identity(), &Rect::new((-1.0, -2.0), (3.0, 4.0))), Rect::new((-1.0, -2.0), (3.0, 4.0)));
+        assert_eq!(apply_rect(&Affine::translate(1.0, -1.0), &Rect::new((-1.0, -2.0), (3.0, 4.0))), Rect::new((0.0, -3.0), (4.0, 3.0)));
+        assert_eq!(apply_rect(&Affine::scale(-2.0, -1.0), &Rect::new((-1.0, -2.0), (3.0, 4.0))), Rect::new((-6.0, -4.0), (2.0, 2.0)));
+        assert_eq!(apply_rect(&Affine::shear(1.0, 0.0), &Rect::new((0.0, 0.0), (2.0, 2.0))), Rect::new((0.0, 0.0), (4.0, 2.0)));
+    }
+
+    #[test]
+    fn apply_rect_rotated() {
+        assert_eq!(apply_rect(&Affine::rotate(FRAC_PI_2), &Rect::new((0.0, 0.0), (2.0, 1.0))), Rect::new((-1.0, 0.0), (2.0 * FRAC_PI_2.cos(), 2.0)));
+        assert_eq!(apply_rect(&Affine::rotate(PI), &Rect::new((0.0, 0.0), (2.0, 1.0))), Rect::new((-2.0, -1.0), (0.0, 2.0 * PI.sin())));
+    }
+}
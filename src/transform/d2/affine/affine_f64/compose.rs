@@ -0,0 +1,47 @@
+use super::Affine;
+
+pub fn compose_assign(t: &mut Affine, next: &Affine) {
+    let result = compose(t, next);
+    *t = result;
+}
+
+pub fn compose(t: &Affine, next: &Affine) -> Affine {
+    Affine {
+        xx: next.xx * t.xx + next.xy * t.yx,
+        xy: next.xx * t.xy + next.xy * t.yy,
+        tx: next.xx * t.tx + next.xy * t.ty + next.tx,
+        yx: next.yx * t.xx + next.yy * t.yx,
+        yy: next.yx * t.xy + next.yy * t.yy,
+        ty: next.yx * t.tx + next.yy * t.ty + next.ty,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compose, compose_assign};
+    use crate::transform::d2::affine::affine_f64::Affine;
+
+    #[test]
+    fn test_compose_assign() {
+        let mut t = Affine::translate(2.0, 3.0);
+        compose_assign(&mut t, &Affine::scale(2.0, 4.0));
+        assert_eq!(t, Affine::new((2.0, 0.0, 4.0), (0.0, 4.0, 12.0)));
+        compose_assign(&mut t, &Affine::translate(-4.0, -12.0));
+        assert_eq!(t, Affine::scale(2.0, 4.0));
+    }
+
+    #[test]
+    fn test_compose() {
+        assert_eq!(compose(&Affine::translate(2.0, 3.0), &Affine::scale(2.0, 4.0)), Affine::new((2.0, 0.0, 4.0), (0.0, 4.0, 12.0)));
+        assert_eq!(compose(&Affine::scale(2.0, 4.0), &Affine::translate(2.0, 3.0)), Affine::new((2.0, 0.0, 2.0), (0.0, 4.0, 3.0)));
+        assert_eq!(compose(&Affine::shear(1.0, 0.0), &Affine::shear(0.0, 1.0)), Affine::new((1.0, 1.0, 0.0), (1.0, 2.0, 0.0)));
+        assert_eq!(compose(&Affine::shear(0.0, 1.0), &Affine::shear(1.0, 0.0)), Affine::new((2.0, 1.0, 0.0), (1.0, 1.0, 0.0)));
+    }
+
+    #[test]
+    fn compose_identity() {
+        let t = Affine::new((1.5, -2.0, 3.0), (4.0, 5.0, -6.25));
+        assert_eq!(compose(&t, &Affine::identity()), t);
+        assert_eq!(compose(&Affine::identity(), &t), t);
+    }
+}
@@ -0,0 +1,79 @@
+use super::Affine;
+
+pub fn try_invert_assign(t: &mut Affine) -> Option<()> {
+    *t = try_invert(t)?;
+    Some(())
+}
+
+pub fn try_invert(t: &Affine) -> Option<Affine> {
+    let det = t.xx * t.yy - t.xy * t.yx;
+    if det == 0.0 || !det.is_finite() {
+        return None;
+    }
+    let xx = t.yy / det;
+    let xy = -t.xy / det;
+    let yx = -t.yx / det;
+    let yy = t.xx / det;
+    Some(Affine { xx, xy, tx: -(xx * t.tx + xy * t.ty), yx, yy, ty: -(yx * t.tx + yy * t.ty) })
+}
+
+pub fn invert_assign(t: &mut Affine) {
+    try_invert_assign(t).unwrap()
+}
+
+pub fn invert(t: &Affine) -> Affine {
+    try_invert(t).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{invert, invert_assign, try_invert, try_invert_assign};
+    use crate::transform::d2::affine::affine_f64::{Affine, compose};
+
+    #[test]
+    fn test_try_invert_assign() {
+        let mut t = Affine::translate(2.0, -3.0);
+        assert_eq!(try_invert_assign(&mut t), Some(()));
+        assert_eq!(t, Affine::translate(-2.0, 3.0));
+        assert_eq!(try_invert_assign(&mut t), Some(()));
+        assert_eq!(t, Affine::translate(2.0, -3.0));
+    }
+
+    #[test]
+    fn test_try_invert() {
+        assert_eq!(try_invert(&Affine::identity()), Some(Affine::identity()));
+        assert_eq!(try_invert(&Affine::translate(2.0, -3.0)), Some(Affine::translate(-2.0, 3.0)));
+        assert_eq!(try_invert(&Affine::scale(2.0, -4.0)), Some(Affine::scale(0.5, -0.25)));
+        assert_eq!(try_invert(&Affine::shear(0.5, 0.0)), Some(Affine::shear(-0.5, 0.0)));
+        assert_eq!(try_invert(&Affine::new((2.0, 0.0, 4.0), (0.0, 4.0, 12.0))), Some(Affine::new((0.5, 0.0, -2.0), (0.0, 0.25, -3.0))));
+    }
+
+    #[test]
+    fn try_invert_round_trip() {
+        let t = Affine::new((2.0, 1.0, -3.0), (1.0, 1.0, 5.0));
+        assert_eq!(try_invert(&t), Some(Affine::new((1.0, -1.0, 8.0), (-1.0, 2.0, -13.0))));
+        assert_eq!(compose(&t, &try_invert(&t).unwrap()), Affine::identity());
+        assert_eq!(compose(&try_invert(&t).unwrap(), &t), Affine::identity());
+    }
+
+    #[test]
+    fn try_invert_singular() {
+        assert_eq!(try_invert(&Affine::scale(0.0, 1.0)), None);
+        assert_eq!(try_invert(&Affine::scale(1.0, 0.0)), None);
+        assert_eq!(try_invert(&Affine::new((1.0, 2.0, 3.0), (2.0, 4.0, 6.0))), None);
+        assert_eq!(try_invert(&Affine::scale(f64::MAX, f64::MAX)), None);
+        assert_eq!(try_invert(&Affine::scale(f64::NAN, 1.0)), None);
+    }
+
+    #[test]
+    fn test_invert_assign() {
+        let mut t = Affine::scale(2.0, -4.0);
+        invert_assign(&mut t);
+        assert_eq!(t, Affine::scale(0.5, -0.25));
+    }
+
+    #[test]
+    fn test_invert() {
+        assert_eq!(invert(&Affine::scale(2.0, -4.0)), Affine::scale(0.5, -0.25));
+    }
+}
@@ -0,0 +1,93 @@
+use super::affine_f32;
+
+mod apply;
+mod compose;
+mod invert;
+
+pub use self::apply::{apply_line, apply_point, apply_rect};
+pub use self::compose::{compose, compose_assign};
+pub use self::invert::{invert, invert_assign, try_invert, try_invert_assign};
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Affine {
+    pub xx: f64,
+    pub xy: f64,
+    pub tx: f64,
+    pub yx: f64,
+    pub yy: f64,
+    pub ty: f64,
+}
+
+impl Affine {
+    pub fn new(row_x: (f64, f64, f64), row_y: (f64, f64, f64)) -> Self {
+        Affine { xx: row_x.0, xy: row_x.1, tx: row_x.2, yx: row_y.0, yy: row_y.1, ty: row_y.2 }
+    }
+
+    pub fn identity() -> Self {
+        Affine { xx: 1.0, xy: 0.0, tx: 0.0, yx: 0.0, yy: 1.0, ty: 0.0 }
+    }
+
+    pub fn translate(dx: f64, dy: f64) -> Self {
+        Affine { xx: 1.0, xy: 0.0, tx: dx, yx: 0.0, yy: 1.0, ty: dy }
+    }
+
+    pub fn rotate(radians: f64) -> Self {
+        let (sin, cos) = radians.sin_cos();
+        Affine { xx: cos, xy: -sin, tx: 0.0, yx: sin, yy: cos, ty: 0.0 }
+    }
+
+    pub fn scale(sx: f64, sy: f64) -> Self {
+        Affine { xx: sx, xy: 0.0, tx: 0.0, yx: 0.0, yy: sy, ty: 0.0 }
+    }
+
+    pub fn shear(kx: f64, ky: f64) -> Self {
+        Affine { xx: 1.0, xy: kx, tx: 0.0, yx: ky, yy: 1.0, ty: 0.0 }
+    }
+}
+
+impl std::fmt::Display for Affine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(({}, {}, {}), ({}, {}, {}))", self.xx, self.xy, self.tx, self.yx, self.yy, self.ty)
+    }
+}
+
+impl From<affine_f32::Affine> for Affine {
+    fn from(t: affine_f32::Affine) -> Self {
+        Affine { xx: t.xx.into(), xy: t.xy.into(), tx: t.tx.into(), yx: t.yx.into(), yy: t.yy.into(), ty: t.ty.into() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Affine;
+    use crate::transform::d2::affine::affine_f32::Affine as AffineF32;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    #[test]
+    fn affine() {
+        assert_eq!(Affine::new((1.0, 2.0, 3.0), (4.0, 5.0, 6.0)), Affine { xx: 1.0, xy: 2.0, tx: 3.0, yx: 4.0, yy: 5.0, ty: 6.0 });
+        assert_eq!(Affine::identity(), Affine { xx: 1.0, xy: 0.0, tx: 0.0, yx: 0.0, yy: 1.0, ty: 0.0 });
+        assert_eq!(Affine::translate(-3.0, 7.0), Affine { xx: 1.0, xy: 0.0, tx: -3.0, yx: 0.0, yy: 1.0, ty: 7.0 });
+        assert_eq!(Affine::scale(2.0, -0.5), Affine { xx: 2.0, xy: 0.0, tx: 0.0, yx: 0.0, yy: -0.5, ty: 0.0 });
+        assert_eq!(Affine::shear(0.5, -2.0), Affine { xx: 1.0, xy: 0.5, tx: 0.0, yx: -2.0, yy: 1.0, ty: 0.0 });
+    }
+
+    #[test]
+    fn rotate() {
+        assert_eq!(Affine::rotate(0.0), Affine::identity());
+        assert_eq!(Affine::rotate(FRAC_PI_2), Affine { xx: FRAC_PI_2.cos(), xy: -1.0, tx: 0.0, yx: 1.0, yy: FRAC_PI_2.cos(), ty: 0.0 });
+        assert_eq!(Affine::rotate(PI), Affine { xx: -1.0, xy: -PI.sin(), tx: 0.0, yx: PI.sin(), yy: -1.0, ty: 0.0 });
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(Affine::identity().to_string(), "((1, 0, 0), (0, 1, 0))");
+        assert_eq!(Affine::new((1.5, -2.0, 3.0), (4.0, 5.0, -6.25)).to_string(), "((1.5, -2, 3), (4, 5, -6.25))");
+    }
+
+    #[test]
+    fn from() {
+        assert_eq!(Affine::from(AffineF32::identity()), Affine::identity());
+        assert_eq!(Affine::from(AffineF32::new((1.5, -2.0, 3.0), (4.0, 5.0, -6.25))), Affine::new((1.5, -2.0, 3.0), (4.0, 5.0, -6.25)));
+    }
+}
@@ -0,0 +1,2 @@
+pub mod affine_f32;
+pub mod affine_f64;
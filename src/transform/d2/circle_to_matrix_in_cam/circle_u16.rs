@@ -0,0 +1,369 @@
+type Circle = crate::cartesian::d2::circle::circle_u16::Circle;
+type Matrix = crate::matrix::d2::point::point_u16::Point;
+type Span = crate::matrix::d2::line::line_u16::Line;
+type Cam = crate::matrix::d2::rect::rect_u16::Rect;
+
+#[derive(Debug, PartialEq)]
+pub struct Outline {
+    center_x: i32,
+    center_y: i32,
+    x: i32,
+    y: i32,
+    decision: i32,
+    cam: Cam,
+    queue: [(i32, i32); 8],
+    queue_len: usize,
+    queue_index: usize,
+}
+
+impl Outline {
+    fn fill_queue(&mut self) {
+        let (x, y) = (self.x, self.y);
+        self.queue_len = 0;
+        self.queue_index = 0;
+        for offset in [(x, y), (-x, y), (x, -y), (-x, -y), (y, x), (-y, x), (y, -x), (-y, -x)] {
+            if !self.queue[..self.queue_len].contains(&offset) {
+                self.queue[self.queue_len] = offset;
+                self.queue_len += 1;
+            }
+        }
+        self.y += 1;
+        if self.decision < 0 {
+            self.decision += 2 * self.y + 1;
+        } else {
+            self.x -= 1;
+            self.decision += 2 * (self.y - self.x) + 1;
+        }
+    }
+}
+
+impl Iterator for Outline {
+    type Item = Matrix;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.queue_index == self.queue_len {
+                if self.x < self.y {
+                    return None;
+                }
+                self.fill_queue();
+            }
+            let (dx, dy) = self.queue[self.queue_index];
+            self.queue_index += 1;
+            if let Some(p) = to_matrix_in_cam(self.center_x + dx, self.center_y + dy, &self.cam) {
+                return Some(p);
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Filled {
+    center_x: i32,
+    center_y: i32,
+    r: u64,
+    dy: i32,
+    end_dy: i32,
+    cam: Cam,
+}
+
+impl Iterator for Filled {
+    type Item = Span;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.dy >= self.end_dy {
+            let dy = self.dy;
+            self.dy -= 1;
+            let half_width = half_width(self.r, u64::from(dy.unsigned_abs())) as i32;
+            let row = i32::from(u16::MAX) - (self.center_y + dy) + i32::from(self.cam.min.row);
+            let min_col = (self.center_x - half_width - i32::from(u16::MIN) + i32::from(self.cam.min.col)).max(i32::from(self.cam.min.col));
+            let max_col = (self.center_x + half_width - i32::from(u16::MIN) + i32::from(self.cam.min.col)).min(i32::from(self.cam.max.col));
+            if min_col <= max_col {
+                return Some(Span::new((row as u16, min_col as u16), (row as u16, max_col as u16)));
+            }
+        }
+        None
+    }
+}
+
+fn half_width(r: u64, dy: u64) -> u64 {
+    let rest = r * r - dy * dy;
+    let inner = if rest + dy > 0 { (rest + dy - 1).isqrt() } else { 0 };
+    let s = rest.isqrt();
+    let outer = if s * (s + 1) < rest { s + 1 } else { s };
+    outer.max(inner)
+}
+
+fn to_matrix_in_cam(x: i32, y: i32, cam: &Cam) -> Option<Matrix> {
+    let row = i32::from(u16::MAX) - y + i32::from(cam.min.row);
+    let col = x - i32::from(u16::MIN) + i32::from(cam.min.col);
+    if row < i32::from(cam.min.row) || row > i32::from(cam.max.row) || col < i32::from(cam.min.col) || col > i32::from(cam.max.col) {
+        return None;
+    }
+    Some(Matrix { row: row as u16, col: col as u16 })
+}
+
+pub fn circle_to_matrix_in_cam(c: &Circle, cam: &Cam) -> Outline {
+    let r = i32::from(c.r);
+    Outline { center_x: i32::from(c.p.x), center_y: i32::from(c.p.y), x: r, y: 0, decision: 1 - r, cam: cam.clone(), queue: [(0, 0); 8], queue_len: 0, queue_index: 0 }
+}
+
+pub fn filled_circle_to_matrix_in_cam(c: &Circle, cam: &Cam) -> Filled {
+    let r = i32::from(c.r);
+    let center_y = i32::from(c.p.y);
+    let min_y = i32::from(u16::MAX) - (i32::from(cam.max.row) - i32::from(cam.min.row));
+    Filled { center_x: i32::from(c.p.x), center_y, r: u64::from(c.r), dy: r.min(i32::from(u16::MAX) - center_y), end_dy: (-r).max(min_y - center_y), cam: cam.clone() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cam, Circle, Matrix, Span, circle_to_matrix_in_cam, filled_circle_to_matrix_in_cam};
+    use crate::cartesian::{d1::point::point_u16::MAX, d2::point::point_u16::Point};
+
+    #[test]
+    fn outline() {
+        assert_eq!(circle_to_matrix_in_cam(&Circle::new(Point::new(4, MAX - 4), 0), &Cam::new((0, 0), (9, 9))).collect::<Vec<Matrix>>(), [Matrix::new(4, 4)]);
+        assert_eq!(
+            circle_to_matrix_in_cam(&Circle::new(Point::new(4, MAX - 4), 1), &Cam::new((0, 0), (9, 9))).collect::<Vec<Matrix>>(),
+            [Matrix::new(4, 5), Matrix::new(4, 3), Matrix::new(3, 4), Matrix::new(5, 4)]
+        );
+        assert_eq!(
+            circle_to_matrix_in_cam(&Circle::new(Point::new(4, MAX - 4), 2), &Cam::new((0, 0), (9, 9))).collect::<Vec<Matrix>>(),
+            [
+                Matrix::new(4, 6),
+                Matrix::new(4, 2),
+                Matrix::new(2, 4),
+                Matrix::new(6, 4),
+                Matrix::new(3, 6),
+                Matrix::new(3, 2),
+                Matrix::new(5, 6),
+                Matrix::new(5, 2),
+                Matrix::new(2, 5),
+                Matrix::new(2, 3),
+                Matrix::new(6, 5),
+                Matrix::new(6, 3)
+            ]
+        );
+        assert_eq!(
+            circle_to_matrix_in_cam(&Circle::new(Point::new(4, MAX - 4), 3), &Cam::new((0, 0), (9, 9))).collect::<Vec<Matrix>>(),
+            [
+                Matrix::new(4, 7),
+                Matrix::new(4, 1),
+                Matrix::new(1, 4),
+                Matrix::new(7, 4),
+                Matrix::new(3, 7),
+                Matrix::new(3, 1),
+                Matrix::new(5, 7),
+                Matrix::new(5, 1),
+                Matrix::new(1, 5),
+                Matrix::new(1, 3),
+                Matrix::new(7, 5),
+                Matrix::new(7, 3),
+                Matrix::new(2, 6),
+                Matrix::new(2, 2),
+                Matrix::new(6, 6),
+                Matrix::new(6, 2)
+            ]
+        );
+        assert_eq!(
+            circle_to_matrix_in_cam(&Circle::new(Point::new(4, MAX - 4), 4), &Cam::new((0, 0), (9, 9))).collect::<Vec<Matrix>>(),
+            [
+                Matrix::new(4, 8),
+                Matrix::new(4, 0),
+                Matrix::new(0, 4),
+                Matrix::new(8, 4),
+                Matrix::new(3, 8),
+                Matrix::new(3, 0),
+                Matrix::new(5, 8),
+                Matrix::new(5, 0),
+                Matrix::new(0, 5),
+                Matrix::new(0, 3),
+                Matrix::new(8, 5),
+                Matrix::new(8, 3),
+                Matrix::new(2, 7),
+                Matrix::new(2, 1),
+                Matrix::new(6, 7),
+                Matrix::new(6, 1),
+                Matrix::new(1, 6),
+                Matrix::new(1, 2),
+                Matrix::new(7, 6),
+                Matrix::new(7, 2),
+                Matrix::new(1, 7),
+                Matrix::new(1, 1),
+                Matrix::new(7, 7),
+                Matrix::new(7, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn outline_cam_offset() {
+        assert_eq!(
+            circle_to_matrix_in_cam(&Circle::new(Point::new(4, MAX - 4), 1), &Cam::new((10, 20), (19, 29))).collect::<Vec<Matrix>>(),
+            [Matrix::new(14, 25), Matrix::new(14, 23), Matrix::new(13, 24), Matrix::new(15, 24)]
+        );
+        assert_eq!(
+            circle_to_matrix_in_cam(&Circle::new(Point::new(4, MAX - 4), 3), &Cam::new((10, 20), (19, 29))).collect::<Vec<Matrix>>(),
+            [
+                Matrix::new(14, 27),
+                Matrix::new(14, 21),
+                Matrix::new(11, 24),
+                Matrix::new(17, 24),
+                Matrix::new(13, 27),
+                Matrix::new(13, 21),
+                Matrix::new(15, 27),
+                Matrix::new(15, 21),
+                Matrix::new(11, 25),
+                Matrix::new(11, 23),
+                Matrix::new(17, 25),
+                Matrix::new(17, 23),
+                Matrix::new(12, 26),
+                Matrix::new(12, 22),
+                Matrix::new(16, 26),
+                Matrix::new(16, 22)
+            ]
+        );
+    }
+
+    #[test]
+    fn outline_clipped() {
+        assert_eq!(
+            circle_to_matrix_in_cam(&Circle::new(Point::new(1, MAX - 1), 3), &Cam::new((0, 0), (9, 9))).collect::<Vec<Matrix>>(),
+            [Matrix::new(1, 4), Matrix::new(4, 1), Matrix::new(0, 4), Matrix::new(2, 4), Matrix::new(4, 2), Matrix::new(4, 0), Matrix::new(3, 3)]
+        );
+        assert_eq!(
+            circle_to_matrix_in_cam(&Circle::new(Point::new(8, MAX - 8), 3), &Cam::new((0, 0), (9, 9))).collect::<Vec<Matrix>>(),
+            [Matrix::new(8, 5), Matrix::new(5, 8), Matrix::new(7, 5), Matrix::new(9, 5), Matrix::new(5, 9), Matrix::new(5, 7), Matrix::new(6, 6)]
+        );
+        assert_eq!(circle_to_matrix_in_cam(&Circle::new(Point::new(20, MAX - 20), 3), &Cam::new((0, 0), (9, 9))).collect::<Vec<Matrix>>(), []);
+    }
+
+    #[test]
+    fn outline_bounds() {
+        assert_eq!(
+            circle_to_matrix_in_cam(&Circle::new(Point::new(0, 0), u16::MAX), &Cam::largest()).take(8).collect::<Vec<Matrix>>(),
+            [
+                Matrix::new(u16::MAX, u16::MAX),
+                Matrix::new(0, 0),
+                Matrix::new(u16::MAX - 1, u16::MAX),
+                Matrix::new(0, 1),
+                Matrix::new(u16::MAX - 2, u16::MAX),
+                Matrix::new(0, 2),
+                Matrix::new(u16::MAX - 3, u16::MAX),
+                Matrix::new(0, 3)
+            ]
+        );
+        assert_eq!(
+            circle_to_matrix_in_cam(&Circle::new(Point::new(MAX, MAX), u16::MAX), &Cam::largest()).take(8).collect::<Vec<Matrix>>(),
+            [
+                Matrix::new(0, 0),
+                Matrix::new(u16::MAX, u16::MAX),
+                Matrix::new(1, 0),
+                Matrix::new(u16::MAX, u16::MAX - 1),
+                Matrix::new(2, 0),
+                Matrix::new(u16::MAX, u16::MAX - 2),
+                Matrix::new(3, 0),
+                Matrix::new(u16::MAX, u16::MAX - 3)
+            ]
+        );
+    }
+
+    #[test]
+    fn filled() {
+        assert_eq!(filled_circle_to_matrix_in_cam(&Circle::new(Point::new(4, MAX - 4), 0), &Cam::new((0, 0), (9, 9))).collect::<Vec<Span>>(), [Span::new((4, 4), (4, 4))]);
+        assert_eq!(
+            filled_circle_to_matrix_in_cam(&Circle::new(Point::new(4, MAX - 4), 1), &Cam::new((0, 0), (9, 9))).collect::<Vec<Span>>(),
+            [Span::new((3, 4), (3, 4)), Span::new((4, 3), (4, 5)), Span::new((5, 4), (5, 4))]
+        );
+        assert_eq!(
+            filled_circle_to_matrix_in_cam(&Circle::new(Point::new(4, MAX - 4), 2), &Cam::new((0, 0), (9, 9))).collect::<Vec<Span>>(),
+            [Span::new((2, 3), (2, 5)), Span::new((3, 2), (3, 6)), Span::new((4, 2), (4, 6)), Span::new((5, 2), (5, 6)), Span::new((6, 3), (6, 5))]
+        );
+        assert_eq!(
+            filled_circle_to_matrix_in_cam(&Circle::new(Point::new(4, MAX - 4), 3), &Cam::new((0, 0), (9, 9))).collect::<Vec<Span>>(),
+            [
+                Span::new((1, 3), (1, 5)),
+                Span::new((2, 2), (2, 6)),
+                Span::new((3, 1), (3, 7)),
+                Span::new((4, 1), (4, 7)),
+                Span::new((5, 1), (5, 7)),
+                Span::new((6, 2), (6, 6)),
+                Span::new((7, 3), (7, 5))
+            ]
+        );
+        assert_eq!(
+            filled_circle_to_matrix_in_cam(&Circle::new(Point::new(4, MAX - 4), 4), &Cam::new((0, 0), (9, 9))).collect::<Vec<Span>>(),
+            [
+                Span::new((0, 3), (0, 5)),
+                Span::new((1, 1), (1, 7)),
+                Span::new((2, 1), (2, 7)),
+                Span::new((3, 0), (3, 8)),
+                Span::new((4, 0), (4, 8)),
+                Span::new((5, 0), (5, 8)),
+                Span::new((6, 1), (6, 7)),
+                Span::new((7, 1), (7, 7)),
+                Span::new((8, 3), (8, 5))
+            ]
+        );
+    }
+
+    #[test]
+    fn filled_cam_offset() {
+        assert_eq!(
+            filled_circle_to_matrix_in_cam(&Circle::new(Point::new(4, MAX - 4), 1), &Cam::new((10, 20), (19, 29))).collect::<Vec<Span>>(),
+            [Span::new((13, 24), (13, 24)), Span::new((14, 23), (14, 25)), Span::new((15, 24), (15, 24))]
+        );
+        assert_eq!(
+            filled_circle_to_matrix_in_cam(&Circle::new(Point::new(4, MAX - 4), 3), &Cam::new((10, 20), (19, 29))).collect::<Vec<Span>>(),
+            [
+                Span::new((11, 23), (11, 25)),
+                Span::new((12, 22), (12, 26)),
+                Span::new((13, 21), (13, 27)),
+                Span::new((14, 21), (14, 27)),
+                Span::new((15, 21), (15, 27)),
+                Span::new((16, 22), (16, 26)),
+                Span::new((17, 23), (17, 25))
+            ]
+        );
+    }
+
+    #[test]
+    fn filled_clipped() {
+        assert_eq!(
+            filled_circle_to_matrix_in_cam(&Circle::new(Point::new(1, MAX - 1), 3), &Cam::new((0, 0), (9, 9))).collect::<Vec<Span>>(),
+            [Span::new((0, 0), (0, 4)), Span::new((1, 0), (1, 4)), Span::new((2, 0), (2, 4)), Span::new((3, 0), (3, 3)), Span::new((4, 0), (4, 2))]
+        );
+        assert_eq!(
+            filled_circle_to_matrix_in_cam(&Circle::new(Point::new(8, MAX - 8), 3), &Cam::new((0, 0), (9, 9))).collect::<Vec<Span>>(),
+            [Span::new((5, 7), (5, 9)), Span::new((6, 6), (6, 9)), Span::new((7, 5), (7, 9)), Span::new((8, 5), (8, 9)), Span::new((9, 5), (9, 9))]
+        );
+        assert_eq!(filled_circle_to_matrix_in_cam(&Circle::new(Point::new(20, MAX - 20), 3), &Cam::new((0, 0), (9, 9))).collect::<Vec<Span>>(), []);
+    }
+
+    #[test]
+    fn filled_bounds() {
+        assert_eq!(
+            filled_circle_to_matrix_in_cam(&Circle::new(Point::new(0, 0), u16::MAX), &Cam::largest()).take(3).collect::<Vec<Span>>(),
+            [Span::new((0, 0), (0, 255)), Span::new((1, 0), (1, 443)), Span::new((2, 0), (2, 572))]
+        );
+        assert_eq!(
+            filled_circle_to_matrix_in_cam(&Circle::new(Point::new(MAX, MAX), u16::MAX), &Cam::largest()).take(3).collect::<Vec<Span>>(),
+            [Span::new((0, 0), (0, u16::MAX)), Span::new((1, 0), (1, u16::MAX)), Span::new((2, 0), (2, u16::MAX))]
+        );
+        assert_eq!(
+            filled_circle_to_matrix_in_cam(&Circle::new(Point::new(0, MAX), u16::MAX), &Cam::new((0, 0), (9, 9))).collect::<Vec<Span>>(),
+            [
+                Span::new((0, 0), (0, 9)),
+                Span::new((1, 0), (1, 9)),
+                Span::new((2, 0), (2, 9)),
+                Span::new((3, 0), (3, 9)),
+                Span::new((4, 0), (4, 9)),
+                Span::new((5, 0), (5, 9)),
+                Span::new((6, 0), (6, 9)),
+                Span::new((7, 0), (7, 9)),
+                Span::new((8, 0), (8, 9)),
+                Span::new((9, 0), (9, 9))
+            ]
+        );
+    }
+}
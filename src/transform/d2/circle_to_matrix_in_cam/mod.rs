@@ -0,0 +1,9 @@
+pub mod circle_u16;
+pub mod circle_u32;
+pub mod circle_u64;
+pub mod circle_u8;
+
+pub mod circle_i16;
+pub mod circle_i32;
+pub mod circle_i64;
+pub mod circle_i8;
@@ -6,3 +6,7 @@ pub mod matrix_in_cam_to_cartesian;
 
 pub mod cartesian_to_matrix_in_cam;
 pub mod matrix_to_cartesian_in_cam;
+
+pub mod circle_to_matrix_in_cam;
+
+pub mod affine;